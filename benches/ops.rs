@@ -0,0 +1,173 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use option_operations::{Error, OptionAdd, OptionCheckedDiv, OptionDiv};
+
+const LEN: usize = 10_000;
+
+/// Distribution of `Some`s and `None`s in the operands.
+#[derive(Clone, Copy, Debug)]
+enum Input {
+    AllSome,
+    AllNone,
+    Mixed,
+}
+
+impl Input {
+    const ALL: [Input; 3] = [Input::AllSome, Input::AllNone, Input::Mixed];
+
+    fn name(self) -> &'static str {
+        match self {
+            Input::AllSome => "all_some",
+            Input::AllNone => "all_none",
+            Input::Mixed => "mixed",
+        }
+    }
+
+    /// Builds the operands, none of which is zero.
+    fn values(self, seed: i64) -> Vec<Option<i64>> {
+        // The `None`s of the mixed input are drawn from a seeded xorshift
+        // generator so that the branch predictor can't guess the next value.
+        let mut state = (seed as u64) ^ 0x9E37_79B9_7F4A_7C15;
+        let mut is_none = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % 5 < 2
+        };
+
+        (1..=LEN as i64)
+            .map(|idx| match self {
+                Input::AllSome => Some(idx * seed),
+                Input::AllNone => None,
+                Input::Mixed if is_none() => None,
+                Input::Mixed => Some(idx * seed),
+            })
+            .collect()
+    }
+}
+
+fn add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("add");
+
+    for input in Input::ALL {
+        let operands = (input.values(3), input.values(7));
+
+        group.bench_with_input(
+            BenchmarkId::new("opt_add", input.name()),
+            &operands,
+            |b, (lhs, rhs)| {
+                b.iter(|| {
+                    lhs.iter()
+                        .zip(rhs.iter())
+                        .map(|(lhs, rhs)| black_box(*lhs).opt_add(black_box(*rhs)))
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("manual", input.name()),
+            &operands,
+            |b, (lhs, rhs)| {
+                b.iter(|| {
+                    lhs.iter()
+                        .zip(rhs.iter())
+                        .map(|(lhs, rhs)| {
+                            black_box(*lhs)
+                                .zip(black_box(*rhs))
+                                .map(|(lhs, rhs)| lhs + rhs)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn div(c: &mut Criterion) {
+    let mut group = c.benchmark_group("div");
+
+    for input in Input::ALL {
+        let operands = (input.values(7), input.values(3));
+
+        group.bench_with_input(
+            BenchmarkId::new("opt_div", input.name()),
+            &operands,
+            |b, (lhs, rhs)| {
+                b.iter(|| {
+                    lhs.iter()
+                        .zip(rhs.iter())
+                        .map(|(lhs, rhs)| black_box(*lhs).opt_div(black_box(*rhs)))
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("manual", input.name()),
+            &operands,
+            |b, (lhs, rhs)| {
+                b.iter(|| {
+                    lhs.iter()
+                        .zip(rhs.iter())
+                        .map(|(lhs, rhs)| {
+                            black_box(*lhs)
+                                .zip(black_box(*rhs))
+                                .map(|(lhs, rhs)| lhs / rhs)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn checked_div(c: &mut Criterion) {
+    let mut group = c.benchmark_group("checked_div");
+
+    for input in Input::ALL {
+        let operands = (input.values(7), input.values(3));
+
+        group.bench_with_input(
+            BenchmarkId::new("opt_checked_div", input.name()),
+            &operands,
+            |b, (lhs, rhs)| {
+                b.iter(|| {
+                    lhs.iter()
+                        .zip(rhs.iter())
+                        .map(|(lhs, rhs)| black_box(*lhs).opt_checked_div(black_box(*rhs)))
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("manual", input.name()),
+            &operands,
+            |b, (lhs, rhs)| {
+                b.iter(|| {
+                    lhs.iter()
+                        .zip(rhs.iter())
+                        .map(|(lhs, rhs)| match (black_box(*lhs), black_box(*rhs)) {
+                            (Some(_), Some(0)) => Err(Error::DivisionByZero),
+                            (Some(lhs), Some(rhs)) => {
+                                lhs.checked_div(rhs).ok_or(Error::Overflow).map(Some)
+                            }
+                            _ => Ok(None),
+                        })
+                        .collect::<Vec<_>>()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, add, div, checked_div);
+criterion_main!(benches);
@@ -0,0 +1,237 @@
+//! Traits for the absolute value [`OptionOperations`].
+
+use crate::{Error, OptionOperations};
+
+option_unary_op_custom!(
+    Abs,
+    abs,
+    "absolute value",
+    "
+# Panics
+
+Most signed integer implementations will panic in debug mode
+if `self` is the minimum value of its type.
+",
+);
+
+impl_for_signed_ints!(OptionAbs, {
+    type Output = Self;
+    #[inline]
+    fn opt_abs(self) -> Option<Self::Output> {
+        Some(self.abs())
+    }
+});
+
+impl_for_floats!(OptionAbs, {
+    type Output = Self;
+    #[inline]
+    fn opt_abs(self) -> Option<Self::Output> {
+        Some(self.abs())
+    }
+});
+
+option_unary_op_checked!(
+    Abs,
+    abs,
+    "absolute value",
+    "- Returns `Err(Error::Overflow)` if `self` is the minimum value of a signed
+integer type.",
+);
+
+impl_for_signed_ints!(OptionCheckedAbs, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_abs(self) -> Result<Option<Self::Output>, Error> {
+        self.checked_abs().ok_or(Error::Overflow).map(Some)
+    }
+});
+
+/// Trait for values and `Option`s overflowing absolute value.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionOverflowingAbs` for `Option<T>`.
+///
+/// Note that since the `std` library doesn't define any `OverflowingAbs`
+/// trait, users must provide the base implementation for the inner type.
+pub trait OptionOverflowingAbs {
+    /// The resulting inner type after applying the overflowing absolute value.
+    type Output;
+
+    /// Computes the absolute value, returning a tuple of the result
+    /// and a boolean indicating whether an arithmetic overflow occured.
+    ///
+    /// If an overflow occured, i.e. if `self` is the minimum value
+    /// of a signed integer type, then `self` is returned.
+    ///
+    /// Returns `None` if `self` is `None`.
+    #[must_use]
+    fn opt_overflowing_abs(self) -> Option<(Self::Output, bool)>;
+}
+
+impl<T> OptionOverflowingAbs for Option<T>
+where
+    T: OptionOperations + OptionOverflowingAbs,
+{
+    type Output = <T as OptionOverflowingAbs>::Output;
+
+    #[inline]
+    fn opt_overflowing_abs(self) -> Option<(Self::Output, bool)> {
+        self.and_then(|inner_self| inner_self.opt_overflowing_abs())
+    }
+}
+
+impl_for_signed_ints!(OptionOverflowingAbs, {
+    type Output = Self;
+    #[inline]
+    fn opt_overflowing_abs(self) -> Option<(Self::Output, bool)> {
+        Some(self.overflowing_abs())
+    }
+});
+
+option_unary_op_custom!(
+    WrappingAbs,
+    wrapping_abs,
+    "wrapping absolute value",
+    "
+The absolute value of the minimum value of a signed integer type wraps
+around to the minimum value.",
+);
+
+impl_for_signed_ints!(OptionWrappingAbs, {
+    type Output = Self;
+    #[inline]
+    fn opt_wrapping_abs(self) -> Option<Self::Output> {
+        Some(self.wrapping_abs())
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionAbs for MyInt {
+        type Output = MyInt;
+        fn opt_abs(self) -> Option<Self::Output> {
+            self.0.opt_abs().map(MyInt)
+        }
+    }
+
+    impl OptionCheckedAbs for MyInt {
+        type Output = MyInt;
+        fn opt_checked_abs(self) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_abs().map(|ok| ok.map(MyInt))
+        }
+    }
+
+    impl OptionOverflowingAbs for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_abs(self) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_abs()
+                .map(|(val, flag)| (MyInt(val), flag))
+        }
+    }
+
+    impl OptionWrappingAbs for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_abs(self) -> Option<Self::Output> {
+            self.0.opt_wrapping_abs().map(MyInt)
+        }
+    }
+
+    const MY_MINUS_2: MyInt = MyInt(-2);
+    const MY_0: MyInt = MyInt(0);
+    const MY_2: MyInt = MyInt(2);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const MY_MAX: MyInt = MyInt(i64::MAX);
+    const SOME_MINUS_2: Option<MyInt> = Some(MY_MINUS_2);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_MIN: Option<MyInt> = Some(MY_MIN);
+    const SOME_MAX: Option<MyInt> = Some(MY_MAX);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn abs() {
+        assert_eq!(MY_MINUS_2.opt_abs(), SOME_2);
+        assert_eq!(MY_2.opt_abs(), SOME_2);
+        assert_eq!(MY_0.opt_abs(), SOME_0);
+        assert_eq!(SOME_MINUS_2.opt_abs(), SOME_2);
+        assert_eq!(SOME_2.opt_abs(), SOME_2);
+        assert_eq!(NONE.opt_abs(), NONE);
+
+        assert_eq!(Some(-5i8).opt_abs(), Some(5));
+        assert_eq!(Some(-1.5f64).opt_abs(), Some(1.5));
+        assert_eq!(Option::<f32>::None.opt_abs(), None);
+    }
+
+    #[test]
+    fn checked_abs() {
+        assert_eq!(MY_MINUS_2.opt_checked_abs(), Ok(SOME_2));
+        assert_eq!(MY_MAX.opt_checked_abs(), Ok(SOME_MAX));
+        assert_eq!(SOME_MINUS_2.opt_checked_abs(), Ok(SOME_2));
+        assert_eq!(SOME_0.opt_checked_abs(), Ok(SOME_0));
+        assert_eq!(MY_MIN.opt_checked_abs(), Err(Error::Overflow));
+        assert_eq!(SOME_MIN.opt_checked_abs(), Err(Error::Overflow));
+        assert_eq!(NONE.opt_checked_abs(), Ok(None));
+
+        assert_eq!(Some(i64::MIN + 1).opt_checked_abs(), Ok(Some(i64::MAX)));
+        assert_eq!(Some(i64::MIN).opt_checked_abs(), Err(Error::Overflow));
+    }
+
+    macro_rules! test_abs_min {
+        ($($typ_:ty),+ $(,)?) => {
+            $(
+                assert_eq!(Some(<$typ_>::MIN).opt_checked_abs(), Err(Error::Overflow));
+                assert_eq!(
+                    Some(<$typ_>::MIN + 1).opt_checked_abs(),
+                    Ok(Some(<$typ_>::MAX))
+                );
+                assert_eq!(
+                    Some(<$typ_>::MIN).opt_overflowing_abs(),
+                    Some((<$typ_>::MIN, true))
+                );
+                assert_eq!(
+                    Some(<$typ_>::MIN + 1).opt_overflowing_abs(),
+                    Some((<$typ_>::MAX, false))
+                );
+                assert_eq!(Some(<$typ_>::MIN).opt_wrapping_abs(), Some(<$typ_>::MIN));
+                assert_eq!(
+                    Some(<$typ_>::MIN + 1).opt_wrapping_abs(),
+                    Some(<$typ_>::MAX)
+                );
+            )+
+        };
+    }
+
+    #[test]
+    fn abs_min_per_type() {
+        test_abs_min!(i8, i16, i32, i64, i128, isize);
+    }
+
+    #[test]
+    fn overflowing_abs() {
+        assert_eq!(MY_MINUS_2.opt_overflowing_abs(), Some((MY_2, false)));
+        assert_eq!(MY_2.opt_overflowing_abs(), Some((MY_2, false)));
+        assert_eq!(SOME_0.opt_overflowing_abs(), Some((MY_0, false)));
+        assert_eq!(MY_MIN.opt_overflowing_abs(), Some((MY_MIN, true)));
+        assert_eq!(SOME_MIN.opt_overflowing_abs(), Some((MY_MIN, true)));
+        assert_eq!(NONE.opt_overflowing_abs(), None);
+    }
+
+    #[test]
+    fn wrapping_abs() {
+        assert_eq!(MY_MINUS_2.opt_wrapping_abs(), SOME_2);
+        assert_eq!(SOME_MAX.opt_wrapping_abs(), SOME_MAX);
+        assert_eq!(MY_MIN.opt_wrapping_abs(), SOME_MIN);
+        assert_eq!(SOME_MIN.opt_wrapping_abs(), SOME_MIN);
+        assert_eq!(NONE.opt_wrapping_abs(), NONE);
+    }
+}
@@ -0,0 +1,106 @@
+//! Traits for the absolute difference [`OptionOperations`].
+
+use crate::OptionOperations;
+
+option_op_custom!(AbsDiff, abs_diff, "absolute difference");
+
+macro_rules! impl_abs_diff_for_ints {
+    ($($typ_:ty => $output:ty),+ $(,)?) => {
+        $(
+            impl_for!(OptionAbsDiff, $typ_, {
+                type Output = $output;
+                #[inline]
+                fn opt_abs_diff(self, rhs: Self) -> Option<Self::Output> {
+                    Some(self.abs_diff(rhs))
+                }
+            });
+        )+
+    };
+}
+
+impl_abs_diff_for_ints!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    usize => usize,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionAbsDiff for MyInt {
+        type Output = u64;
+        fn opt_abs_diff(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_abs_diff(rhs.0)
+        }
+    }
+
+    impl OptionAbsDiff<i64> for MyInt {
+        type Output = u64;
+        fn opt_abs_diff(self, rhs: i64) -> Option<Self::Output> {
+            self.0.opt_abs_diff(rhs)
+        }
+    }
+
+    const MY_MINUS_3: MyInt = MyInt(-3);
+    const MY_0: MyInt = MyInt(0);
+    const MY_5: MyInt = MyInt(5);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const MY_MAX: MyInt = MyInt(i64::MAX);
+    const SOME_MINUS_3: Option<MyInt> = Some(MY_MINUS_3);
+    const SOME_5: Option<MyInt> = Some(MY_5);
+    const SOME_MIN: Option<MyInt> = Some(MY_MIN);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn abs_diff_my() {
+        assert_eq!(MY_5.opt_abs_diff(MY_MINUS_3), Some(8));
+        assert_eq!(MY_MINUS_3.opt_abs_diff(MY_5), Some(8));
+        assert_eq!(MY_0.opt_abs_diff(MY_0), Some(0));
+        assert_eq!(MY_MIN.opt_abs_diff(MY_MAX), Some(u64::MAX));
+        assert_eq!(MY_5.opt_abs_diff(SOME_MINUS_3), Some(8));
+        assert_eq!(MY_5.opt_abs_diff(&SOME_MINUS_3), Some(8));
+        assert_eq!(SOME_MINUS_3.opt_abs_diff(MY_5), Some(8));
+        assert_eq!(SOME_MINUS_3.opt_abs_diff(SOME_5), Some(8));
+        assert_eq!(
+            SOME_MIN.opt_abs_diff(&SOME_MINUS_3),
+            Some(i64::MAX as u64 - 2)
+        );
+        assert_eq!(MY_5.opt_abs_diff(NONE), None);
+        assert_eq!(NONE.opt_abs_diff(MY_5), None);
+        assert_eq!(NONE.opt_abs_diff(SOME_5), None);
+    }
+
+    #[test]
+    fn abs_diff_i64() {
+        assert_eq!(MY_5.opt_abs_diff(-3), Some(8));
+        assert_eq!(MY_5.opt_abs_diff(Some(-3)), Some(8));
+        assert_eq!(SOME_5.opt_abs_diff(10), Some(5));
+        assert_eq!(SOME_5.opt_abs_diff(&Some(10)), Some(5));
+        assert_eq!(MY_5.opt_abs_diff(Option::<i64>::None), None);
+        assert_eq!(NONE.opt_abs_diff(10), None);
+    }
+
+    #[test]
+    fn abs_diff_primitive() {
+        assert_eq!(Some(-100i8).opt_abs_diff(Some(100i8)), Some(200u8));
+        assert_eq!(Some(3u32).opt_abs_diff(Some(10u32)), Some(7u32));
+        assert_eq!(i64::MIN.opt_abs_diff(Some(i64::MAX)), Some(u64::MAX));
+        assert_eq!(Some(3u32).opt_abs_diff(Option::<u32>::None), None);
+    }
+}
@@ -8,6 +8,7 @@ common_option_op!(Add, add, addition);
 
 impl_for_ints!(OptionOverflowingAdd, {
     type Output = Self;
+    #[inline]
     fn opt_overflowing_add(self, rhs: Self) -> Option<(Self::Output, bool)> {
         Some(self.overflowing_add(rhs))
     }
@@ -15,45 +16,120 @@ impl_for_ints!(OptionOverflowingAdd, {
 
 impl_for_ints!(OptionWrappingAdd, {
     type Output = Self;
+    #[inline]
     fn opt_wrapping_add(self, rhs: Self) -> Option<Self::Output> {
         Some(self.wrapping_add(rhs))
     }
 });
 
-option_op_checked!(Add, add, addition);
+option_op_checked!(
+    Add,
+    add,
+    addition,
+    "- Returns `Err(Error::NotFinite)` if the result of a floating point operation
+is infinite or `NaN` while the operands are finite.
+- Returns `Err(Error::InvalidChar)` if the result of a `char` operation
+is not a valid Unicode scalar value.",
+);
 
 impl_for_ints_and_duration!(OptionCheckedAdd, {
     type Output = Self;
+    #[inline]
     fn opt_checked_add(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
         self.checked_add(rhs).ok_or(Error::Overflow).map(Some)
     }
 });
 
-#[cfg(feature = "std")]
+impl_for_floats!(OptionCheckedAdd, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_add(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        let res = self.add(rhs);
+        if !res.is_finite() && self.is_finite() && rhs.is_finite() {
+            return Err(Error::NotFinite);
+        }
+        Ok(Some(res))
+    }
+});
+
+impl OptionCheckedAdd<u32> for char {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_add(self, rhs: u32) -> Result<Option<Self::Output>, Error> {
+        u32::from(self)
+            .checked_add(rhs)
+            .and_then(char::from_u32)
+            .ok_or(Error::InvalidChar)
+            .map(Some)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "duration"))]
 impl OptionCheckedAdd<std::time::Duration> for std::time::Instant {
     type Output = Self;
+    #[inline]
     fn opt_checked_add(self, rhs: std::time::Duration) -> Result<Option<Self::Output>, Error> {
         self.checked_add(rhs).ok_or(Error::Overflow).map(Some)
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "duration"))]
 impl OptionCheckedAdd<std::time::Duration> for std::time::SystemTime {
     type Output = Self;
+    #[inline]
     fn opt_checked_add(self, rhs: std::time::Duration) -> Result<Option<Self::Output>, Error> {
         self.checked_add(rhs).ok_or(Error::Overflow).map(Some)
     }
 }
 
+option_op_checked_assign!(
+    Add,
+    add,
+    addition,
+    "- Returns `Err(Error::Overflow)` if an overflow occured.",
+    "- Returns `Err(Error::NotFinite)` if the result of a floating point operation
+is infinite or `NaN` while the operands are finite.",
+);
+
+impl_for_ints_and_duration!(OptionCheckedAddAssign, {
+    #[inline]
+    fn opt_checked_add_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        *self = self.checked_add(rhs).ok_or(Error::Overflow)?;
+        Ok(())
+    }
+});
+
+impl_for_floats!(OptionCheckedAddAssign, {
+    #[inline]
+    fn opt_checked_add_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        if let Some(res) = (*self).opt_checked_add(rhs)? {
+            *self = res;
+        }
+        Ok(())
+    }
+});
+
 option_op_saturating!(Add, add, addition);
 
 impl_for_ints_and_duration!(OptionSaturatingAdd, {
     type Output = Self;
+    #[inline]
     fn opt_saturating_add(self, rhs: Self) -> Option<Self::Output> {
         Some(self.saturating_add(rhs))
     }
 });
 
+impl<T> OptionSaturatingAdd for core::num::Saturating<T>
+where
+    Self: OptionOperations + Add<Output = Self>,
+{
+    type Output = Self;
+    #[inline]
+    fn opt_saturating_add(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.add(rhs))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -81,6 +157,14 @@ mod test {
         }
     }
 
+    impl Add<MyInt> for &MyInt {
+        type Output = MyInt;
+
+        fn add(self, rhs: MyInt) -> MyInt {
+            (*self).add(rhs)
+        }
+    }
+
     impl AddAssign<MyInt> for MyInt {
         fn add_assign(&mut self, rhs: MyInt) {
             self.0.add_assign(rhs.0)
@@ -113,6 +197,23 @@ mod test {
         assert_eq!(NONE.opt_add(MY_1), NONE);
     }
 
+    #[test]
+    fn add_ref_receivers() {
+        let values = [SOME_1, NONE, SOME_2];
+        let mut sums = values.iter().map(|value| value.as_ref().opt_add(MY_1));
+        assert_eq!(sums.next(), Some(SOME_2));
+        assert_eq!(sums.next(), Some(NONE));
+        assert_eq!(sums.next(), Some(Some(MyInt(3))));
+
+        assert_eq!(values[0].as_ref().opt_add(SOME_1), SOME_2);
+        assert_eq!(values[1].as_ref().opt_add(SOME_1), NONE);
+        assert_eq!((&MY_1).opt_add(&SOME_1), SOME_2);
+
+        let values = [Some(1u64), None];
+        assert_eq!(values[0].as_ref().opt_add(Some(1)), Some(2));
+        assert_eq!(values[1].as_ref().opt_add(1), None);
+    }
+
     #[test]
     fn add_u64() {
         assert_eq!(MY_1.opt_add(1), SOME_2);
@@ -198,22 +299,22 @@ mod test {
         assert_eq!(none, NONE);
     }
 
-    #[test]
-    fn checked_add() {
-        impl OptionCheckedAdd for MyInt {
-            type Output = MyInt;
-            fn opt_checked_add(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
-                self.0.opt_checked_add(rhs.0).map(|ok| ok.map(MyInt))
-            }
+    impl OptionCheckedAdd for MyInt {
+        type Output = MyInt;
+        fn opt_checked_add(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_add(rhs.0).map(|ok| ok.map(MyInt))
         }
+    }
 
-        impl OptionCheckedAdd<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_checked_add(self, rhs: u64) -> Result<Option<Self::Output>, Error> {
-                self.0.opt_checked_add(rhs).map(|ok| ok.map(MyInt))
-            }
+    impl OptionCheckedAdd<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_add(self, rhs: u64) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_add(rhs).map(|ok| ok.map(MyInt))
         }
+    }
 
+    #[test]
+    fn checked_add() {
         assert_eq!(MY_1.opt_checked_add(MY_1), Ok(SOME_2));
         assert_eq!(MY_1.opt_checked_add(SOME_1), Ok(SOME_2));
         assert_eq!(MY_1.opt_checked_add(&SOME_1), Ok(SOME_2));
@@ -232,21 +333,57 @@ mod test {
     }
 
     #[test]
-    fn saturating_add() {
-        impl OptionSaturatingAdd for MyInt {
-            type Output = MyInt;
-            fn opt_saturating_add(self, rhs: MyInt) -> Option<Self::Output> {
-                self.0.opt_saturating_add(rhs.0).map(MyInt)
-            }
+    fn checked_add_float() {
+        assert_eq!(Some(1.5f64).opt_checked_add(2.0), Ok(Some(3.5)));
+        assert_eq!(1.5f32.opt_checked_add(Some(-2.0)), Ok(Some(-0.5)));
+        assert_eq!(f64::MAX.opt_checked_add(f64::MAX), Err(Error::NotFinite));
+        assert_eq!(
+            Some(f32::MIN).opt_checked_add(&Some(f32::MIN)),
+            Err(Error::NotFinite)
+        );
+        assert_eq!(
+            Some(f64::INFINITY).opt_checked_add(1.0),
+            Ok(Some(f64::INFINITY))
+        );
+        assert!(f64::NAN.opt_checked_add(1.0).unwrap().unwrap().is_nan());
+        assert_eq!(
+            Some(f64::MAX).opt_checked_add(Option::<f64>::None),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn checked_add_char() {
+        assert_eq!(Some('a').opt_checked_add(1), Ok(Some('b')));
+        assert_eq!('a'.opt_checked_add(Some(25)), Ok(Some('z')));
+        assert_eq!(
+            Some('\u{D7FF}').opt_checked_add(&Some(0)),
+            Ok(Some('\u{D7FF}'))
+        );
+        assert_eq!('\u{D7FF}'.opt_checked_add(1), Err(Error::InvalidChar));
+        assert_eq!('\u{D7FF}'.opt_checked_add(0x801), Ok(Some('\u{E000}')));
+        assert_eq!(char::MAX.opt_checked_add(1), Err(Error::InvalidChar));
+        assert_eq!('a'.opt_checked_add(u32::MAX), Err(Error::InvalidChar));
+        assert_eq!(Some('a').opt_checked_add(Option::<u32>::None), Ok(None));
+        assert_eq!(Option::<char>::None.opt_checked_add(1), Ok(None));
+    }
+
+    impl OptionSaturatingAdd for MyInt {
+        type Output = MyInt;
+        fn opt_saturating_add(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_saturating_add(rhs.0).map(MyInt)
         }
+    }
 
-        impl OptionSaturatingAdd<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_saturating_add(self, rhs: u64) -> Option<Self::Output> {
-                self.0.opt_saturating_add(rhs).map(MyInt)
-            }
+    impl OptionSaturatingAdd<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_saturating_add(self, rhs: u64) -> Option<Self::Output> {
+            self.0.opt_saturating_add(rhs).map(MyInt)
         }
+    }
 
+    #[test]
+    fn saturating_add() {
         assert_eq!(MY_1.opt_saturating_add(MY_1), SOME_2);
         assert_eq!(MY_MAX.opt_saturating_add(MY_1), SOME_MAX);
         assert_eq!(SOME_MAX.opt_saturating_add(MY_1), SOME_MAX);
@@ -259,26 +396,55 @@ mod test {
         assert_eq!(NONE.opt_saturating_add(SOME_MAX), NONE);
     }
 
+    #[cfg(feature = "duration")]
     #[test]
-    fn overflowing_add() {
-        impl OptionOverflowingAdd for MyInt {
-            type Output = MyInt;
-            fn opt_overflowing_add(self, rhs: MyInt) -> Option<(Self::Output, bool)> {
-                self.0
-                    .opt_overflowing_add(rhs.0)
-                    .map(|(val, flag)| (MyInt(val), flag))
-            }
+    fn saturating_add_duration() {
+        use core::time::Duration;
+
+        let secs_1 = Duration::from_secs(1);
+        assert_eq!(
+            secs_1.opt_saturating_add(secs_1),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            Duration::MAX.opt_saturating_add(secs_1),
+            Some(Duration::MAX)
+        );
+        assert_eq!(
+            Some(Duration::MAX).opt_saturating_add(Some(Duration::MAX)),
+            Some(Duration::MAX)
+        );
+        assert_eq!(
+            secs_1.opt_saturating_add(&Some(Duration::MAX)),
+            Some(Duration::MAX)
+        );
+        assert_eq!(
+            Some(Duration::MAX).opt_saturating_add(Option::<Duration>::None),
+            None
+        );
+        assert_eq!(Option::<Duration>::None.opt_saturating_add(secs_1), None);
+    }
+
+    impl OptionOverflowingAdd for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_add(self, rhs: MyInt) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_add(rhs.0)
+                .map(|(val, flag)| (MyInt(val), flag))
         }
+    }
 
-        impl OptionOverflowingAdd<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_overflowing_add(self, rhs: u64) -> Option<(Self::Output, bool)> {
-                self.0
-                    .opt_overflowing_add(rhs)
-                    .map(|(val, flag)| (MyInt(val), flag))
-            }
+    impl OptionOverflowingAdd<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_add(self, rhs: u64) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_add(rhs)
+                .map(|(val, flag)| (MyInt(val), flag))
         }
+    }
 
+    #[test]
+    fn overflowing_add() {
         assert_eq!(MY_1.opt_overflowing_add(MY_1), Some((MY_2, false)));
         assert_eq!(MY_MAX.opt_overflowing_add(MY_1), Some((MY_0, true)));
         assert_eq!(SOME_MAX.opt_overflowing_add(MY_1), Some((MY_0, true)));
@@ -291,22 +457,22 @@ mod test {
         assert_eq!(NONE.opt_overflowing_add(SOME_MAX), None);
     }
 
-    #[test]
-    fn wrapping_add() {
-        impl OptionWrappingAdd for MyInt {
-            type Output = MyInt;
-            fn opt_wrapping_add(self, rhs: MyInt) -> Option<Self::Output> {
-                self.0.opt_wrapping_add(rhs.0).map(MyInt)
-            }
+    impl OptionWrappingAdd for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_add(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_wrapping_add(rhs.0).map(MyInt)
         }
+    }
 
-        impl OptionWrappingAdd<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_wrapping_add(self, rhs: u64) -> Option<Self::Output> {
-                self.0.opt_wrapping_add(rhs).map(MyInt)
-            }
+    impl OptionWrappingAdd<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_add(self, rhs: u64) -> Option<Self::Output> {
+            self.0.opt_wrapping_add(rhs).map(MyInt)
         }
+    }
 
+    #[test]
+    fn wrapping_add() {
         assert_eq!(MY_1.opt_wrapping_add(MY_1), SOME_2);
         assert_eq!(MY_MAX.opt_wrapping_add(MY_1), SOME_0);
         assert_eq!(SOME_MAX.opt_wrapping_add(MY_1), SOME_0);
@@ -318,4 +484,130 @@ mod test {
         assert_eq!(MY_MAX.opt_wrapping_add(NONE), NONE);
         assert_eq!(NONE.opt_wrapping_add(SOME_MAX), NONE);
     }
+
+    #[test]
+    fn add_wrapping_type() {
+        use core::num::Wrapping;
+
+        assert_eq!(
+            Some(Wrapping(5u8)).opt_add(Wrapping(250u8)),
+            Some(Wrapping(255))
+        );
+        assert_eq!(
+            Some(Wrapping(6u8)).opt_add(Wrapping(250u8)),
+            Some(Wrapping(0))
+        );
+        assert_eq!(
+            Wrapping(i32::MAX).opt_add(Some(Wrapping(1))),
+            Some(Wrapping(i32::MIN))
+        );
+        assert_eq!(
+            Some(Wrapping(1u8)).opt_add(Option::<Wrapping<u8>>::None),
+            None
+        );
+
+        let mut some = Some(Wrapping(u64::MAX));
+        some.opt_add_assign(Wrapping(2));
+        assert_eq!(some, Some(Wrapping(1)));
+    }
+
+    #[test]
+    fn add_saturating_type() {
+        use core::num::Saturating;
+
+        assert_eq!(
+            Some(Saturating(250u8)).opt_add(Saturating(10u8)),
+            Some(Saturating(255))
+        );
+        assert_eq!(
+            Some(Saturating(250u8)).opt_add(Saturating(5u8)),
+            Some(Saturating(255))
+        );
+        assert_eq!(
+            Saturating(i32::MIN).opt_add(Some(Saturating(-1))),
+            Some(Saturating(i32::MIN))
+        );
+        assert_eq!(
+            Some(Saturating(1u8)).opt_add(Option::<Saturating<u8>>::None),
+            None
+        );
+        assert_eq!(
+            Some(Saturating(250u8)).opt_saturating_add(Some(Saturating(10u8))),
+            Some(Saturating(255))
+        );
+
+        let mut some = Some(Saturating(u64::MAX - 1));
+        some.opt_add_assign(Saturating(2));
+        assert_eq!(some, Some(Saturating(u64::MAX)));
+    }
+
+    impl OptionCheckedAddAssign for MyInt {
+        fn opt_checked_add_assign(&mut self, rhs: MyInt) -> Result<(), Error> {
+            self.0.opt_checked_add_assign(rhs.0)
+        }
+    }
+
+    impl OptionCheckedAddAssign<u64> for MyInt {
+        fn opt_checked_add_assign(&mut self, rhs: u64) -> Result<(), Error> {
+            self.0.opt_checked_add_assign(rhs)
+        }
+    }
+
+    #[test]
+    fn checked_add_assign() {
+        let mut my = MY_1;
+        assert_eq!(my.opt_checked_add_assign(MY_1), Ok(()));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_1;
+        assert_eq!(my.opt_checked_add_assign(SOME_1), Ok(()));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_1;
+        assert_eq!(my.opt_checked_add_assign(&SOME_1), Ok(()));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_MAX;
+        assert_eq!(my.opt_checked_add_assign(MY_1), Err(Error::Overflow));
+        assert_eq!(my, MY_MAX);
+
+        let mut my = MY_1;
+        assert_eq!(my.opt_checked_add_assign(NONE), Ok(()));
+        assert_eq!(my, MY_1);
+
+        let mut some = SOME_1;
+        assert_eq!(some.opt_checked_add_assign(1), Ok(()));
+        assert_eq!(some, SOME_2);
+
+        let mut some = SOME_MAX;
+        assert_eq!(some.opt_checked_add_assign(Some(1)), Err(Error::Overflow));
+        assert_eq!(some, SOME_MAX);
+
+        let mut some = SOME_MAX;
+        assert_eq!(some.opt_checked_add_assign(&SOME_0), Ok(()));
+        assert_eq!(some, SOME_MAX);
+
+        let mut some = SOME_MAX;
+        assert_eq!(some.opt_checked_add_assign(NONE), Ok(()));
+        assert_eq!(some, SOME_MAX);
+
+        let mut none = NONE;
+        assert_eq!(none.opt_checked_add_assign(SOME_MAX), Ok(()));
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    fn checked_add_assign_primitive() {
+        let mut some = Some(i8::MAX);
+        assert_eq!(some.opt_checked_add_assign(1), Err(Error::Overflow));
+        assert_eq!(some, Some(i8::MAX));
+
+        let mut some = Some(1.5f64);
+        assert_eq!(some.opt_checked_add_assign(1.0), Ok(()));
+        assert_eq!(some, Some(2.5));
+
+        let mut some = Some(f64::MAX);
+        assert_eq!(some.opt_checked_add_assign(f64::MAX), Err(Error::NotFinite));
+        assert_eq!(some, Some(f64::MAX));
+    }
 }
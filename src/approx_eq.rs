@@ -0,0 +1,254 @@
+//! Trait for the approximate equality [`OptionOperations`].
+
+use crate::OptionOperations;
+
+/// Trait for values and `Option`s approximate equality.
+///
+/// This is mostly useful for floats, which are subject to rounding errors:
+///
+/// ```
+/// # use option_operations::OptionApproxEq;
+/// let computed = Some(0.1f64 + 0.2);
+///
+/// assert_ne!(computed, Some(0.3));
+/// assert_eq!(computed.opt_approx_eq(0.3, 1e-12), Some(true));
+/// assert_eq!(computed.opt_approx_eq_ulps(Some(0.3), 1), Some(true));
+/// assert_eq!(computed.opt_approx_eq(None, 1e-12), None);
+/// ```
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionApproxEq<Option<InnerRhs>>` for `T`.
+/// - `OptionApproxEq<Rhs>` for `Option<T>`.
+/// - `OptionApproxEq<Option<InnerRhs>>` for `Option<T>`.
+///
+/// Note that since the `std` library doesn't define any `ApproxEq` trait,
+/// users must provide the base implementation for the inner type.
+pub trait OptionApproxEq<Rhs = Self, InnerRhs = Rhs> {
+    /// The inner type used to express the tolerance.
+    type Inner;
+
+    /// Tests whether `self` is equal to `other` within the absolute
+    /// tolerance `epsilon`.
+    ///
+    /// Returns `None` if at least one argument is `None`.
+    ///
+    /// Note that an absolute tolerance is not suitable for values with
+    /// a large magnitude, see
+    /// [`opt_approx_eq_ulps`](Self::opt_approx_eq_ulps).
+    #[must_use]
+    fn opt_approx_eq(self, other: Rhs, epsilon: Self::Inner) -> Option<bool>;
+
+    /// Tests whether `self` is equal to `other` within `max_ulps` units
+    /// in the last place, i.e. whether at most `max_ulps - 1`
+    /// representable values lie between them.
+    ///
+    /// The tolerance thus scales with the magnitude of the values. Values
+    /// of opposite signs are only equal if they are both zero.
+    ///
+    /// Returns `None` if at least one argument is `None`.
+    #[must_use]
+    fn opt_approx_eq_ulps(self, other: Rhs, max_ulps: u32) -> Option<bool>;
+}
+
+impl<T, InnerRhs> OptionApproxEq<Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + OptionApproxEq<InnerRhs>,
+{
+    type Inner = <T as OptionApproxEq<InnerRhs>>::Inner;
+
+    #[inline]
+    fn opt_approx_eq(self, other: Option<InnerRhs>, epsilon: Self::Inner) -> Option<bool> {
+        other.and_then(|inner_other| self.opt_approx_eq(inner_other, epsilon))
+    }
+
+    #[inline]
+    fn opt_approx_eq_ulps(self, other: Option<InnerRhs>, max_ulps: u32) -> Option<bool> {
+        other.and_then(|inner_other| self.opt_approx_eq_ulps(inner_other, max_ulps))
+    }
+}
+
+impl<T, Rhs> OptionApproxEq<Rhs> for Option<T>
+where
+    T: OptionOperations + OptionApproxEq<Rhs>,
+{
+    type Inner = <T as OptionApproxEq<Rhs>>::Inner;
+
+    #[inline]
+    fn opt_approx_eq(self, other: Rhs, epsilon: Self::Inner) -> Option<bool> {
+        self.and_then(|inner_self| inner_self.opt_approx_eq(other, epsilon))
+    }
+
+    #[inline]
+    fn opt_approx_eq_ulps(self, other: Rhs, max_ulps: u32) -> Option<bool> {
+        self.and_then(|inner_self| inner_self.opt_approx_eq_ulps(other, max_ulps))
+    }
+}
+
+impl<T, InnerRhs> OptionApproxEq<Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + OptionApproxEq<InnerRhs>,
+{
+    type Inner = <T as OptionApproxEq<InnerRhs>>::Inner;
+
+    #[inline]
+    fn opt_approx_eq(self, other: Option<InnerRhs>, epsilon: Self::Inner) -> Option<bool> {
+        self.zip(other)
+            .and_then(|(inner_self, inner_other)| inner_self.opt_approx_eq(inner_other, epsilon))
+    }
+
+    #[inline]
+    fn opt_approx_eq_ulps(self, other: Option<InnerRhs>, max_ulps: u32) -> Option<bool> {
+        self.zip(other).and_then(|(inner_self, inner_other)| {
+            inner_self.opt_approx_eq_ulps(inner_other, max_ulps)
+        })
+    }
+}
+
+#[inline]
+fn is_within_ulps<U: PartialOrd + From<u32>>(distance: U, max_ulps: u32) -> bool {
+    distance <= U::from(max_ulps)
+}
+
+impl_for_floats!(OptionApproxEq, {
+    type Inner = Self;
+
+    #[inline]
+    fn opt_approx_eq(self, other: Self, epsilon: Self) -> Option<bool> {
+        // `inf - inf` is `NaN`, so equal infinities need a dedicated check
+        Some(self == other || (self - other).abs() <= epsilon)
+    }
+
+    #[inline]
+    fn opt_approx_eq_ulps(self, other: Self, max_ulps: u32) -> Option<bool> {
+        if self.is_nan() || other.is_nan() {
+            return Some(false);
+        }
+        if self.is_sign_negative() != other.is_sign_negative() {
+            // Only `0.0` and `-0.0` are equal across signs
+            return Some(self == other);
+        }
+
+        Some(is_within_ulps(
+            self.to_bits().abs_diff(other.to_bits()),
+            max_ulps,
+        ))
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::OptionApproxEq;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyFloat(f64);
+
+    impl OptionOperations for MyFloat {}
+
+    impl OptionApproxEq for MyFloat {
+        type Inner = f64;
+
+        fn opt_approx_eq(self, other: MyFloat, epsilon: f64) -> Option<bool> {
+            self.0.opt_approx_eq(other.0, epsilon)
+        }
+
+        fn opt_approx_eq_ulps(self, other: MyFloat, max_ulps: u32) -> Option<bool> {
+            self.0.opt_approx_eq_ulps(other.0, max_ulps)
+        }
+    }
+
+    const MY_1: MyFloat = MyFloat(1.0);
+    const MY_1_PLUS: MyFloat = MyFloat(1.0 + 1e-10);
+    const MY_2: MyFloat = MyFloat(2.0);
+    const SOME_1: Option<MyFloat> = Some(MY_1);
+    const SOME_1_PLUS: Option<MyFloat> = Some(MY_1_PLUS);
+    const NONE: Option<MyFloat> = None;
+
+    #[test]
+    fn approx_eq_my() {
+        assert_eq!(MY_1.opt_approx_eq(MY_1_PLUS, 1e-9), Some(true));
+        assert_eq!(MY_1.opt_approx_eq(SOME_1_PLUS, 1e-9), Some(true));
+        assert_eq!(SOME_1.opt_approx_eq(MY_1_PLUS, 1e-11), Some(false));
+        assert_eq!(SOME_1.opt_approx_eq(SOME_1_PLUS, 1e-9), Some(true));
+        assert_eq!(MY_1.opt_approx_eq(MY_2, 0.5), Some(false));
+        assert_eq!(MY_1.opt_approx_eq(NONE, 1e-9), None);
+        assert_eq!(NONE.opt_approx_eq(MY_1, 1e-9), None);
+        assert_eq!(NONE.opt_approx_eq(NONE, 1e-9), None);
+    }
+
+    #[test]
+    fn approx_eq_ulps_my() {
+        assert_eq!(MY_1.opt_approx_eq_ulps(MY_1, 0), Some(true));
+        assert_eq!(MY_1.opt_approx_eq_ulps(MY_1_PLUS, 1), Some(false));
+        assert_eq!(SOME_1.opt_approx_eq_ulps(SOME_1_PLUS, 500_000), Some(true));
+        assert_eq!(SOME_1.opt_approx_eq_ulps(NONE, 500_000), None);
+        assert_eq!(NONE.opt_approx_eq_ulps(SOME_1, 500_000), None);
+    }
+
+    #[test]
+    fn absolute_vs_ulps_near_zero() {
+        let tiny = Some(1e-10f64);
+        let tinier = Some(2e-10f64);
+
+        // Both are within an absolute epsilon of each other...
+        assert_eq!(tiny.opt_approx_eq(tinier, 1e-9), Some(true));
+        // ... but they are far apart in terms of representable values.
+        assert_eq!(tiny.opt_approx_eq_ulps(tinier, 4), Some(false));
+
+        // Conversely, large neighbouring values are not within a small
+        // absolute epsilon, while they are 1 ULP apart.
+        let large = 1e16f64;
+        let next = f64::from_bits(large.to_bits() + 1);
+        assert_eq!(Some(large).opt_approx_eq(next, 1e-9), Some(false));
+        assert_eq!(Some(large).opt_approx_eq_ulps(next, 1), Some(true));
+
+        assert_eq!(Some(0.0f32).opt_approx_eq_ulps(-0.0, 0), Some(true));
+        assert_eq!(
+            Some(f32::MIN_POSITIVE).opt_approx_eq_ulps(-f32::MIN_POSITIVE, 4),
+            Some(false)
+        );
+        assert_eq!(
+            Some(f32::MIN_POSITIVE).opt_approx_eq(-f32::MIN_POSITIVE, 1e-6),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn approx_eq_nan() {
+        assert_eq!(Some(f64::NAN).opt_approx_eq(f64::NAN, 1.0), Some(false));
+        assert_eq!(
+            Some(f32::NAN).opt_approx_eq_ulps(f32::NAN, u32::MAX),
+            Some(false)
+        );
+        assert_eq!(Some(1.0f32).opt_approx_eq(Some(f32::NAN), 1.0), Some(false));
+    }
+
+    #[test]
+    fn approx_eq_infinity() {
+        assert_eq!(
+            Some(f64::INFINITY).opt_approx_eq(f64::INFINITY, 0.0),
+            Some(true)
+        );
+        assert_eq!(
+            f32::NEG_INFINITY.opt_approx_eq(Some(f32::NEG_INFINITY), 1e-6),
+            Some(true)
+        );
+        assert_eq!(
+            Some(f64::INFINITY).opt_approx_eq(f64::NEG_INFINITY, f64::MAX),
+            Some(false)
+        );
+        assert_eq!(
+            Some(f64::INFINITY).opt_approx_eq(f64::MAX, f64::MAX),
+            Some(false)
+        );
+        assert_eq!(
+            Some(f64::INFINITY).opt_approx_eq_ulps(f64::INFINITY, 0),
+            Some(true)
+        );
+        assert_eq!(
+            Some(f32::NEG_INFINITY).opt_approx_eq_ulps(f32::NEG_INFINITY, 0),
+            Some(true)
+        );
+    }
+}
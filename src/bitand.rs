@@ -0,0 +1,169 @@
+//! Traits for the bitwise AND [`OptionOperations`].
+
+use core::ops::{BitAnd, BitAndAssign};
+
+use crate::OptionOperations;
+
+option_op!(BitAnd, bitand, "bitwise AND");
+
+option_op_assign!(BitAnd, bitand, "bitwise AND");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+    use core::ops::{BitAnd, BitAndAssign};
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl BitAnd<MyInt> for MyInt {
+        type Output = MyInt;
+
+        fn bitand(self, rhs: MyInt) -> MyInt {
+            MyInt(self.0.bitand(rhs.0))
+        }
+    }
+
+    impl BitAnd<u64> for MyInt {
+        type Output = MyInt;
+
+        fn bitand(self, rhs: u64) -> MyInt {
+            MyInt(self.0.bitand(rhs))
+        }
+    }
+
+    impl BitAndAssign<MyInt> for MyInt {
+        fn bitand_assign(&mut self, rhs: MyInt) {
+            self.0.bitand_assign(rhs.0)
+        }
+    }
+
+    impl BitAndAssign<u64> for MyInt {
+        fn bitand_assign(&mut self, rhs: u64) {
+            self.0.bitand_assign(rhs)
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const MY_3: MyInt = MyInt(3);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn bitand_my() {
+        assert_eq!(MY_3.opt_bitand(MY_1), SOME_1);
+        assert_eq!(SOME_3.opt_bitand(MY_2), SOME_2);
+        assert_eq!(MY_1.opt_bitand(SOME_2), SOME_0);
+        assert_eq!(MY_3.opt_bitand(&SOME_3), SOME_3);
+        assert_eq!(SOME_3.opt_bitand(SOME_1), SOME_1);
+        assert_eq!(SOME_3.opt_bitand(&SOME_2), SOME_2);
+        assert_eq!(MY_3.opt_bitand(NONE), NONE);
+        assert_eq!(NONE.opt_bitand(MY_3), NONE);
+        assert_eq!(NONE.opt_bitand(SOME_3), NONE);
+    }
+
+    #[test]
+    fn bitand_u64() {
+        assert_eq!(MY_3.opt_bitand(1), SOME_1);
+        assert_eq!(MY_3.opt_bitand(Some(2)), SOME_2);
+        assert_eq!(SOME_1.opt_bitand(2), SOME_0);
+        assert_eq!(SOME_3.opt_bitand(Some(3)), SOME_3);
+        assert_eq!(SOME_3.opt_bitand(&Some(1)), SOME_1);
+        assert_eq!(MY_3.opt_bitand(Option::<u64>::None), NONE);
+        assert_eq!(Option::<MyInt>::None.opt_bitand(3), NONE);
+    }
+
+    #[test]
+    fn bitand_primitive() {
+        assert_eq!(0b1100u8.opt_bitand(Some(0b1010)), Some(0b1000));
+        assert_eq!(Some(0b1100u8).opt_bitand(0b0011), Some(0));
+        assert_eq!(Some(-1i32).opt_bitand(Some(0x7f)), Some(0x7f));
+        assert_eq!(Some(0b1100u8).opt_bitand(Option::<u8>::None), None);
+    }
+
+    #[test]
+    fn bitand_assign_my() {
+        let mut my = MY_3;
+        my.opt_bitand_assign(MY_1);
+        assert_eq!(my, MY_1);
+
+        let mut some = SOME_3;
+        some.opt_bitand_assign(MY_2);
+        assert_eq!(some, SOME_2);
+
+        let mut my = MY_3;
+        my.opt_bitand_assign(SOME_2);
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_1;
+        my.opt_bitand_assign(&SOME_2);
+        assert_eq!(my, MY_0);
+
+        let mut my = MY_3;
+        my.opt_bitand_assign(NONE);
+        assert_eq!(my, MY_3);
+
+        let mut some = SOME_3;
+        some.opt_bitand_assign(SOME_1);
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_3;
+        some.opt_bitand_assign(&SOME_2);
+        assert_eq!(some, SOME_2);
+
+        let mut some = SOME_3;
+        some.opt_bitand_assign(NONE);
+        assert_eq!(some, SOME_3);
+
+        let mut none = NONE;
+        none.opt_bitand_assign(SOME_1);
+        assert_eq!(none, NONE);
+
+        let mut none = NONE;
+        none.opt_bitand_assign(NONE);
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    fn bitand_assign_u64() {
+        let mut my = MY_3;
+        my.opt_bitand_assign(1);
+        assert_eq!(my, MY_1);
+
+        let mut some = SOME_3;
+        some.opt_bitand_assign(2);
+        assert_eq!(some, SOME_2);
+
+        let mut my = MY_3;
+        my.opt_bitand_assign(Some(2));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_1;
+        my.opt_bitand_assign(&Some(2));
+        assert_eq!(my, MY_0);
+
+        let mut some = SOME_3;
+        some.opt_bitand_assign(Some(1));
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_3;
+        some.opt_bitand_assign(&Some(3));
+        assert_eq!(some, SOME_3);
+
+        let mut some = SOME_3;
+        some.opt_bitand_assign(Option::<u64>::None);
+        assert_eq!(some, SOME_3);
+
+        let mut none = NONE;
+        none.opt_bitand_assign(Some(1));
+        assert_eq!(none, NONE);
+    }
+}
@@ -0,0 +1,169 @@
+//! Traits for the bitwise OR [`OptionOperations`].
+
+use core::ops::{BitOr, BitOrAssign};
+
+use crate::OptionOperations;
+
+option_op!(BitOr, bitor, "bitwise OR");
+
+option_op_assign!(BitOr, bitor, "bitwise OR");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+    use core::ops::{BitOr, BitOrAssign};
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl BitOr<MyInt> for MyInt {
+        type Output = MyInt;
+
+        fn bitor(self, rhs: MyInt) -> MyInt {
+            MyInt(self.0.bitor(rhs.0))
+        }
+    }
+
+    impl BitOr<u64> for MyInt {
+        type Output = MyInt;
+
+        fn bitor(self, rhs: u64) -> MyInt {
+            MyInt(self.0.bitor(rhs))
+        }
+    }
+
+    impl BitOrAssign<MyInt> for MyInt {
+        fn bitor_assign(&mut self, rhs: MyInt) {
+            self.0.bitor_assign(rhs.0)
+        }
+    }
+
+    impl BitOrAssign<u64> for MyInt {
+        fn bitor_assign(&mut self, rhs: u64) {
+            self.0.bitor_assign(rhs)
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const MY_3: MyInt = MyInt(3);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn bitor_my() {
+        assert_eq!(MY_1.opt_bitor(MY_2), SOME_3);
+        assert_eq!(SOME_1.opt_bitor(MY_2), SOME_3);
+        assert_eq!(MY_0.opt_bitor(SOME_2), SOME_2);
+        assert_eq!(MY_3.opt_bitor(&SOME_1), SOME_3);
+        assert_eq!(SOME_2.opt_bitor(SOME_1), SOME_3);
+        assert_eq!(SOME_0.opt_bitor(&SOME_0), SOME_0);
+        assert_eq!(MY_3.opt_bitor(NONE), NONE);
+        assert_eq!(NONE.opt_bitor(MY_3), NONE);
+        assert_eq!(NONE.opt_bitor(SOME_3), NONE);
+    }
+
+    #[test]
+    fn bitor_u64() {
+        assert_eq!(MY_1.opt_bitor(2), SOME_3);
+        assert_eq!(MY_0.opt_bitor(Some(2)), SOME_2);
+        assert_eq!(SOME_2.opt_bitor(1), SOME_3);
+        assert_eq!(SOME_1.opt_bitor(Some(1)), SOME_1);
+        assert_eq!(SOME_2.opt_bitor(&Some(1)), SOME_3);
+        assert_eq!(MY_3.opt_bitor(Option::<u64>::None), NONE);
+        assert_eq!(Option::<MyInt>::None.opt_bitor(3), NONE);
+    }
+
+    #[test]
+    fn bitor_primitive() {
+        assert_eq!(0b1100u8.opt_bitor(Some(0b1010)), Some(0b1110));
+        assert_eq!(Some(0b1100u8).opt_bitor(0b0011), Some(0b1111));
+        assert_eq!(Some(i32::MIN).opt_bitor(Some(0x7f)), Some(i32::MIN + 0x7f));
+        assert_eq!(Some(0b1100u8).opt_bitor(Option::<u8>::None), None);
+    }
+
+    #[test]
+    fn bitor_assign_my() {
+        let mut my = MY_1;
+        my.opt_bitor_assign(MY_2);
+        assert_eq!(my, MY_3);
+
+        let mut some = SOME_2;
+        some.opt_bitor_assign(MY_1);
+        assert_eq!(some, SOME_3);
+
+        let mut my = MY_0;
+        my.opt_bitor_assign(SOME_2);
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_1;
+        my.opt_bitor_assign(&SOME_2);
+        assert_eq!(my, MY_3);
+
+        let mut my = MY_1;
+        my.opt_bitor_assign(NONE);
+        assert_eq!(my, MY_1);
+
+        let mut some = SOME_1;
+        some.opt_bitor_assign(SOME_1);
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_1;
+        some.opt_bitor_assign(&SOME_2);
+        assert_eq!(some, SOME_3);
+
+        let mut some = SOME_1;
+        some.opt_bitor_assign(NONE);
+        assert_eq!(some, SOME_1);
+
+        let mut none = NONE;
+        none.opt_bitor_assign(SOME_1);
+        assert_eq!(none, NONE);
+
+        let mut none = NONE;
+        none.opt_bitor_assign(NONE);
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    fn bitor_assign_u64() {
+        let mut my = MY_1;
+        my.opt_bitor_assign(2);
+        assert_eq!(my, MY_3);
+
+        let mut some = SOME_2;
+        some.opt_bitor_assign(1);
+        assert_eq!(some, SOME_3);
+
+        let mut my = MY_0;
+        my.opt_bitor_assign(Some(2));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_1;
+        my.opt_bitor_assign(&Some(2));
+        assert_eq!(my, MY_3);
+
+        let mut some = SOME_1;
+        some.opt_bitor_assign(Some(1));
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_1;
+        some.opt_bitor_assign(&Some(2));
+        assert_eq!(some, SOME_3);
+
+        let mut some = SOME_1;
+        some.opt_bitor_assign(Option::<u64>::None);
+        assert_eq!(some, SOME_1);
+
+        let mut none = NONE;
+        none.opt_bitor_assign(Some(1));
+        assert_eq!(none, NONE);
+    }
+}
@@ -0,0 +1,125 @@
+//! Traits for the bit inspection [`OptionOperations`].
+
+use crate::OptionOperations;
+
+option_unary_op_custom!(
+    LeadingZeros,
+    leading_zeros,
+    "number of leading zeros in the binary representation",
+);
+
+impl_for_ints!(OptionLeadingZeros, {
+    type Output = u32;
+    #[inline]
+    fn opt_leading_zeros(self) -> Option<Self::Output> {
+        Some(self.leading_zeros())
+    }
+});
+
+option_unary_op_custom!(
+    TrailingZeros,
+    trailing_zeros,
+    "number of trailing zeros in the binary representation",
+);
+
+impl_for_ints!(OptionTrailingZeros, {
+    type Output = u32;
+    #[inline]
+    fn opt_trailing_zeros(self) -> Option<Self::Output> {
+        Some(self.trailing_zeros())
+    }
+});
+
+option_unary_op_custom!(
+    CountOnes,
+    count_ones,
+    "number of ones in the binary representation",
+);
+
+impl_for_ints!(OptionCountOnes, {
+    type Output = u32;
+    #[inline]
+    fn opt_count_ones(self) -> Option<Self::Output> {
+        Some(self.count_ones())
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionLeadingZeros for MyInt {
+        type Output = u32;
+        fn opt_leading_zeros(self) -> Option<Self::Output> {
+            self.0.opt_leading_zeros()
+        }
+    }
+
+    impl OptionTrailingZeros for MyInt {
+        type Output = u32;
+        fn opt_trailing_zeros(self) -> Option<Self::Output> {
+            self.0.opt_trailing_zeros()
+        }
+    }
+
+    impl OptionCountOnes for MyInt {
+        type Output = u32;
+        fn opt_count_ones(self) -> Option<Self::Output> {
+            self.0.opt_count_ones()
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_12: MyInt = MyInt(0b1100);
+    const MY_MAX: MyInt = MyInt(u64::MAX);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_12: Option<MyInt> = Some(MY_12);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn leading_zeros() {
+        assert_eq!(MY_12.opt_leading_zeros(), Some(60));
+        assert_eq!(MY_MAX.opt_leading_zeros(), Some(0));
+        assert_eq!(SOME_12.opt_leading_zeros(), Some(60));
+        assert_eq!(SOME_0.opt_leading_zeros(), Some(64));
+        assert_eq!(NONE.opt_leading_zeros(), None);
+
+        assert_eq!(Some(0u8).opt_leading_zeros(), Some(8));
+        assert_eq!(Some(0u128).opt_leading_zeros(), Some(128));
+        assert_eq!(Some(0i32).opt_leading_zeros(), Some(32));
+        assert_eq!(Some(-1i16).opt_leading_zeros(), Some(0));
+        assert_eq!(Option::<u32>::None.opt_leading_zeros(), None);
+    }
+
+    #[test]
+    fn trailing_zeros() {
+        assert_eq!(MY_12.opt_trailing_zeros(), Some(2));
+        assert_eq!(MY_MAX.opt_trailing_zeros(), Some(0));
+        assert_eq!(SOME_12.opt_trailing_zeros(), Some(2));
+        assert_eq!(SOME_0.opt_trailing_zeros(), Some(64));
+        assert_eq!(NONE.opt_trailing_zeros(), None);
+
+        assert_eq!(Some(0u16).opt_trailing_zeros(), Some(16));
+        assert_eq!(Some(i64::MIN).opt_trailing_zeros(), Some(63));
+        assert_eq!(Option::<i8>::None.opt_trailing_zeros(), None);
+    }
+
+    #[test]
+    fn count_ones() {
+        assert_eq!(MY_12.opt_count_ones(), Some(2));
+        assert_eq!(MY_MAX.opt_count_ones(), Some(64));
+        assert_eq!(SOME_12.opt_count_ones(), Some(2));
+        assert_eq!(SOME_0.opt_count_ones(), Some(0));
+        assert_eq!(NONE.opt_count_ones(), None);
+
+        assert_eq!(Some(u8::MAX).opt_count_ones(), Some(8));
+        assert_eq!(Some(-1i128).opt_count_ones(), Some(128));
+        assert_eq!(Option::<u32>::None.opt_count_ones(), None);
+    }
+}
@@ -0,0 +1,169 @@
+//! Traits for the bitwise XOR [`OptionOperations`].
+
+use core::ops::{BitXor, BitXorAssign};
+
+use crate::OptionOperations;
+
+option_op!(BitXor, bitxor, "bitwise XOR");
+
+option_op_assign!(BitXor, bitxor, "bitwise XOR");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+    use core::ops::{BitXor, BitXorAssign};
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl BitXor<MyInt> for MyInt {
+        type Output = MyInt;
+
+        fn bitxor(self, rhs: MyInt) -> MyInt {
+            MyInt(self.0.bitxor(rhs.0))
+        }
+    }
+
+    impl BitXor<u64> for MyInt {
+        type Output = MyInt;
+
+        fn bitxor(self, rhs: u64) -> MyInt {
+            MyInt(self.0.bitxor(rhs))
+        }
+    }
+
+    impl BitXorAssign<MyInt> for MyInt {
+        fn bitxor_assign(&mut self, rhs: MyInt) {
+            self.0.bitxor_assign(rhs.0)
+        }
+    }
+
+    impl BitXorAssign<u64> for MyInt {
+        fn bitxor_assign(&mut self, rhs: u64) {
+            self.0.bitxor_assign(rhs)
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const MY_3: MyInt = MyInt(3);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn bitxor_my() {
+        assert_eq!(MY_1.opt_bitxor(MY_2), SOME_3);
+        assert_eq!(SOME_3.opt_bitxor(MY_2), SOME_1);
+        assert_eq!(MY_3.opt_bitxor(SOME_3), SOME_0);
+        assert_eq!(MY_3.opt_bitxor(&SOME_1), SOME_2);
+        assert_eq!(SOME_2.opt_bitxor(SOME_1), SOME_3);
+        assert_eq!(SOME_2.opt_bitxor(&SOME_0), SOME_2);
+        assert_eq!(MY_3.opt_bitxor(NONE), NONE);
+        assert_eq!(NONE.opt_bitxor(MY_3), NONE);
+        assert_eq!(NONE.opt_bitxor(SOME_3), NONE);
+    }
+
+    #[test]
+    fn bitxor_u64() {
+        assert_eq!(MY_1.opt_bitxor(2), SOME_3);
+        assert_eq!(MY_3.opt_bitxor(Some(2)), SOME_1);
+        assert_eq!(SOME_3.opt_bitxor(1), SOME_2);
+        assert_eq!(SOME_1.opt_bitxor(Some(1)), SOME_0);
+        assert_eq!(SOME_2.opt_bitxor(&Some(1)), SOME_3);
+        assert_eq!(MY_3.opt_bitxor(Option::<u64>::None), NONE);
+        assert_eq!(Option::<MyInt>::None.opt_bitxor(3), NONE);
+    }
+
+    #[test]
+    fn bitxor_primitive() {
+        assert_eq!(0b1100u8.opt_bitxor(Some(0b1010)), Some(0b0110));
+        assert_eq!(Some(0b1100u8).opt_bitxor(0b1111), Some(0b0011));
+        assert_eq!(Some(-1i32).opt_bitxor(Some(-1)), Some(0));
+        assert_eq!(Some(0b1100u8).opt_bitxor(Option::<u8>::None), None);
+    }
+
+    #[test]
+    fn bitxor_assign_my() {
+        let mut my = MY_1;
+        my.opt_bitxor_assign(MY_2);
+        assert_eq!(my, MY_3);
+
+        let mut some = SOME_3;
+        some.opt_bitxor_assign(MY_1);
+        assert_eq!(some, SOME_2);
+
+        let mut my = MY_3;
+        my.opt_bitxor_assign(SOME_3);
+        assert_eq!(my, MY_0);
+
+        let mut my = MY_1;
+        my.opt_bitxor_assign(&SOME_2);
+        assert_eq!(my, MY_3);
+
+        let mut my = MY_1;
+        my.opt_bitxor_assign(NONE);
+        assert_eq!(my, MY_1);
+
+        let mut some = SOME_1;
+        some.opt_bitxor_assign(SOME_1);
+        assert_eq!(some, SOME_0);
+
+        let mut some = SOME_3;
+        some.opt_bitxor_assign(&SOME_2);
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_1;
+        some.opt_bitxor_assign(NONE);
+        assert_eq!(some, SOME_1);
+
+        let mut none = NONE;
+        none.opt_bitxor_assign(SOME_1);
+        assert_eq!(none, NONE);
+
+        let mut none = NONE;
+        none.opt_bitxor_assign(NONE);
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    fn bitxor_assign_u64() {
+        let mut my = MY_1;
+        my.opt_bitxor_assign(2);
+        assert_eq!(my, MY_3);
+
+        let mut some = SOME_3;
+        some.opt_bitxor_assign(1);
+        assert_eq!(some, SOME_2);
+
+        let mut my = MY_3;
+        my.opt_bitxor_assign(Some(3));
+        assert_eq!(my, MY_0);
+
+        let mut my = MY_1;
+        my.opt_bitxor_assign(&Some(2));
+        assert_eq!(my, MY_3);
+
+        let mut some = SOME_1;
+        some.opt_bitxor_assign(Some(1));
+        assert_eq!(some, SOME_0);
+
+        let mut some = SOME_3;
+        some.opt_bitxor_assign(&Some(2));
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_1;
+        some.opt_bitxor_assign(Option::<u64>::None);
+        assert_eq!(some, SOME_1);
+
+        let mut none = NONE;
+        none.opt_bitxor_assign(Some(1));
+        assert_eq!(none, NONE);
+    }
+}
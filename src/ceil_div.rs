@@ -0,0 +1,200 @@
+//! Traits for the ceiling division [`OptionOperations`].
+
+use crate::{Error, OptionOperations, OptionRoundedDiv, RoundingMode};
+
+option_op_custom!(
+    CeilDiv,
+    ceil_div,
+    "ceiling division",
+    "
+The quotient is rounded toward positive infinity.
+
+# Panics
+
+Most implementations will panic if `rhs` is zero.
+",
+);
+
+impl_for_ints!(OptionCeilDiv, {
+    type Output = Self;
+    #[inline]
+    fn opt_ceil_div(self, rhs: Self) -> Option<Self::Output> {
+        self.opt_rounded_div(rhs, RoundingMode::Ceil)
+    }
+});
+
+option_op_checked!(
+    CeilDiv,
+    ceil_div,
+    "ceiling division",
+    "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero.",
+);
+
+impl_for_ints!(OptionCheckedCeilDiv, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_ceil_div(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        if rhs == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        // The quotient can only overflow for `MIN / -1`. Otherwise, it can't
+        // be incremented past `MAX`, since it's rounded only when the remainder
+        // is non-zero, i.e. when `|rhs| > 1`.
+        self.checked_div(rhs).ok_or(Error::Overflow)?;
+        Ok(self.opt_rounded_div(rhs, RoundingMode::Ceil))
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionCeilDiv for MyInt {
+        type Output = MyInt;
+        fn opt_ceil_div(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_ceil_div(rhs.0).map(MyInt)
+        }
+    }
+
+    impl OptionCeilDiv<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_ceil_div(self, rhs: i64) -> Option<Self::Output> {
+            self.0.opt_ceil_div(rhs).map(MyInt)
+        }
+    }
+
+    impl OptionCheckedCeilDiv for MyInt {
+        type Output = MyInt;
+        fn opt_checked_ceil_div(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_ceil_div(rhs.0).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    impl OptionCheckedCeilDiv<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_ceil_div(self, rhs: i64) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_ceil_div(rhs).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    const MY_MINUS_7: MyInt = MyInt(-7);
+    const MY_MINUS_3: MyInt = MyInt(-3);
+    const MY_MINUS_2: MyInt = MyInt(-2);
+    const MY_MINUS_1: MyInt = MyInt(-1);
+    const MY_0: MyInt = MyInt(0);
+    const MY_2: MyInt = MyInt(2);
+    const MY_3: MyInt = MyInt(3);
+    const MY_4: MyInt = MyInt(4);
+    const MY_7: MyInt = MyInt(7);
+    const MY_8: MyInt = MyInt(8);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const SOME_MINUS_7: Option<MyInt> = Some(MY_MINUS_7);
+    const SOME_MINUS_3: Option<MyInt> = Some(MY_MINUS_3);
+    const SOME_MINUS_1: Option<MyInt> = Some(MY_MINUS_1);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const SOME_4: Option<MyInt> = Some(MY_4);
+    const SOME_7: Option<MyInt> = Some(MY_7);
+    const SOME_8: Option<MyInt> = Some(MY_8);
+    const SOME_MIN: Option<MyInt> = Some(MY_MIN);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn ceil_div_my() {
+        assert_eq!(MY_7.opt_ceil_div(MY_2), SOME_4);
+        assert_eq!(MY_8.opt_ceil_div(SOME_2), SOME_4);
+        assert_eq!(MY_7.opt_ceil_div(&SOME_2), SOME_4);
+        assert_eq!(SOME_7.opt_ceil_div(MY_2), SOME_4);
+        assert_eq!(SOME_7.opt_ceil_div(SOME_2), SOME_4);
+        assert_eq!(SOME_7.opt_ceil_div(&SOME_2), SOME_4);
+        assert_eq!(SOME_7.opt_ceil_div(2), SOME_4);
+        assert_eq!(SOME_0.opt_ceil_div(Some(2)), SOME_0);
+
+        assert_eq!(MY_7.opt_ceil_div(NONE), NONE);
+        assert_eq!(SOME_7.opt_ceil_div(Option::<i64>::None), NONE);
+        assert_eq!(NONE.opt_ceil_div(MY_2), NONE);
+        assert_eq!(NONE.opt_ceil_div(SOME_2), NONE);
+    }
+
+    #[test]
+    fn ceil_div_negative() {
+        // -7 / 2 = -3.5: ceiling and truncation agree.
+        assert_eq!(MY_MINUS_7.opt_ceil_div(MY_2), SOME_MINUS_3);
+        assert_eq!(-7 / 2, -3);
+        // 7 / -2 = -3.5
+        assert_eq!(MY_7.opt_ceil_div(MY_MINUS_2), SOME_MINUS_3);
+        // -7 / -2 = 3.5: ceiling rounds up where truncation rounds down.
+        assert_eq!(SOME_MINUS_7.opt_ceil_div(MY_MINUS_2), SOME_4);
+        assert_eq!(-7 / -2, 3);
+        // Exact divisions.
+        assert_eq!(Some(-8i32).opt_ceil_div(Some(2)), Some(-4));
+        assert_eq!(Some(-8i32).opt_ceil_div(Some(-2)), Some(4));
+        assert_eq!(Some(-1i8).opt_ceil_div(Some(i8::MIN)), Some(1));
+        assert_eq!(Some(1i8).opt_ceil_div(Some(i8::MIN)), Some(0));
+    }
+
+    #[test]
+    fn ceil_div_unsigned() {
+        // Number of 4 KiB pages for an optional total size.
+        let page_size = 4_096u64;
+        assert_eq!(Some(10_000u64).opt_ceil_div(page_size), Some(3));
+        assert_eq!(Some(8_192u64).opt_ceil_div(page_size), Some(2));
+        assert_eq!(Some(0u64).opt_ceil_div(page_size), Some(0));
+        assert_eq!(Option::<u64>::None.opt_ceil_div(page_size), None);
+
+        assert_eq!(Some(u8::MAX).opt_ceil_div(2), Some(128));
+        assert_eq!(u32::MAX.opt_ceil_div(Some(u32::MAX)), Some(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ceil_div_by_zero_my() {
+        let _ = SOME_7.opt_ceil_div(SOME_0);
+    }
+
+    #[test]
+    fn checked_ceil_div() {
+        assert_eq!(MY_7.opt_checked_ceil_div(MY_2), Ok(SOME_4));
+        assert_eq!(MY_7.opt_checked_ceil_div(SOME_2), Ok(SOME_4));
+        assert_eq!(MY_7.opt_checked_ceil_div(&SOME_2), Ok(SOME_4));
+        assert_eq!(SOME_MINUS_7.opt_checked_ceil_div(2), Ok(SOME_MINUS_3));
+        assert_eq!(SOME_MINUS_7.opt_checked_ceil_div(Some(-2)), Ok(SOME_4));
+        assert_eq!(SOME_7.opt_checked_ceil_div(&Some(7)), Ok(Some(MyInt(1))));
+        assert_eq!(SOME_8.opt_checked_ceil_div(SOME_3), Ok(SOME_3));
+
+        assert_eq!(MY_7.opt_checked_ceil_div(MY_0), Err(Error::DivisionByZero));
+        assert_eq!(
+            SOME_7.opt_checked_ceil_div(Some(0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            MY_MIN.opt_checked_ceil_div(MY_MINUS_1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            SOME_MIN.opt_checked_ceil_div(SOME_MINUS_1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Some(i64::MIN).opt_checked_ceil_div(2),
+            Ok(Some(i64::MIN / 2))
+        );
+        assert_eq!(Some(i64::MAX).opt_checked_ceil_div(2), Ok(Some(1 << 62)));
+
+        assert_eq!(Some(10u8).opt_checked_ceil_div(3), Ok(Some(4)));
+        assert_eq!(
+            Some(10u8).opt_checked_ceil_div(0),
+            Err(Error::DivisionByZero)
+        );
+
+        assert_eq!(MY_7.opt_checked_ceil_div(NONE), Ok(None));
+        assert_eq!(NONE.opt_checked_ceil_div(SOME_0), Ok(None));
+    }
+}
@@ -0,0 +1,456 @@
+//! Traits for the clamp [`OptionOperations`].
+
+// Required for doc
+#[allow(unused)]
+use crate::OptionOperations;
+
+use core::ops::RangeInclusive;
+
+use crate::OptionOrd;
+
+/// Trait for values and `Option`s that can be restricted to an interval.
+///
+/// Implementing this type leads to the following auto-implementations:
+///
+/// - `OptionClamp<Min, Max> for T`.
+/// - `OptionClamp<Min, Max> for Option<T>`.
+///
+/// where `Min` and `Max` can be either `T` or `Option<T>`.
+///
+/// This trait is auto-implemented for [`OptionOperations`] types
+/// implementing `OptionOrd<Rhs>`.
+pub trait OptionClamp<Min, Max = Min> {
+    /// The resulting inner type after clamping.
+    type Output;
+
+    /// Restricts a value to a certain interval.
+    ///
+    /// - Returns `max` if `self` is greater than `max`.
+    /// - Returns `min` if `self` is less than `min`.
+    /// - Otherwise, returns `self`.
+    ///
+    /// If `min` is `None`, there is no lower bound. If `max` is `None`,
+    /// there is no upper bound. Returns `None` if `self` is `None`.
+    ///
+    /// ```
+    /// # use option_operations::OptionClamp;
+    /// assert_eq!(Some(12).opt_clamp(Some(0), Some(10)), Some(10));
+    /// assert_eq!(Some(12).opt_clamp(Some(0), None), Some(12));
+    /// assert_eq!(Some(-2).opt_clamp(Some(0), None), Some(0));
+    /// assert_eq!(Option::<i32>::None.opt_clamp(Some(0), Some(10)), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if both `min` and `max` are defined and `min > max`
+    /// or if they can't be compared, even if `self` is `None`.
+    #[must_use]
+    fn opt_clamp(self, min: Min, max: Max) -> Option<Self::Output>;
+}
+
+fn clamp<T>(mut value: T, min: Option<T>, max: Option<T>) -> T
+where
+    T: for<'a> OptionOrd<&'a T, T>,
+{
+    clamp_assign(&mut value, min, max);
+    value
+}
+
+fn clamp_assign<T>(value: &mut T, min: Option<T>, max: Option<T>)
+where
+    T: for<'a> OptionOrd<&'a T, T>,
+{
+    assert_bounds(&min, &max);
+    clamp_assign_within(value, min, max);
+}
+
+/// Panics if both bounds are defined and `min > max` or if they can't be compared.
+///
+/// This is checked even if the value to clamp is `None`, like `Ord::clamp` would.
+fn assert_bounds<T>(min: &Option<T>, max: &Option<T>)
+where
+    T: for<'a> OptionOrd<&'a T, T>,
+{
+    if let (Some(min), Some(max)) = (min, max) {
+        assert!(
+            min.opt_le(max) == Some(true),
+            "min > max, or either was not comparable"
+        );
+    }
+}
+
+/// Clamps `value`, assuming the bounds were checked with [`assert_bounds`].
+fn clamp_assign_within<T>(value: &mut T, min: Option<T>, max: Option<T>)
+where
+    T: for<'a> OptionOrd<&'a T, T>,
+{
+    if let Some(min) = min {
+        if (*value).opt_lt(&min) == Some(true) {
+            *value = min;
+            return;
+        }
+    }
+
+    if let Some(max) = max {
+        if (*value).opt_gt(&max) == Some(true) {
+            *value = max;
+        }
+    }
+}
+
+impl<T, Min, Max> OptionClamp<Min, Max> for T
+where
+    T: OptionOperations + for<'a> OptionOrd<&'a T, T>,
+    Min: Into<Option<T>>,
+    Max: Into<Option<T>>,
+{
+    type Output = T;
+
+    #[inline]
+    fn opt_clamp(self, min: Min, max: Max) -> Option<T> {
+        Some(clamp(self, min.into(), max.into()))
+    }
+}
+
+impl<T, Min, Max> OptionClamp<Min, Max> for Option<T>
+where
+    T: OptionOperations + for<'a> OptionOrd<&'a T, T>,
+    Min: Into<Option<T>>,
+    Max: Into<Option<T>>,
+{
+    type Output = T;
+
+    #[inline]
+    fn opt_clamp(self, min: Min, max: Max) -> Option<T> {
+        let (min, max) = (min.into(), max.into());
+        assert_bounds(&min, &max);
+
+        self.map(|mut inner_self| {
+            clamp_assign_within(&mut inner_self, min, max);
+            inner_self
+        })
+    }
+}
+
+/// Trait for values and `Option`s that can be restricted to a [`RangeInclusive`].
+///
+/// Implementing this type leads to the following auto-implementations:
+///
+/// - `OptionClampToRange<T> for T`.
+/// - `OptionClampToRange<T> for Option<T>`.
+///
+/// This trait is auto-implemented for [`OptionOperations`] types
+/// implementing `OptionOrd<Rhs>`.
+pub trait OptionClampToRange<T> {
+    /// The resulting inner type after clamping.
+    type Output;
+
+    /// Restricts a value to `range`.
+    ///
+    /// - Returns the end of `range` if `self` is greater than it.
+    /// - Returns the start of `range` if `self` is less than it.
+    /// - Otherwise, returns `self`.
+    ///
+    /// Returns `None` if `self` is `None`. See also [`OptionClamp`] for
+    /// optional bounds.
+    ///
+    /// ```
+    /// # use option_operations::OptionClampToRange;
+    /// assert_eq!(Some(120).opt_clamp_range(0..=100), Some(100));
+    /// assert_eq!(Some(-5).opt_clamp_range(0..=100), Some(0));
+    /// assert_eq!(Some(42).opt_clamp_range(0..=100), Some(42));
+    /// assert_eq!(Option::<i32>::None.opt_clamp_range(0..=100), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is greater than its end
+    /// or if they can't be compared, even if `self` is `None`.
+    #[must_use]
+    fn opt_clamp_range(self, range: RangeInclusive<T>) -> Option<Self::Output>;
+}
+
+impl<T> OptionClampToRange<T> for T
+where
+    T: OptionOperations + for<'a> OptionOrd<&'a T, T>,
+{
+    type Output = T;
+
+    #[inline]
+    fn opt_clamp_range(self, range: RangeInclusive<T>) -> Option<T> {
+        let (min, max) = range.into_inner();
+        Some(clamp(self, Some(min), Some(max)))
+    }
+}
+
+impl<T> OptionClampToRange<T> for Option<T>
+where
+    T: OptionOperations + for<'a> OptionOrd<&'a T, T>,
+{
+    type Output = T;
+
+    #[inline]
+    fn opt_clamp_range(self, range: RangeInclusive<T>) -> Option<T> {
+        let (min, max) = range.into_inner();
+        let (min, max) = (Some(min), Some(max));
+        assert_bounds(&min, &max);
+
+        self.map(|mut inner_self| {
+            clamp_assign_within(&mut inner_self, min, max);
+            inner_self
+        })
+    }
+}
+
+/// Trait for values and `Option`s that can be restricted to an interval in place.
+///
+/// Implementing this type leads to the following auto-implementations:
+///
+/// - `OptionClampAssign<Min, Max> for T`.
+/// - `OptionClampAssign<Min, Max> for Option<T>`.
+///
+/// where `Min` and `Max` can be either `T` or `Option<T>`.
+///
+/// This trait is auto-implemented for [`OptionOperations`] types
+/// implementing `OptionOrd<Rhs>`.
+pub trait OptionClampAssign<Min, Max = Min> {
+    /// Restricts a value to a certain interval in place.
+    ///
+    /// - Sets `self` to `max` if `self` is greater than `max`.
+    /// - Sets `self` to `min` if `self` is less than `min`.
+    ///
+    /// If `min` is `None`, there is no lower bound. If `max` is `None`,
+    /// there is no upper bound. `self` is unchanged if it is `None`.
+    ///
+    /// ```
+    /// # use option_operations::OptionClampAssign;
+    /// let mut value = Some(12);
+    /// value.opt_clamp_assign(Some(0), Some(10));
+    /// assert_eq!(value, Some(10));
+    ///
+    /// let mut value = Option::<i32>::None;
+    /// value.opt_clamp_assign(Some(0), Some(10));
+    /// assert_eq!(value, None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if both `min` and `max` are defined and `min > max`
+    /// or if they can't be compared, even if `self` is `None`.
+    fn opt_clamp_assign(&mut self, min: Min, max: Max);
+}
+
+impl<T, Min, Max> OptionClampAssign<Min, Max> for T
+where
+    T: OptionOperations + for<'a> OptionOrd<&'a T, T>,
+    Min: Into<Option<T>>,
+    Max: Into<Option<T>>,
+{
+    #[inline]
+    fn opt_clamp_assign(&mut self, min: Min, max: Max) {
+        clamp_assign(self, min.into(), max.into())
+    }
+}
+
+impl<T, Min, Max> OptionClampAssign<Min, Max> for Option<T>
+where
+    T: OptionOperations + for<'a> OptionOrd<&'a T, T>,
+    Min: Into<Option<T>>,
+    Max: Into<Option<T>>,
+{
+    #[inline]
+    fn opt_clamp_assign(&mut self, min: Min, max: Max) {
+        let (min, max) = (min.into(), max.into());
+        assert_bounds(&min, &max);
+
+        if let Some(inner_self) = self {
+            clamp_assign_within(inner_self, min, max)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{OptionClamp, OptionClampAssign, OptionClampToRange};
+    use crate::OptionOperations;
+    use core::ops::RangeInclusive;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const MY_3: MyInt = MyInt(3);
+    const MY_5: MyInt = MyInt(5);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const SOME_5: Option<MyInt> = Some(MY_5);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn clamp() {
+        assert_eq!(MY_1.opt_clamp(MY_2, MY_3), SOME_2);
+        assert_eq!(MY_2.opt_clamp(MY_1, MY_3), SOME_2);
+        assert_eq!(MY_5.opt_clamp(MY_1, MY_3), SOME_3);
+        assert_eq!(MY_5.opt_clamp(SOME_1, SOME_3), SOME_3);
+        assert_eq!(MY_5.opt_clamp(MY_1, SOME_3), SOME_3);
+        assert_eq!(MY_1.opt_clamp(SOME_2, MY_3), SOME_2);
+
+        assert_eq!(SOME_1.opt_clamp(MY_2, MY_3), SOME_2);
+        assert_eq!(SOME_2.opt_clamp(SOME_1, SOME_3), SOME_2);
+        assert_eq!(SOME_5.opt_clamp(SOME_1, MY_3), SOME_3);
+
+        assert_eq!(NONE.opt_clamp(MY_1, MY_3), NONE);
+        assert_eq!(NONE.opt_clamp(SOME_1, NONE), NONE);
+    }
+
+    #[test]
+    fn clamp_unbounded() {
+        assert_eq!(MY_5.opt_clamp(NONE, MY_3), SOME_3);
+        assert_eq!(MY_1.opt_clamp(NONE, MY_3), SOME_1);
+        assert_eq!(MY_1.opt_clamp(MY_2, NONE), SOME_2);
+        assert_eq!(MY_5.opt_clamp(MY_2, NONE), SOME_5);
+        assert_eq!(SOME_5.opt_clamp(NONE, NONE), SOME_5);
+        assert_eq!(SOME_1.opt_clamp(SOME_2, NONE), SOME_2);
+        assert_eq!(SOME_5.opt_clamp(NONE, SOME_3), SOME_3);
+    }
+
+    #[test]
+    fn clamp_primitive() {
+        assert_eq!(Some(12u32).opt_clamp(Some(0), Some(10)), Some(10));
+        assert_eq!(12i64.opt_clamp(None, Some(10)), Some(10));
+        assert_eq!(Some(-1.5f64).opt_clamp(Some(0.0), None), Some(0.0));
+        assert_eq!(Option::<u8>::None.opt_clamp(Some(0), Some(10)), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_min_greater_than_max() {
+        let _ = SOME_2.opt_clamp(MY_3, MY_1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_none_min_greater_than_max() {
+        let _ = NONE.opt_clamp(MY_3, SOME_1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_nan_bound() {
+        let _ = Some(1.0f64).opt_clamp(Some(f64::NAN), Some(2.0));
+    }
+
+    #[test]
+    fn clamp_range() {
+        assert_eq!(MY_1.opt_clamp_range(MY_2..=MY_3), SOME_2);
+        assert_eq!(MY_2.opt_clamp_range(MY_1..=MY_3), SOME_2);
+        assert_eq!(MY_5.opt_clamp_range(MY_1..=MY_3), SOME_3);
+        assert_eq!(SOME_1.opt_clamp_range(MY_2..=MY_3), SOME_2);
+        assert_eq!(SOME_5.opt_clamp_range(MY_1..=MY_3), SOME_3);
+        assert_eq!(NONE.opt_clamp_range(MY_1..=MY_3), NONE);
+
+        // Inclusive endpoints.
+        assert_eq!(MY_1.opt_clamp_range(MY_1..=MY_3), SOME_1);
+        assert_eq!(SOME_3.opt_clamp_range(MY_1..=MY_3), SOME_3);
+        assert_eq!(SOME_2.opt_clamp_range(MY_2..=MY_2), SOME_2);
+        assert_eq!(SOME_5.opt_clamp_range(MY_2..=MY_2), SOME_2);
+    }
+
+    #[test]
+    fn clamp_range_primitive() {
+        assert_eq!(Some(120u8).opt_clamp_range(0..=100), Some(100));
+        assert_eq!(Some(100u8).opt_clamp_range(0..=100), Some(100));
+        assert_eq!((-3i64).opt_clamp_range(-2..=2), Some(-2));
+        assert_eq!(Some(0.5f64).opt_clamp_range(0.0..=1.0), Some(0.5));
+        assert_eq!(Option::<u8>::None.opt_clamp_range(0..=100), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_range_inverted() {
+        let _ = SOME_2.opt_clamp_range(MY_3..=MY_1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_range_none_inverted() {
+        let _ = Option::<u8>::None.opt_clamp_range(RangeInclusive::new(100, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_range_nan_bound() {
+        let _ = Some(1.0f64).opt_clamp_range(0.0..=f64::NAN);
+    }
+
+    #[test]
+    fn clamp_assign() {
+        let mut my = MY_1;
+        my.opt_clamp_assign(MY_2, MY_3);
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_5;
+        my.opt_clamp_assign(SOME_1, SOME_3);
+        assert_eq!(my, MY_3);
+
+        let mut some = SOME_2;
+        some.opt_clamp_assign(MY_1, SOME_3);
+        assert_eq!(some, SOME_2);
+
+        let mut some = SOME_5;
+        some.opt_clamp_assign(SOME_1, MY_3);
+        assert_eq!(some, SOME_3);
+
+        let mut none = NONE;
+        none.opt_clamp_assign(MY_1, MY_3);
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    fn clamp_assign_unbounded() {
+        // Only a lower bound.
+        let mut some = SOME_1;
+        some.opt_clamp_assign(SOME_2, NONE);
+        assert_eq!(some, SOME_2);
+
+        let mut some = SOME_5;
+        some.opt_clamp_assign(MY_2, NONE);
+        assert_eq!(some, SOME_5);
+
+        // Only an upper bound.
+        let mut some = SOME_5;
+        some.opt_clamp_assign(NONE, SOME_3);
+        assert_eq!(some, SOME_3);
+
+        let mut my = MY_1;
+        my.opt_clamp_assign(NONE, MY_3);
+        assert_eq!(my, MY_1);
+
+        // No bounds.
+        let mut some = SOME_5;
+        some.opt_clamp_assign(NONE, NONE);
+        assert_eq!(some, SOME_5);
+
+        let mut none = NONE;
+        none.opt_clamp_assign(NONE, SOME_3);
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_assign_min_greater_than_max() {
+        let mut some = SOME_2;
+        some.opt_clamp_assign(MY_3, MY_1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn clamp_assign_none_min_greater_than_max() {
+        let mut none = NONE;
+        none.opt_clamp_assign(SOME_3, MY_1);
+    }
+}
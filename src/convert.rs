@@ -0,0 +1,255 @@
+//! Traits for the conversion [`OptionOperations`].
+
+use core::convert::TryInto;
+use core::str::FromStr;
+
+use crate::{Error, OptionOperations};
+
+/// Trait for values and `Option`s fallible conversions.
+///
+/// This is helpful to narrow the result of a checked operation while
+/// sticking to the [`Error`] type:
+///
+/// ```
+/// # use option_operations::{Error, OptionCheckedAdd, OptionTryInto};
+/// let some_i64 = Some(40i64);
+///
+/// assert_eq!(
+///     some_i64.opt_checked_add(2).and_then(|res| res.opt_try_into::<u32>()),
+///     Ok(Some(42u32)),
+/// );
+/// assert_eq!(
+///     some_i64.opt_checked_add(-42).and_then(|res| res.opt_try_into::<u32>()),
+///     Err(Error::Overflow),
+/// );
+/// ```
+///
+/// This trait is auto-implemented for [`OptionOperations`] types and `Option`s
+/// of [`OptionOperations`] types.
+pub trait OptionTryInto: Sized {
+    /// The inner type to convert from.
+    type Inner;
+
+    /// Converts the inner value into `U`.
+    ///
+    /// - Returns `Ok(Some(value))` if the value could be converted.
+    /// - Returns `Ok(None)` if `self` is `None`.
+    /// - Returns `Err(Error::Overflow)` if the value doesn't fit in `U`.
+    ///
+    /// Any `U` implementing `TryFrom<Self::Inner>` is supported, since
+    /// `TryFrom` implies `TryInto`.
+    #[doc(alias = "opt_try_convert")]
+    #[doc(alias = "opt_try_from")]
+    fn opt_try_into<U>(self) -> Result<Option<U>, Error>
+    where
+        Self::Inner: TryInto<U>;
+}
+
+impl<T> OptionTryInto for T
+where
+    T: OptionOperations,
+{
+    type Inner = T;
+
+    #[inline]
+    fn opt_try_into<U>(self) -> Result<Option<U>, Error>
+    where
+        T: TryInto<U>,
+    {
+        self.try_into().map_err(|_| Error::Overflow).map(Some)
+    }
+}
+
+impl<T> OptionTryInto for Option<T>
+where
+    T: OptionOperations,
+{
+    type Inner = T;
+
+    #[inline]
+    fn opt_try_into<U>(self) -> Result<Option<U>, Error>
+    where
+        T: TryInto<U>,
+    {
+        self.map(|inner_self| inner_self.try_into().map_err(|_| Error::Overflow))
+            .transpose()
+    }
+}
+
+/// Parses an optional string into an `Option<T>`.
+///
+/// This is helpful when reading optional fields, e.g. from a configuration:
+///
+/// ```
+/// # use option_operations::opt_parse;
+/// assert_eq!(opt_parse::<i64>(Some("42")), Ok(Some(42)));
+/// assert!(opt_parse::<i64>(Some("x")).is_err());
+/// assert_eq!(opt_parse::<i64>(None), Ok(None));
+/// ```
+///
+/// - Returns `Ok(Some(value))` if the string could be parsed.
+/// - Returns `Ok(None)` if `s` is `None`.
+/// - Returns `Err(err)` with the parse error of `T` otherwise.
+#[inline]
+pub fn opt_parse<T: FromStr>(s: Option<&str>) -> Result<Option<T>, T::Err> {
+    s.map(str::parse).transpose()
+}
+
+option_unary_op_checked!(
+    CastUnsigned,
+    cast_unsigned,
+    "conversion to the unsigned integer type of the same size",
+    "- Returns `Err(Error::Underflow)` if `self` is negative, instead of
+wrapping around as `as` or `cast_unsigned` would.
+
+Unlike [`OptionTryInto`], the target type is implied by the source type
+and negative values are reported as such rather than as an overflow.",
+);
+
+macro_rules! impl_checked_cast_unsigned {
+    ($($signed:ty => $unsigned:ty),+ $(,)?) => {
+        $(
+            impl OptionCheckedCastUnsigned for $signed {
+                type Output = $unsigned;
+
+                #[inline]
+                fn opt_checked_cast_unsigned(self) -> Result<Option<Self::Output>, Error> {
+                    if self < 0 {
+                        return Err(Error::Underflow);
+                    }
+                    Ok(Some(self as $unsigned))
+                }
+            }
+        )+
+    };
+}
+
+impl_checked_cast_unsigned!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize,
+);
+
+#[cfg(test)]
+mod test {
+    use core::convert::TryFrom;
+
+    use super::{opt_parse, OptionCheckedCastUnsigned, OptionTryInto};
+    use crate::{Error, OptionOperations};
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyNarrowInt(u8);
+
+    impl TryFrom<MyInt> for MyNarrowInt {
+        type Error = core::num::TryFromIntError;
+
+        fn try_from(my: MyInt) -> Result<Self, Self::Error> {
+            u8::try_from(my.0).map(MyNarrowInt)
+        }
+    }
+
+    const MY_1: MyInt = MyInt(1);
+    const MY_MINUS_1: MyInt = MyInt(-1);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_256: Option<MyInt> = Some(MyInt(256));
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn try_into_my() {
+        assert_eq!(MY_1.opt_try_into::<MyNarrowInt>(), Ok(Some(MyNarrowInt(1))));
+        assert_eq!(
+            SOME_1.opt_try_into::<MyNarrowInt>(),
+            Ok(Some(MyNarrowInt(1)))
+        );
+        assert_eq!(
+            MY_MINUS_1.opt_try_into::<MyNarrowInt>(),
+            Err(Error::Overflow)
+        );
+        assert_eq!(SOME_256.opt_try_into::<MyNarrowInt>(), Err(Error::Overflow));
+        assert_eq!(NONE.opt_try_into::<MyNarrowInt>(), Ok(None));
+    }
+
+    #[test]
+    fn try_into_primitive() {
+        assert_eq!(Some(42i64).opt_try_into::<u32>(), Ok(Some(42u32)));
+        assert_eq!(42i64.opt_try_into::<u8>(), Ok(Some(42u8)));
+        assert_eq!(Some(-1i64).opt_try_into::<u32>(), Err(Error::Overflow));
+        assert_eq!(Some(i64::MAX).opt_try_into::<u32>(), Err(Error::Overflow));
+        assert_eq!(Some(300u16).opt_try_into::<u8>(), Err(Error::Overflow));
+        assert_eq!(Some(255u8).opt_try_into::<u64>(), Ok(Some(255u64)));
+        assert_eq!(Option::<i64>::None.opt_try_into::<u32>(), Ok(None));
+    }
+
+    #[test]
+    fn try_into_after_checked_op() {
+        use crate::OptionCheckedMul;
+
+        let narrow = |lhs: Option<i64>, rhs: Option<i64>| -> Result<Option<u16>, Error> {
+            lhs.opt_checked_mul(rhs)?.opt_try_into::<u16>()
+        };
+
+        assert_eq!(narrow(Some(200), Some(300)), Ok(Some(60_000)));
+        assert_eq!(narrow(Some(300), Some(300)), Err(Error::Overflow));
+        assert_eq!(narrow(Some(i64::MAX), Some(2)), Err(Error::Overflow));
+        assert_eq!(narrow(None, Some(2)), Ok(None));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(opt_parse::<i64>(Some("42")), Ok(Some(42)));
+        assert_eq!(opt_parse::<i64>(Some("-7")), Ok(Some(-7)));
+        assert_eq!(opt_parse::<i64>(None), Ok(None));
+        assert_eq!(opt_parse::<f64>(Some("1.5")), Ok(Some(1.5)));
+
+        let err = opt_parse::<i64>(Some("x")).unwrap_err();
+        assert_eq!(err, "x".parse::<i64>().unwrap_err());
+        assert!(opt_parse::<u8>(Some("256")).is_err());
+    }
+
+    impl OptionCheckedCastUnsigned for MyInt {
+        type Output = u64;
+        fn opt_checked_cast_unsigned(self) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_cast_unsigned()
+        }
+    }
+
+    #[test]
+    fn checked_cast_unsigned() {
+        assert_eq!(MY_1.opt_checked_cast_unsigned(), Ok(Some(1u64)));
+        assert_eq!(SOME_256.opt_checked_cast_unsigned(), Ok(Some(256u64)));
+        assert_eq!(
+            MY_MINUS_1.opt_checked_cast_unsigned(),
+            Err(Error::Underflow)
+        );
+        assert_eq!(NONE.opt_checked_cast_unsigned(), Ok(None));
+
+        assert_eq!(Some(5i64).opt_checked_cast_unsigned(), Ok(Some(5u64)));
+        assert_eq!(
+            Some(-1i64).opt_checked_cast_unsigned(),
+            Err(Error::Underflow)
+        );
+        assert_eq!(Some(0i8).opt_checked_cast_unsigned(), Ok(Some(0u8)));
+        assert_eq!(Some(i8::MAX).opt_checked_cast_unsigned(), Ok(Some(127u8)));
+        assert_eq!(
+            Some(i8::MIN).opt_checked_cast_unsigned(),
+            Err(Error::Underflow)
+        );
+        assert_eq!(
+            Some(i128::MAX).opt_checked_cast_unsigned(),
+            Ok(Some(i128::MAX as u128))
+        );
+        assert_eq!(
+            Some(-3isize).opt_checked_cast_unsigned(),
+            Err(Error::Underflow)
+        );
+        assert_eq!(Option::<i32>::None.opt_checked_cast_unsigned(), Ok(None));
+    }
+}
@@ -0,0 +1,103 @@
+//! Traits for the sign copy [`OptionOperations`].
+
+use crate::OptionOperations;
+
+option_op_custom!(
+    Copysign,
+    copysign,
+    "sign copy",
+    "
+The result has the magnitude of `self` and the sign of `rhs`. The sign
+is transferred even if `rhs` is `-0.0` or `NaN`.
+",
+);
+
+impl_for_floats!(OptionCopysign, {
+    type Output = Self;
+    #[inline]
+    fn opt_copysign(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.copysign(rhs))
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyFloat(f64);
+
+    impl OptionOperations for MyFloat {}
+
+    impl OptionCopysign for MyFloat {
+        type Output = MyFloat;
+        fn opt_copysign(self, rhs: MyFloat) -> Option<Self::Output> {
+            self.0.opt_copysign(rhs.0).map(MyFloat)
+        }
+    }
+
+    impl OptionCopysign<f64> for MyFloat {
+        type Output = MyFloat;
+        fn opt_copysign(self, rhs: f64) -> Option<Self::Output> {
+            self.0.opt_copysign(rhs).map(MyFloat)
+        }
+    }
+
+    const MY_MINUS_2: MyFloat = MyFloat(-2.0);
+    const MY_MINUS_1: MyFloat = MyFloat(-1.0);
+    const MY_1: MyFloat = MyFloat(1.0);
+    const MY_2: MyFloat = MyFloat(2.0);
+    const SOME_MINUS_2: Option<MyFloat> = Some(MY_MINUS_2);
+    const SOME_MINUS_1: Option<MyFloat> = Some(MY_MINUS_1);
+    const SOME_1: Option<MyFloat> = Some(MY_1);
+    const SOME_2: Option<MyFloat> = Some(MY_2);
+    const NONE: Option<MyFloat> = None;
+
+    #[test]
+    fn copysign_my() {
+        assert_eq!(MY_2.opt_copysign(MY_MINUS_1), SOME_MINUS_2);
+        assert_eq!(MY_2.opt_copysign(SOME_MINUS_1), SOME_MINUS_2);
+        assert_eq!(MY_MINUS_2.opt_copysign(&SOME_1), SOME_2);
+        assert_eq!(SOME_MINUS_2.opt_copysign(MY_1), SOME_2);
+        assert_eq!(SOME_2.opt_copysign(SOME_MINUS_1), SOME_MINUS_2);
+        assert_eq!(SOME_2.opt_copysign(&SOME_1), SOME_2);
+        assert_eq!(SOME_2.opt_copysign(-3.0), SOME_MINUS_2);
+        assert_eq!(SOME_MINUS_2.opt_copysign(Some(3.0)), SOME_2);
+
+        assert_eq!(MY_2.opt_copysign(NONE), NONE);
+        assert_eq!(SOME_2.opt_copysign(Option::<f64>::None), NONE);
+        assert_eq!(NONE.opt_copysign(MY_1), NONE);
+        assert_eq!(NONE.opt_copysign(SOME_MINUS_1), NONE);
+    }
+
+    #[test]
+    fn copysign_signed_zero() {
+        let res = Some(2.5f64).opt_copysign(-0.0).unwrap();
+        assert_eq!(res, -2.5);
+
+        let res = Some(2.5f32).opt_copysign(Some(0.0)).unwrap();
+        assert_eq!(res, 2.5);
+
+        // The magnitude can be a signed zero too.
+        let res = 0.0f64.opt_copysign(-1.0).unwrap();
+        assert_eq!(res, 0.0);
+        assert!(res.is_sign_negative());
+    }
+
+    #[test]
+    fn copysign_nan() {
+        let res = Some(f64::NAN).opt_copysign(Some(-1.0)).unwrap();
+        assert!(res.is_nan());
+        assert!(res.is_sign_negative());
+
+        let res = Some(-f32::NAN).opt_copysign(1.0).unwrap();
+        assert!(res.is_nan());
+        assert!(res.is_sign_positive());
+
+        // A NaN sign source still transfers its sign bit.
+        let res = Some(1.0f64).opt_copysign(-f64::NAN).unwrap();
+        assert_eq!(res, -1.0);
+        assert_eq!(Some(f64::NAN).opt_copysign(Option::<f64>::None), None);
+    }
+}
@@ -0,0 +1,134 @@
+//! Display wrapper for `Option`s.
+
+use core::fmt;
+
+use crate::OptionOperations;
+
+/// Wrapper displaying the inner value of an `Option` or a placeholder
+/// if it is `None`.
+///
+/// This is returned by [`OptionDisplay::opt_display`].
+///
+/// Formatting options such as the width and alignment apply to
+/// the inner value as well as to the placeholder.
+#[derive(Copy, Clone, Debug)]
+#[must_use]
+pub struct DisplayOr<'a, T> {
+    value: Option<&'a T>,
+    none: &'a str,
+}
+
+impl<'a, T> DisplayOr<'a, T> {
+    /// Builds a [`DisplayOr`] displaying `value` or `none` if it is `None`.
+    pub fn new(value: Option<&'a T>, none: &'a str) -> Self {
+        DisplayOr { value, none }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DisplayOr<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.value {
+            Some(value) => fmt::Display::fmt(value, f),
+            None => f.pad(self.none),
+        }
+    }
+}
+
+/// Trait for values and `Option`s which can be displayed with
+/// a placeholder for `None`.
+///
+/// ```
+/// # use option_operations::OptionDisplay;
+/// let rows = [("min", Some(5)), ("max", None)];
+///
+/// let mut out = String::new();
+/// for (name, value) in rows.iter() {
+///     out += &format!("{}: {:>3}\n", name, value.opt_display("-"));
+/// }
+/// assert_eq!(out, "min:   5\nmax:   -\n");
+/// ```
+///
+/// This trait is auto-implemented for [`OptionOperations`] types
+/// implementing `Display` and for `Option`s of such types.
+pub trait OptionDisplay {
+    /// The inner type to display.
+    type Inner;
+
+    /// Returns a wrapper which displays the inner value or `none`
+    /// if `self` is `None`.
+    fn opt_display<'a>(&'a self, none: &'a str) -> DisplayOr<'a, Self::Inner>;
+}
+
+impl<T> OptionDisplay for T
+where
+    T: OptionOperations + fmt::Display,
+{
+    type Inner = T;
+
+    #[inline]
+    fn opt_display<'a>(&'a self, none: &'a str) -> DisplayOr<'a, T> {
+        DisplayOr::new(Some(self), none)
+    }
+}
+
+impl<T> OptionDisplay for Option<T>
+where
+    T: OptionOperations + fmt::Display,
+{
+    type Inner = T;
+
+    #[inline]
+    fn opt_display<'a>(&'a self, none: &'a str) -> DisplayOr<'a, T> {
+        DisplayOr::new(self.as_ref(), none)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use alloc::format;
+    use core::fmt;
+
+    use super::OptionDisplay;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl fmt::Display for MyInt {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "#{}", self.0)
+        }
+    }
+
+    const SOME_5: Option<MyInt> = Some(MyInt(5));
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn display_or() {
+        assert_eq!(format!("{}", Some(5).opt_display("-")), "5");
+        assert_eq!(format!("{}", Option::<i32>::None.opt_display("-")), "-");
+        assert_eq!(format!("{}", 5.opt_display("-")), "5");
+        assert_eq!(format!("{}", Some(1.5f64).opt_display("n/a")), "1.5");
+
+        assert_eq!(format!("{}", SOME_5.opt_display("-")), "#5");
+        assert_eq!(format!("{}", NONE.opt_display("-")), "-");
+        assert_eq!(format!("{}", MyInt(5).opt_display("-")), "#5");
+    }
+
+    #[test]
+    fn display_or_formatting() {
+        assert_eq!(format!("{:>4}", Some(5).opt_display("-")), "   5");
+        assert_eq!(
+            format!("{:>4}", Option::<u8>::None.opt_display("-")),
+            "   -"
+        );
+        assert_eq!(
+            format!("{:<3}|", Option::<u8>::None.opt_display("-")),
+            "-  |"
+        );
+        assert_eq!(format!("{:.2}", Some(1.0f64).opt_display("-")), "1.00");
+        assert_eq!(format!("{:03}", Some(7u8).opt_display("-")), "007");
+    }
+}
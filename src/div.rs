@@ -1,4 +1,15 @@
 //! Traits for the division [`OptionOperations`].
+//!
+//! Besides the same-type divisions, [`OptionCheckedDivWidening`] divides
+//! integers by a narrower integer of the same signedness, e.g. `i64` by
+//! `i8`, `i16` or `i32` and `u128` by `u8`, `u16`, `u32` or `u64`:
+//!
+//! ```
+//! # use option_operations::OptionCheckedDivWidening;
+//! let lhs: Option<i64> = Some(-10);
+//! let rhs: Option<i32> = Some(2);
+//! assert_eq!(lhs.opt_checked_div_widening(rhs), Ok(Some(-5)));
+//! ```
 
 use core::ops::{Div, DivAssign};
 
@@ -15,8 +26,97 @@ Most implementations will panic if `rhs` is zero.
 ",
 );
 
+// `&T` receivers are covered by the auto-implementations since
+// `OptionOperations` is implemented for `&T`. The following allow
+// `&Option<T>` receivers for inner types implementing the division
+// traits on references. The assignment traits take `&mut self`.
+macro_rules! impl_ref_option_receivers {
+    ($trait:ident, $op:ident, $output:ty, $none:expr $(, $arg:ident: $arg_ty:ty)* $(,)?) => {
+        impl<'a, T, Rhs> $trait<Rhs> for &'a Option<T>
+        where
+            T: OptionOperations,
+            &'a T: $trait<Rhs>,
+        {
+            type Output = <&'a T as $trait<Rhs>>::Output;
+
+            #[inline]
+            fn $op(self, rhs: Rhs $(, $arg: $arg_ty)*) -> $output {
+                match self {
+                    Some(inner_self) => inner_self.$op(rhs $(, $arg)*),
+                    None => $none,
+                }
+            }
+        }
+
+        impl<'a, T, InnerRhs> $trait<Option<InnerRhs>, InnerRhs> for &'a Option<T>
+        where
+            T: OptionOperations,
+            &'a T: $trait<InnerRhs>,
+        {
+            type Output = <&'a T as $trait<InnerRhs>>::Output;
+
+            #[inline]
+            fn $op(self, rhs: Option<InnerRhs> $(, $arg: $arg_ty)*) -> $output {
+                match (self, rhs) {
+                    (Some(inner_self), Some(inner_rhs)) => inner_self.$op(inner_rhs $(, $arg)*),
+                    _ => $none,
+                }
+            }
+        }
+
+        impl<'a, 'b, T, InnerRhs> $trait<&'b Option<InnerRhs>, &'b InnerRhs> for &'a Option<T>
+        where
+            T: OptionOperations,
+            &'a T: $trait<&'b InnerRhs>,
+        {
+            type Output = <&'a T as $trait<&'b InnerRhs>>::Output;
+
+            #[inline]
+            fn $op(self, rhs: &'b Option<InnerRhs> $(, $arg: $arg_ty)*) -> $output {
+                match (self, rhs) {
+                    (Some(inner_self), Some(inner_rhs)) => inner_self.$op(inner_rhs $(, $arg)*),
+                    _ => $none,
+                }
+            }
+        }
+    };
+}
+
+impl_ref_option_receivers!(OptionDiv, opt_div, Option<Self::Output>, None);
+impl_ref_option_receivers!(
+    OptionOverflowingDiv,
+    opt_overflowing_div,
+    Option<(Self::Output, bool)>,
+    None
+);
+impl_ref_option_receivers!(
+    OptionWrappingDiv,
+    opt_wrapping_div,
+    Option<Self::Output>,
+    None
+);
+impl_ref_option_receivers!(
+    OptionSaturatingDiv,
+    opt_saturating_div,
+    Option<Self::Output>,
+    None
+);
+impl_ref_option_receivers!(
+    OptionCheckedDiv,
+    opt_checked_div,
+    Result<Option<Self::Output>, Error>,
+    Ok(None)
+);
+impl_ref_option_receivers!(
+    OptionCheckedDivWidening,
+    opt_checked_div_widening,
+    Result<Option<Self::Output>, Error>,
+    Ok(None)
+);
+
 impl_for_ints!(OptionOverflowingDiv, {
     type Output = Self;
+    #[inline]
     fn opt_overflowing_div(self, rhs: Self) -> Option<(Self::Output, bool)> {
         Some(self.overflowing_div(rhs))
     }
@@ -24,6 +124,7 @@ impl_for_ints!(OptionOverflowingDiv, {
 
 impl_for_ints!(OptionWrappingDiv, {
     type Output = Self;
+    #[inline]
     fn opt_wrapping_div(self, rhs: Self) -> Option<Self::Output> {
         Some(self.wrapping_div(rhs))
     }
@@ -33,11 +134,17 @@ option_op_checked!(
     Div,
     div,
     division,
-    "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero.",
+    "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero, including
+`-0.0` for floating point operations.
+- Returns `Err(Error::NotFinite)` if the result of a floating point operation
+is infinite or `NaN` while the operands are finite.
+- Returns `Err(Error::Underflow)` if a `Duration` is divided by a negative
+`f64`, and `Err(Error::Domain)` if the `f64` is infinite or `NaN`.",
 );
 
 impl_for_ints!(OptionCheckedDiv, {
     type Output = Self;
+    #[inline]
     fn opt_checked_div(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
         if rhs == 0 {
             return Err(Error::DivisionByZero);
@@ -46,8 +153,98 @@ impl_for_ints!(OptionCheckedDiv, {
     }
 });
 
+impl_for_floats!(OptionCheckedDiv, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_div(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        if rhs == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        let res = self.div(rhs);
+        if !res.is_finite() && self.is_finite() && rhs.is_finite() {
+            return Err(Error::NotFinite);
+        }
+        Ok(Some(res))
+    }
+});
+
+option_op_checked!(
+    DivWidening,
+    div_widening,
+    "division by a narrower integer",
+    "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero.
+
+The `rhs` is converted losslessly to the type of `self` before dividing.",
+);
+
+macro_rules! impl_checked_div_widening_for_ints {
+    ($($typ_:ty => $($rhs:ty),+);+ $(;)?) => {
+        $(
+            $(
+                impl OptionCheckedDivWidening<$rhs> for $typ_ {
+                    type Output = Self;
+                    #[inline]
+                    fn opt_checked_div_widening(
+                        self,
+                        rhs: $rhs,
+                    ) -> Result<Option<Self::Output>, Error> {
+                        self.opt_checked_div(<$typ_>::from(rhs))
+                    }
+                }
+            )+
+        )+
+    };
+}
+
+// Divisions by a narrower integer of the same signedness,
+// for which the conversion of `rhs` is lossless.
+impl_checked_div_widening_for_ints!(
+    i16 => i8;
+    i32 => i8, i16;
+    i64 => i8, i16, i32;
+    i128 => i8, i16, i32, i64;
+    u16 => u8;
+    u32 => u8, u16;
+    u64 => u8, u16, u32;
+    u128 => u8, u16, u32, u64;
+);
+
+// The divisor of a `NonZero*` type can't be zero, so this never
+// returns `Error::DivisionByZero`. The quotient can be zero though,
+// hence the primitive integer `Output`.
+macro_rules! impl_checked_div_for_non_zero_ints {
+    ($($typ_:ty => $output:ty),+ $(,)?) => {
+        $(
+            impl_for!(OptionCheckedDiv, $typ_, {
+                type Output = $output;
+                #[inline]
+                fn opt_checked_div(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+                    self.get().checked_div(rhs.get()).ok_or(Error::Overflow).map(Some)
+                }
+            });
+        )+
+    };
+}
+
+impl_checked_div_for_non_zero_ints!(
+    core::num::NonZeroI8 => i8,
+    core::num::NonZeroI16 => i16,
+    core::num::NonZeroI32 => i32,
+    core::num::NonZeroI64 => i64,
+    core::num::NonZeroI128 => i128,
+    core::num::NonZeroIsize => isize,
+    core::num::NonZeroU8 => u8,
+    core::num::NonZeroU16 => u16,
+    core::num::NonZeroU32 => u32,
+    core::num::NonZeroU64 => u64,
+    core::num::NonZeroU128 => u128,
+    core::num::NonZeroUsize => usize,
+);
+
+#[cfg(feature = "duration")]
 impl OptionCheckedDiv<u32> for core::time::Duration {
     type Output = Self;
+    #[inline]
     fn opt_checked_div(self, rhs: u32) -> Result<Option<Self::Output>, Error> {
         if rhs == 0 {
             return Err(Error::DivisionByZero);
@@ -56,6 +253,227 @@ impl OptionCheckedDiv<u32> for core::time::Duration {
     }
 }
 
+#[cfg(feature = "duration")]
+impl OptionCheckedDiv for core::time::Duration {
+    type Output = f64;
+    #[inline]
+    fn opt_checked_div(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        if rhs.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Some(self.div_duration_f64(rhs)))
+    }
+}
+
+#[cfg(feature = "duration")]
+impl OptionCheckedDiv<f64> for core::time::Duration {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_div(self, rhs: f64) -> Result<Option<Self::Output>, Error> {
+        if rhs == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        if !rhs.is_finite() {
+            return Err(Error::Domain);
+        }
+        let secs = self.as_secs_f64() / rhs;
+        if secs < 0.0 {
+            return Err(Error::Underflow);
+        }
+        core::time::Duration::try_from_secs_f64(secs)
+            .map_err(|_| Error::Overflow)
+            .map(Some)
+    }
+}
+
+option_op_checked_assign!(
+    Div,
+    div,
+    division,
+    "- Returns `Err(Error::Overflow)` if an overflow occured.",
+    "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero, including
+`-0.0` for floating point operations.
+- Returns `Err(Error::NotFinite)` if the result of a floating point operation
+is infinite or `NaN` while the operands are finite.",
+);
+
+impl_for_ints!(OptionCheckedDivAssign, {
+    #[inline]
+    fn opt_checked_div_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        if rhs == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        *self = self.checked_div(rhs).ok_or(Error::Overflow)?;
+        Ok(())
+    }
+});
+
+impl_for_floats!(OptionCheckedDivAssign, {
+    #[inline]
+    fn opt_checked_div_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        if let Some(res) = (*self).opt_checked_div(rhs)? {
+            *self = res;
+        }
+        Ok(())
+    }
+});
+
+option_op_saturating!(
+    Div,
+    div,
+    division,
+    "
+# Panics
+
+Most implementations will panic if `rhs` is zero.
+",
+);
+
+impl_for_ints!(OptionSaturatingDiv, {
+    type Output = Self;
+    #[inline]
+    fn opt_saturating_div(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.saturating_div(rhs))
+    }
+});
+
+/// Trait for values and `Option`s division with a fallback value.
+///
+/// This is a non-panicking alternative to [`OptionDiv`] which doesn't
+/// require handling the `Result` of [`OptionCheckedDiv`], nor implementing it.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionDivOr<Option<InnerRhs>>` for `T`.
+/// - `OptionDivOr<Rhs>` for `Option<T>`.
+/// - `OptionDivOr<Option<InnerRhs>>` for `Option<T>`.
+/// - ... and some variants with references.
+///
+/// This trait is implemented for the integer primitives, the floats and
+/// `Duration` divided by `u32`. Users must provide the base implementation
+/// for other inner types.
+pub trait OptionDivOr<Rhs = Self, InnerRhs = Rhs> {
+    /// The resulting inner type after applying the division.
+    type Output;
+
+    /// Computes the division, returning `default` if it can't be computed.
+    ///
+    /// - Returns `Some(result)` if `result` could be computed.
+    /// - Returns `Some(default)` if `rhs` is zero or if an overflow occured.
+    /// - Returns `None` if at least one argument is `None`.
+    ///
+    /// ```
+    /// # use option_operations::OptionDivOr;
+    /// assert_eq!(Some(10i32).opt_div_or(Some(2), 0), Some(5));
+    /// assert_eq!(Some(10i32).opt_div_or(Some(0), 0), Some(0));
+    /// assert_eq!(Some(10i32).opt_div_or(Option::<i32>::None, 0), None);
+    /// ```
+    #[must_use]
+    fn opt_div_or(self, rhs: Rhs, default: Self::Output) -> Option<Self::Output>;
+}
+
+impl<T, InnerRhs> OptionDivOr<Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + OptionDivOr<InnerRhs>,
+{
+    type Output = <T as OptionDivOr<InnerRhs>>::Output;
+
+    #[inline]
+    fn opt_div_or(self, rhs: Option<InnerRhs>, default: Self::Output) -> Option<Self::Output> {
+        rhs.and_then(|inner_rhs| self.opt_div_or(inner_rhs, default))
+    }
+}
+
+impl<T, InnerRhs> OptionDivOr<&Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + OptionDivOr<InnerRhs>,
+    InnerRhs: Copy,
+{
+    type Output = <T as OptionDivOr<InnerRhs>>::Output;
+
+    #[inline]
+    fn opt_div_or(self, rhs: &Option<InnerRhs>, default: Self::Output) -> Option<Self::Output> {
+        rhs.as_ref()
+            .and_then(|inner_rhs| self.opt_div_or(*inner_rhs, default))
+    }
+}
+
+impl<T, Rhs> OptionDivOr<Rhs> for Option<T>
+where
+    T: OptionOperations + OptionDivOr<Rhs>,
+{
+    type Output = <T as OptionDivOr<Rhs>>::Output;
+
+    #[inline]
+    fn opt_div_or(self, rhs: Rhs, default: Self::Output) -> Option<Self::Output> {
+        self.and_then(|inner_self| inner_self.opt_div_or(rhs, default))
+    }
+}
+
+impl<T, InnerRhs> OptionDivOr<Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + OptionDivOr<InnerRhs>,
+{
+    type Output = <T as OptionDivOr<InnerRhs>>::Output;
+
+    #[inline]
+    fn opt_div_or(self, rhs: Option<InnerRhs>, default: Self::Output) -> Option<Self::Output> {
+        self.zip(rhs)
+            .and_then(|(inner_self, inner_rhs)| inner_self.opt_div_or(inner_rhs, default))
+    }
+}
+
+impl<T, InnerRhs> OptionDivOr<&Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + OptionDivOr<InnerRhs>,
+    InnerRhs: Copy,
+{
+    type Output = <T as OptionDivOr<InnerRhs>>::Output;
+
+    #[inline]
+    fn opt_div_or(self, rhs: &Option<InnerRhs>, default: Self::Output) -> Option<Self::Output> {
+        self.zip(rhs.as_ref())
+            .and_then(|(inner_self, inner_rhs)| inner_self.opt_div_or(*inner_rhs, default))
+    }
+}
+
+impl_ref_option_receivers!(
+    OptionDivOr,
+    opt_div_or,
+    Option<Self::Output>,
+    None,
+    default: Self::Output,
+);
+
+// `checked_div` also catches `MIN / -1`, which would panic with `/`.
+impl_for_ints!(OptionDivOr, {
+    type Output = Self;
+    #[inline]
+    fn opt_div_or(self, rhs: Self, default: Self) -> Option<Self::Output> {
+        Some(self.checked_div(rhs).unwrap_or(default))
+    }
+});
+
+impl_for_floats!(OptionDivOr, {
+    type Output = Self;
+    #[inline]
+    fn opt_div_or(self, rhs: Self, default: Self) -> Option<Self::Output> {
+        Some(match self.opt_checked_div(rhs) {
+            Ok(Some(res)) => res,
+            _ => default,
+        })
+    }
+});
+
+#[cfg(feature = "duration")]
+impl OptionDivOr<u32> for core::time::Duration {
+    type Output = Self;
+    #[inline]
+    fn opt_div_or(self, rhs: u32, default: Self) -> Option<Self::Output> {
+        Some(self.checked_div(rhs).unwrap_or(default))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -243,22 +661,22 @@ mod test {
         some.opt_div_assign(Some(0));
     }
 
-    #[test]
-    fn checked_div() {
-        impl OptionCheckedDiv for MyInt {
-            type Output = MyInt;
-            fn opt_checked_div(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
-                self.0.opt_checked_div(rhs.0).map(|ok| ok.map(MyInt))
-            }
+    impl OptionCheckedDiv for MyInt {
+        type Output = MyInt;
+        fn opt_checked_div(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_div(rhs.0).map(|ok| ok.map(MyInt))
         }
+    }
 
-        impl OptionCheckedDiv<i64> for MyInt {
-            type Output = MyInt;
-            fn opt_checked_div(self, rhs: i64) -> Result<Option<Self::Output>, Error> {
-                self.0.opt_checked_div(rhs).map(|ok| ok.map(MyInt))
-            }
+    impl OptionCheckedDiv<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_div(self, rhs: i64) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_div(rhs).map(|ok| ok.map(MyInt))
         }
+    }
 
+    #[test]
+    fn checked_div() {
         assert_eq!(MY_2.opt_checked_div(MY_1), Ok(SOME_2));
         assert_eq!(MY_10.opt_checked_div(SOME_5), Ok(SOME_2));
         assert_eq!(MY_0.opt_checked_div(&SOME_1), Ok(SOME_0));
@@ -287,25 +705,149 @@ mod test {
     }
 
     #[test]
-    fn overflowing_div() {
-        impl OptionOverflowingDiv for MyInt {
-            type Output = MyInt;
-            fn opt_overflowing_div(self, rhs: MyInt) -> Option<(Self::Output, bool)> {
-                self.0
-                    .opt_overflowing_div(rhs.0)
-                    .map(|(val, flag)| (MyInt(val), flag))
-            }
+    fn checked_div_widening() {
+        assert_eq!(Some(-10i64).opt_checked_div_widening(2i32), Ok(Some(-5)));
+        assert_eq!(10i64.opt_checked_div_widening(Some(-2i32)), Ok(Some(-5)));
+        assert_eq!(
+            Some(i64::MAX).opt_checked_div_widening(Some(i32::MAX)),
+            Ok(Some(i64::MAX / i64::from(i32::MAX)))
+        );
+        assert_eq!(
+            Some(i64::MIN).opt_checked_div_widening(&Some(-1i8)),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Some(10i64).opt_checked_div_widening(0i32),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            Some(10i64).opt_checked_div_widening(Option::<i32>::None),
+            Ok(None)
+        );
+        assert_eq!(Option::<i64>::None.opt_checked_div_widening(0i16), Ok(None));
+
+        assert_eq!(
+            Some(u128::MAX).opt_checked_div_widening(Some(u64::MAX)),
+            Ok(Some(u128::from(u64::MAX) + 2))
+        );
+        assert_eq!(1_000u16.opt_checked_div_widening(10u8), Ok(Some(100)));
+        assert_eq!(
+            1_000u32.opt_checked_div_widening(Some(0u16)),
+            Err(Error::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn checked_div_literal_inference() {
+        assert_eq!(Some(10u64).opt_checked_div(2), Ok(Some(5)));
+        assert_eq!(Some(10u32).opt_checked_div(Some(2)), Ok(Some(5)));
+        assert_eq!(10u16.opt_checked_div(&Some(2)), Ok(Some(5)));
+        assert_eq!(Some(10u128).opt_checked_div(0), Err(Error::DivisionByZero));
+        assert_eq!(Some(-10i16).opt_checked_div(Some(2)), Ok(Some(-5)));
+    }
+
+    impl OptionDivOr for MyInt {
+        type Output = MyInt;
+        fn opt_div_or(self, rhs: MyInt, default: MyInt) -> Option<Self::Output> {
+            self.0.opt_div_or(rhs.0, default.0).map(MyInt)
         }
+    }
 
-        impl OptionOverflowingDiv<i64> for MyInt {
-            type Output = MyInt;
-            fn opt_overflowing_div(self, rhs: i64) -> Option<(Self::Output, bool)> {
-                self.0
-                    .opt_overflowing_div(rhs)
-                    .map(|(val, flag)| (MyInt(val), flag))
-            }
+    #[test]
+    fn div_or() {
+        assert_eq!(MY_10.opt_div_or(MY_5, MY_0), SOME_2);
+        assert_eq!(MY_10.opt_div_or(SOME_5, MY_0), SOME_2);
+        assert_eq!(SOME_10.opt_div_or(&SOME_5, MY_0), SOME_2);
+        assert_eq!(MY_10.opt_div_or(MY_0, MY_1), SOME_1);
+        assert_eq!(SOME_10.opt_div_or(SOME_0, MY_1), SOME_1);
+        assert_eq!(MY_10.opt_div_or(NONE, MY_1), NONE);
+        assert_eq!(NONE.opt_div_or(SOME_0, MY_1), NONE);
+
+        assert_eq!(Some(10i32).opt_div_or(Some(0), 0), Some(0));
+        assert_eq!(Some(10u8).opt_div_or(3, 0), Some(3));
+        assert_eq!(Some(i64::MIN).opt_div_or(-1, i64::MAX), Some(i64::MAX));
+        assert_eq!(Option::<u64>::None.opt_div_or(0, 0), None);
+        assert_eq!(10usize.opt_div_or(&Some(0), 7), Some(7));
+
+        assert_eq!(Some(1.0f64).opt_div_or(Some(0.0), -1.0), Some(-1.0));
+        assert_eq!(f32::MAX.opt_div_or(0.5, 0.0), Some(0.0));
+        assert_eq!(Some(3.0f32).opt_div_or(2.0, 0.0), Some(1.5));
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn div_or_duration() {
+        use core::time::Duration;
+
+        let secs_10 = Duration::from_secs(10);
+        assert_eq!(
+            secs_10.opt_div_or(Some(2), Duration::ZERO),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            Some(secs_10).opt_div_or(0, Duration::MAX),
+            Some(Duration::MAX)
+        );
+        assert_eq!(Option::<Duration>::None.opt_div_or(0, secs_10), None);
+    }
+
+    impl OptionSaturatingDiv for MyInt {
+        type Output = MyInt;
+        fn opt_saturating_div(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_saturating_div(rhs.0).map(MyInt)
         }
+    }
 
+    impl OptionSaturatingDiv<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_saturating_div(self, rhs: i64) -> Option<Self::Output> {
+            self.0.opt_saturating_div(rhs).map(MyInt)
+        }
+    }
+
+    #[test]
+    fn saturating_div() {
+        assert_eq!(MY_2.opt_saturating_div(MY_1), SOME_2);
+        assert_eq!(MY_10.opt_saturating_div(SOME_5), SOME_2);
+        assert_eq!(MY_0.opt_saturating_div(&SOME_1), SOME_0);
+        assert_eq!(MY_MAX.opt_saturating_div(MY_2), SOME_HALF_MAX);
+        assert_eq!(MY_MIN.opt_saturating_div(MY_MINUS_1), SOME_MAX);
+        assert_eq!(SOME_MIN.opt_saturating_div(MY_MINUS_1), SOME_MAX);
+        assert_eq!(SOME_MIN.opt_saturating_div(-1), SOME_MAX);
+        assert_eq!(SOME_MIN.opt_saturating_div(Some(-1)), SOME_MAX);
+        assert_eq!(SOME_MIN.opt_saturating_div(&Some(-1)), SOME_MAX);
+        assert_eq!(MY_MIN.opt_saturating_div(SOME_MINUS_1), SOME_MAX);
+        assert_eq!(MY_MIN.opt_saturating_div(&SOME_MINUS_1), SOME_MAX);
+        assert_eq!(MY_MIN.opt_saturating_div(NONE), None);
+        assert_eq!(NONE.opt_saturating_div(MY_MINUS_1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn saturating_div_by_zero() {
+        let _ = SOME_10.opt_saturating_div(SOME_0);
+    }
+
+    impl OptionOverflowingDiv for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_div(self, rhs: MyInt) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_div(rhs.0)
+                .map(|(val, flag)| (MyInt(val), flag))
+        }
+    }
+
+    impl OptionOverflowingDiv<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_div(self, rhs: i64) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_div(rhs)
+                .map(|(val, flag)| (MyInt(val), flag))
+        }
+    }
+
+    #[test]
+    fn overflowing_div() {
         assert_eq!(MY_2.opt_overflowing_div(MY_1), Some((MY_2, false)));
         assert_eq!(MY_0.opt_overflowing_div(MY_1), Some((MY_0, false)));
         assert_eq!(MY_MAX.opt_overflowing_div(MY_2), Some((MY_HALF_MAX, false)));
@@ -332,22 +874,22 @@ mod test {
         assert_eq!(NONE.opt_overflowing_div(MY_MIN), None);
     }
 
-    #[test]
-    fn wrapping_div() {
-        impl OptionWrappingDiv for MyInt {
-            type Output = MyInt;
-            fn opt_wrapping_div(self, rhs: MyInt) -> Option<Self::Output> {
-                self.0.opt_wrapping_div(rhs.0).map(MyInt)
-            }
+    impl OptionWrappingDiv for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_div(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_wrapping_div(rhs.0).map(MyInt)
         }
+    }
 
-        impl OptionWrappingDiv<i64> for MyInt {
-            type Output = MyInt;
-            fn opt_wrapping_div(self, rhs: i64) -> Option<Self::Output> {
-                self.0.opt_wrapping_div(rhs).map(MyInt)
-            }
+    impl OptionWrappingDiv<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_div(self, rhs: i64) -> Option<Self::Output> {
+            self.0.opt_wrapping_div(rhs).map(MyInt)
         }
+    }
 
+    #[test]
+    fn wrapping_div() {
         assert_eq!(MY_2.opt_wrapping_div(MY_1), SOME_2);
         assert_eq!(MY_0.opt_wrapping_div(MY_1), SOME_0);
         assert_eq!(MY_MIN.opt_wrapping_div(MY_MINUS_1), SOME_MIN);
@@ -360,4 +902,365 @@ mod test {
         assert_eq!(MY_MIN.opt_wrapping_div(NONE), None);
         assert_eq!(NONE.opt_wrapping_div(MY_MIN), None);
     }
+
+    #[test]
+    fn checked_div_non_zero() {
+        use core::num::{NonZeroI64, NonZeroIsize, NonZeroU32, NonZeroUsize};
+
+        let one = NonZeroU32::new(1).unwrap();
+        let two = NonZeroU32::new(2).unwrap();
+        let max = NonZeroU32::new(u32::MAX).unwrap();
+        assert_eq!(max.opt_checked_div(max), Ok(Some(1)));
+        assert_eq!(Some(max).opt_checked_div(one), Ok(Some(u32::MAX)));
+        assert_eq!(Some(one).opt_checked_div(Some(two)), Ok(Some(0)));
+        assert_eq!(one.opt_checked_div(&Some(max)), Ok(Some(0)));
+        assert_eq!(one.opt_checked_div(Option::<NonZeroU32>::None), Ok(None));
+
+        let minus_one = NonZeroI64::new(-1).unwrap();
+        let min = NonZeroI64::new(i64::MIN).unwrap();
+        assert_eq!(min.opt_checked_div(min), Ok(Some(1)));
+        assert_eq!(Some(min).opt_checked_div(minus_one), Err(Error::Overflow));
+
+        let seven = NonZeroUsize::new(7).unwrap();
+        let two = NonZeroUsize::new(2).unwrap();
+        assert_eq!(Some(seven).opt_checked_div(Some(two)), Ok(Some(3)));
+
+        let minus_one = NonZeroIsize::new(-1).unwrap();
+        let min = NonZeroIsize::new(isize::MIN).unwrap();
+        assert_eq!(min.opt_checked_div(&Some(minus_one)), Err(Error::Overflow));
+    }
+
+    impl OptionCheckedDivAssign for MyInt {
+        fn opt_checked_div_assign(&mut self, rhs: MyInt) -> Result<(), Error> {
+            self.0.opt_checked_div_assign(rhs.0)
+        }
+    }
+
+    impl OptionCheckedDivAssign<i64> for MyInt {
+        fn opt_checked_div_assign(&mut self, rhs: i64) -> Result<(), Error> {
+            self.0.opt_checked_div_assign(rhs)
+        }
+    }
+
+    #[test]
+    fn checked_div_assign() {
+        let mut my = MY_10;
+        assert_eq!(my.opt_checked_div_assign(MY_2), Ok(()));
+        assert_eq!(my, MY_5);
+
+        let mut my = MY_10;
+        assert_eq!(my.opt_checked_div_assign(SOME_5), Ok(()));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_10;
+        assert_eq!(my.opt_checked_div_assign(&SOME_10), Ok(()));
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_10;
+        assert_eq!(my.opt_checked_div_assign(MY_0), Err(Error::DivisionByZero));
+        assert_eq!(my, MY_10);
+
+        let mut my = MY_MIN;
+        assert_eq!(my.opt_checked_div_assign(-1), Err(Error::Overflow));
+        assert_eq!(my, MY_MIN);
+
+        let mut my = MY_10;
+        assert_eq!(my.opt_checked_div_assign(NONE), Ok(()));
+        assert_eq!(my, MY_10);
+
+        let mut some = SOME_10;
+        assert_eq!(some.opt_checked_div_assign(2), Ok(()));
+        assert_eq!(some, SOME_5);
+
+        let mut some = SOME_10;
+        assert_eq!(
+            some.opt_checked_div_assign(Some(0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(some, SOME_10);
+
+        let mut some = SOME_10;
+        assert_eq!(
+            some.opt_checked_div_assign(&SOME_0),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(some, SOME_10);
+
+        let mut some = SOME_10;
+        assert_eq!(some.opt_checked_div_assign(NONE), Ok(()));
+        assert_eq!(some, SOME_10);
+
+        let mut none = NONE;
+        assert_eq!(none.opt_checked_div_assign(SOME_0), Ok(()));
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    fn checked_div_assign_primitive() {
+        let mut some = Some(10u8);
+        assert_eq!(some.opt_checked_div_assign(0), Err(Error::DivisionByZero));
+        assert_eq!(some, Some(10));
+
+        let mut some = Some(1.0f64);
+        assert_eq!(some.opt_checked_div_assign(4.0), Ok(()));
+        assert_eq!(some, Some(0.25));
+
+        let mut some = Some(1.0f64);
+        assert_eq!(
+            some.opt_checked_div_assign(Some(-0.0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(some, Some(1.0));
+
+        let mut value = f32::MAX;
+        assert_eq!(value.opt_checked_div_assign(0.5), Err(Error::NotFinite));
+        assert_eq!(value, f32::MAX);
+    }
+
+    #[test]
+    fn checked_div_float() {
+        assert_eq!(Some(1.0f64).opt_checked_div(Some(4.0)), Ok(Some(0.25)));
+        assert_eq!(Some(-3.0f32).opt_checked_div(2.0), Ok(Some(-1.5)));
+        assert_eq!(1.0f64.opt_checked_div(f64::INFINITY), Ok(Some(0.0)));
+
+        assert_eq!(
+            Some(1.0f64).opt_checked_div(0.0),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            Some(1.0f64).opt_checked_div(-0.0),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            Some(0.0f32).opt_checked_div(Some(0.0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            Some(f64::INFINITY).opt_checked_div(Some(0.0)),
+            Err(Error::DivisionByZero)
+        );
+
+        assert_eq!(Some(f64::MAX).opt_checked_div(0.5), Err(Error::NotFinite));
+        // Non-finite operands propagate as with the regular division.
+        assert_eq!(
+            Some(f64::INFINITY).opt_checked_div(2.0),
+            Ok(Some(f64::INFINITY))
+        );
+        assert!(Some(1.0f64)
+            .opt_checked_div(f64::NAN)
+            .unwrap()
+            .unwrap()
+            .is_nan());
+
+        assert_eq!(Option::<f64>::None.opt_checked_div(0.0), Ok(None));
+        assert_eq!(Some(1.0f64).opt_checked_div(Option::<f64>::None), Ok(None));
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn checked_div_duration() {
+        use core::time::Duration;
+
+        let elapsed = Duration::from_millis(1_500);
+        let total = Duration::from_secs(6);
+
+        assert_eq!(
+            elapsed.opt_checked_div(total),
+            Ok(Some(elapsed.div_duration_f64(total)))
+        );
+        assert_eq!(Some(elapsed).opt_checked_div(Some(total)), Ok(Some(0.25)));
+        assert_eq!(elapsed.opt_checked_div(&Some(total)), Ok(Some(0.25)));
+        assert_eq!(total.opt_checked_div(elapsed), Ok(Some(4.0)));
+        assert_eq!(
+            Some(elapsed).opt_checked_div(Duration::ZERO),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            Some(elapsed).opt_checked_div(Option::<Duration>::None),
+            Ok(None)
+        );
+        assert_eq!(Option::<Duration>::None.opt_checked_div(total), Ok(None));
+
+        assert_eq!(total.opt_checked_div(4u32), Ok(Some(elapsed)));
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn checked_div_duration_f64() {
+        use core::time::Duration;
+
+        let millis_150 = Duration::from_millis(150);
+        assert_eq!(
+            millis_150.opt_checked_div(1.5),
+            Ok(Some(millis_150.div_f64(1.5)))
+        );
+        assert_eq!(
+            Some(millis_150).opt_checked_div(Some(1.5)),
+            Ok(Some(Duration::from_millis(100)))
+        );
+        assert_eq!(
+            Some(millis_150).opt_checked_div(&Some(f64::INFINITY)),
+            Err(Error::Domain)
+        );
+        assert_eq!(millis_150.opt_checked_div(0.0), Err(Error::DivisionByZero));
+        assert_eq!(millis_150.opt_checked_div(-0.0), Err(Error::DivisionByZero));
+        assert_eq!(millis_150.opt_checked_div(f64::NAN), Err(Error::Domain));
+        assert_eq!(millis_150.opt_checked_div(-1.5), Err(Error::Underflow));
+        assert_eq!(
+            Duration::ZERO.opt_checked_div(-1.5),
+            Ok(Some(Duration::ZERO))
+        );
+        assert_eq!(Duration::MAX.opt_checked_div(0.5), Err(Error::Overflow));
+        assert_eq!(
+            Duration::MAX.opt_checked_div(f64::MIN_POSITIVE),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Some(millis_150).opt_checked_div(Option::<f64>::None),
+            Ok(None)
+        );
+        assert_eq!(Option::<Duration>::None.opt_checked_div(1.5), Ok(None));
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct MyBig(u64);
+
+    impl OptionOperations for MyBig {}
+
+    impl Div<&MyBig> for &MyBig {
+        type Output = MyBig;
+
+        fn div(self, rhs: &MyBig) -> MyBig {
+            MyBig(self.0 / rhs.0)
+        }
+    }
+
+    #[test]
+    fn div_ref_receivers() {
+        let big_10 = MyBig(10);
+        let big_5 = MyBig(5);
+        let some_10 = Some(MyBig(10));
+        let some_5 = Some(MyBig(5));
+        let none = Option::<MyBig>::None;
+
+        assert_eq!((&big_10).opt_div(&big_5), Some(MyBig(2)));
+        assert_eq!((&big_10).opt_div(some_5.as_ref()), Some(MyBig(2)));
+        assert_eq!((&big_10).opt_div(none.as_ref()), None);
+
+        assert_eq!((&some_10).opt_div(&big_5), Some(MyBig(2)));
+        assert_eq!((&some_10).opt_div(some_5.as_ref()), Some(MyBig(2)));
+        assert_eq!((&some_10).opt_div(none.as_ref()), None);
+        assert_eq!((&some_10).opt_div(&some_5), Some(MyBig(2)));
+        assert_eq!((&some_10).opt_div(&none), None);
+        assert_eq!((&none).opt_div(&some_5), None);
+        assert_eq!((&none).opt_div(&big_5), None);
+        assert_eq!((&none).opt_div(some_5.as_ref()), None);
+
+        // The operands are still usable.
+        assert_eq!(big_10, MyBig(10));
+        assert_eq!(some_10, Some(MyBig(10)));
+
+        assert_eq!((&Some(10u64)).opt_div(&2), Some(5));
+        assert_eq!((&Some(10u64)).opt_div(Some(&2)), Some(5));
+    }
+
+    impl OptionCheckedDiv<&MyBig> for &MyBig {
+        type Output = MyBig;
+
+        fn opt_checked_div(self, rhs: &MyBig) -> Result<Option<MyBig>, Error> {
+            self.0.opt_checked_div(rhs.0).map(|res| res.map(MyBig))
+        }
+    }
+
+    impl OptionOverflowingDiv<&MyBig> for &MyBig {
+        type Output = MyBig;
+
+        fn opt_overflowing_div(self, rhs: &MyBig) -> Option<(MyBig, bool)> {
+            self.0
+                .opt_overflowing_div(rhs.0)
+                .map(|(res, flag)| (MyBig(res), flag))
+        }
+    }
+
+    impl OptionWrappingDiv<&MyBig> for &MyBig {
+        type Output = MyBig;
+
+        fn opt_wrapping_div(self, rhs: &MyBig) -> Option<MyBig> {
+            self.0.opt_wrapping_div(rhs.0).map(MyBig)
+        }
+    }
+
+    impl OptionDivOr<&MyBig> for &MyBig {
+        type Output = MyBig;
+
+        fn opt_div_or(self, rhs: &MyBig, default: MyBig) -> Option<MyBig> {
+            self.0.opt_div_or(rhs.0, default.0).map(MyBig)
+        }
+    }
+
+    impl OptionSaturatingDiv<&MyBig> for &MyBig {
+        type Output = MyBig;
+
+        fn opt_saturating_div(self, rhs: &MyBig) -> Option<MyBig> {
+            self.0.opt_saturating_div(rhs.0).map(MyBig)
+        }
+    }
+
+    #[test]
+    fn div_family_ref_receivers() {
+        let big_10 = MyBig(10);
+        let big_0 = MyBig(0);
+        let some_10 = Some(MyBig(10));
+        let some_5 = Some(MyBig(5));
+        let none = Option::<MyBig>::None;
+
+        assert_eq!((&some_10).opt_checked_div(&big_10), Ok(Some(MyBig(1))));
+        assert_eq!(
+            (&some_10).opt_checked_div(some_5.as_ref()),
+            Ok(Some(MyBig(2)))
+        );
+        assert_eq!(
+            (&some_10).opt_checked_div(&big_0),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!((&some_10).opt_checked_div(none.as_ref()), Ok(None));
+        assert_eq!((&some_10).opt_checked_div(&some_5), Ok(Some(MyBig(2))));
+        assert_eq!((&some_10).opt_checked_div(&none), Ok(None));
+        assert_eq!((&none).opt_checked_div(&big_0), Ok(None));
+
+        assert_eq!(
+            (&some_10).opt_div_or(some_5.as_ref(), MyBig(0)),
+            Some(MyBig(2))
+        );
+        assert_eq!((&some_10).opt_div_or(&big_0, MyBig(0)), Some(MyBig(0)));
+        assert_eq!((&none).opt_div_or(&big_10, MyBig(0)), None);
+        assert_eq!((&some_10).opt_div_or(&some_5, MyBig(0)), Some(MyBig(2)));
+
+        assert_eq!(
+            (&some_10).opt_overflowing_div(&big_10),
+            Some((MyBig(1), false))
+        );
+        assert_eq!((&none).opt_overflowing_div(some_5.as_ref()), None);
+
+        assert_eq!((&some_10).opt_wrapping_div(some_5.as_ref()), Some(MyBig(2)));
+        assert_eq!((&some_10).opt_wrapping_div(none.as_ref()), None);
+        assert_eq!((&some_10).opt_wrapping_div(&some_5), Some(MyBig(2)));
+        assert_eq!(
+            (&some_10).opt_overflowing_div(&some_5),
+            Some((MyBig(2), false))
+        );
+        assert_eq!((&some_10).opt_saturating_div(&none), None);
+
+        assert_eq!((&some_10).opt_saturating_div(&big_10), Some(MyBig(1)));
+        assert_eq!((&none).opt_saturating_div(&big_10), None);
+
+        // The operands are still usable.
+        assert_eq!(big_10, MyBig(10));
+        assert_eq!(some_10, Some(MyBig(10)));
+
+        let some_i64: &Option<i64> = &Some(-10);
+        assert_eq!(some_i64.opt_checked_div_widening(Some(2i32)), Ok(Some(-5)));
+        let none_i64: &Option<i64> = &None;
+        assert_eq!(none_i64.opt_checked_div_widening(2i32), Ok(None));
+    }
 }
@@ -0,0 +1,291 @@
+//! Traits for the Euclidean division [`OptionOperations`].
+
+use crate::{Error, OptionOperations};
+
+option_op_custom!(
+    DivEuclid,
+    div_euclid,
+    "Euclidean division",
+    "
+# Panics
+
+Most implementations will panic if `rhs` is zero.
+",
+);
+
+impl_for_ints!(OptionDivEuclid, {
+    type Output = Self;
+    #[inline]
+    fn opt_div_euclid(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.div_euclid(rhs))
+    }
+});
+
+option_op_assign_custom!(
+    DivEuclid,
+    div_euclid,
+    "Euclidean division",
+    "
+# Panics
+
+Most implementations will panic if `rhs` is zero.
+",
+);
+
+impl_for_ints!(OptionDivEuclidAssign, {
+    #[inline]
+    fn opt_div_euclid_assign(&mut self, rhs: Self) {
+        *self = self.div_euclid(rhs);
+    }
+});
+
+option_op_checked!(
+    DivEuclid,
+    div_euclid,
+    "Euclidean division",
+    "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero.",
+);
+
+impl_for_ints!(OptionCheckedDivEuclid, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_div_euclid(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        if rhs == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        self.checked_div_euclid(rhs)
+            .ok_or(Error::Overflow)
+            .map(Some)
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionDivEuclid for MyInt {
+        type Output = MyInt;
+        fn opt_div_euclid(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_div_euclid(rhs.0).map(MyInt)
+        }
+    }
+
+    impl OptionDivEuclid<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_div_euclid(self, rhs: i64) -> Option<Self::Output> {
+            self.0.opt_div_euclid(rhs).map(MyInt)
+        }
+    }
+
+    impl OptionDivEuclidAssign for MyInt {
+        fn opt_div_euclid_assign(&mut self, rhs: MyInt) {
+            self.0.opt_div_euclid_assign(rhs.0)
+        }
+    }
+
+    impl OptionDivEuclidAssign<i64> for MyInt {
+        fn opt_div_euclid_assign(&mut self, rhs: i64) {
+            self.0.opt_div_euclid_assign(rhs)
+        }
+    }
+
+    impl OptionCheckedDivEuclid for MyInt {
+        type Output = MyInt;
+        fn opt_checked_div_euclid(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_div_euclid(rhs.0).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    impl OptionCheckedDivEuclid<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_div_euclid(self, rhs: i64) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_div_euclid(rhs).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    const MY_MINUS_7: MyInt = MyInt(-7);
+    const MY_MINUS_4: MyInt = MyInt(-4);
+    const MY_MINUS_2: MyInt = MyInt(-2);
+    const MY_MINUS_1: MyInt = MyInt(-1);
+    const MY_0: MyInt = MyInt(0);
+    const MY_2: MyInt = MyInt(2);
+    const MY_4: MyInt = MyInt(4);
+    const MY_7: MyInt = MyInt(7);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const SOME_MINUS_7: Option<MyInt> = Some(MY_MINUS_7);
+    const SOME_MINUS_4: Option<MyInt> = Some(MY_MINUS_4);
+    const SOME_MINUS_2: Option<MyInt> = Some(MY_MINUS_2);
+    const SOME_MINUS_1: Option<MyInt> = Some(MY_MINUS_1);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_4: Option<MyInt> = Some(MY_4);
+    const SOME_7: Option<MyInt> = Some(MY_7);
+    const SOME_MIN: Option<MyInt> = Some(MY_MIN);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn div_euclid_my() {
+        assert_eq!(MY_7.opt_div_euclid(MyInt(4)), Some(MyInt(1)));
+        assert_eq!(MY_MINUS_7.opt_div_euclid(MyInt(4)), SOME_MINUS_2);
+        assert_eq!(MY_7.opt_div_euclid(MY_MINUS_4), SOME_MINUS_1);
+        assert_eq!(MY_MINUS_7.opt_div_euclid(MY_MINUS_4), SOME_2);
+        assert_eq!(MY_MINUS_7.opt_div_euclid(SOME_4), SOME_MINUS_2);
+        assert_eq!(MY_MINUS_7.opt_div_euclid(&SOME_4), SOME_MINUS_2);
+        assert_eq!(SOME_MINUS_7.opt_div_euclid(MY_4), SOME_MINUS_2);
+        assert_eq!(SOME_MINUS_7.opt_div_euclid(SOME_4), SOME_MINUS_2);
+        assert_eq!(SOME_MINUS_7.opt_div_euclid(&SOME_4), SOME_MINUS_2);
+        assert_eq!(MY_7.opt_div_euclid(NONE), NONE);
+        assert_eq!(NONE.opt_div_euclid(MY_4), NONE);
+        assert_eq!(NONE.opt_div_euclid(SOME_4), NONE);
+    }
+
+    #[test]
+    fn div_euclid_i64() {
+        assert_eq!(MY_MINUS_7.opt_div_euclid(4), SOME_MINUS_2);
+        assert_eq!(MY_MINUS_7.opt_div_euclid(Some(4)), SOME_MINUS_2);
+        assert_eq!(SOME_7.opt_div_euclid(-4), SOME_MINUS_1);
+        assert_eq!(SOME_MINUS_7.opt_div_euclid(&Some(-4)), SOME_2);
+        assert_eq!(MY_7.opt_div_euclid(Option::<i64>::None), NONE);
+        assert_eq!(NONE.opt_div_euclid(4), NONE);
+
+        assert_eq!(Some(-7i32).opt_div_euclid(Some(4)), Some(-2));
+        assert_eq!(Some(7u8).opt_div_euclid(Some(4)), Some(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_euclid_by_zero_my() {
+        let _ = SOME_7.opt_div_euclid(SOME_0);
+    }
+
+    #[test]
+    fn div_euclid_assign_my() {
+        let mut my = MY_MINUS_7;
+        my.opt_div_euclid_assign(MY_4);
+        assert_eq!(my, MY_MINUS_2);
+
+        let mut some = SOME_7;
+        some.opt_div_euclid_assign(MY_MINUS_4);
+        assert_eq!(some, SOME_MINUS_1);
+
+        let mut my = MY_MINUS_7;
+        my.opt_div_euclid_assign(SOME_4);
+        assert_eq!(my, MY_MINUS_2);
+
+        let mut my = MY_MINUS_7;
+        my.opt_div_euclid_assign(&SOME_MINUS_4);
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_7;
+        my.opt_div_euclid_assign(NONE);
+        assert_eq!(my, MY_7);
+
+        let mut some = SOME_MINUS_7;
+        some.opt_div_euclid_assign(SOME_4);
+        assert_eq!(some, SOME_MINUS_2);
+
+        let mut some = SOME_MINUS_7;
+        some.opt_div_euclid_assign(&SOME_MINUS_4);
+        assert_eq!(some, SOME_2);
+
+        let mut some = SOME_7;
+        some.opt_div_euclid_assign(NONE);
+        assert_eq!(some, SOME_7);
+
+        let mut none = NONE;
+        none.opt_div_euclid_assign(SOME_4);
+        assert_eq!(none, NONE);
+
+        let mut none = NONE;
+        none.opt_div_euclid_assign(NONE);
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_euclid_assign_by_zero_my() {
+        let mut some = SOME_7;
+        some.opt_div_euclid_assign(SOME_0);
+    }
+
+    #[test]
+    fn div_euclid_assign_i64() {
+        let mut my = MY_MINUS_7;
+        my.opt_div_euclid_assign(4);
+        assert_eq!(my, MY_MINUS_2);
+
+        let mut some = SOME_7;
+        some.opt_div_euclid_assign(-4);
+        assert_eq!(some, SOME_MINUS_1);
+
+        let mut my = MY_MINUS_7;
+        my.opt_div_euclid_assign(Some(4));
+        assert_eq!(my, MY_MINUS_2);
+
+        let mut my = MY_MINUS_7;
+        my.opt_div_euclid_assign(&Some(-4));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_7;
+        my.opt_div_euclid_assign(Option::<i64>::None);
+        assert_eq!(my, MY_7);
+
+        let mut some = SOME_MINUS_7;
+        some.opt_div_euclid_assign(Some(4));
+        assert_eq!(some, SOME_MINUS_2);
+
+        let mut some = SOME_7;
+        some.opt_div_euclid_assign(Option::<i64>::None);
+        assert_eq!(some, SOME_7);
+
+        let mut none = NONE;
+        none.opt_div_euclid_assign(4);
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_euclid_assign_by_zero_i64() {
+        let mut some = SOME_7;
+        some.opt_div_euclid_assign(Some(0));
+    }
+
+    #[test]
+    fn checked_div_euclid() {
+        assert_eq!(MY_MINUS_7.opt_checked_div_euclid(MY_4), Ok(SOME_MINUS_2));
+        assert_eq!(MY_MINUS_7.opt_checked_div_euclid(SOME_4), Ok(SOME_MINUS_2));
+        assert_eq!(MY_MINUS_7.opt_checked_div_euclid(&SOME_4), Ok(SOME_MINUS_2));
+        assert_eq!(SOME_MINUS_7.opt_checked_div_euclid(-4), Ok(SOME_2));
+        assert_eq!(
+            SOME_MINUS_7.opt_checked_div_euclid(Some(4)),
+            Ok(SOME_MINUS_2)
+        );
+        assert_eq!(SOME_MINUS_7.opt_checked_div_euclid(&Some(-4)), Ok(SOME_2));
+
+        assert_eq!(
+            MY_7.opt_checked_div_euclid(MY_0),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            SOME_7.opt_checked_div_euclid(Some(0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            MY_MIN.opt_checked_div_euclid(MY_MINUS_1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            SOME_MIN.opt_checked_div_euclid(SOME_MINUS_1),
+            Err(Error::Overflow)
+        );
+
+        assert_eq!(MY_7.opt_checked_div_euclid(NONE), Ok(None));
+        assert_eq!(NONE.opt_checked_div_euclid(SOME_0), Ok(None));
+    }
+}
@@ -0,0 +1,167 @@
+//! Traits for the division and remainder [`OptionOperations`].
+//!
+//! The `Output` of these operations is the `(quotient, remainder)` pair.
+
+use crate::{Error, OptionOperations};
+
+option_op_custom!(
+    DivRem,
+    div_rem,
+    "division and remainder",
+    "
+# Panics
+
+Most implementations will panic if `rhs` is zero.
+",
+);
+
+impl_for_ints!(OptionDivRem, {
+    type Output = (Self, Self);
+    #[inline]
+    fn opt_div_rem(self, rhs: Self) -> Option<Self::Output> {
+        Some((self / rhs, self % rhs))
+    }
+});
+
+option_op_checked!(
+    DivRem,
+    div_rem,
+    "division and remainder",
+    "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero.",
+);
+
+impl_for_ints!(OptionCheckedDivRem, {
+    type Output = (Self, Self);
+    #[inline]
+    fn opt_checked_div_rem(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        if rhs == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        self.checked_div(rhs)
+            .zip(self.checked_rem(rhs))
+            .ok_or(Error::Overflow)
+            .map(Some)
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionDivRem for MyInt {
+        type Output = (MyInt, MyInt);
+        fn opt_div_rem(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0
+                .opt_div_rem(rhs.0)
+                .map(|(quot, rem)| (MyInt(quot), MyInt(rem)))
+        }
+    }
+
+    impl OptionDivRem<i64> for MyInt {
+        type Output = (MyInt, MyInt);
+        fn opt_div_rem(self, rhs: i64) -> Option<Self::Output> {
+            self.0
+                .opt_div_rem(rhs)
+                .map(|(quot, rem)| (MyInt(quot), MyInt(rem)))
+        }
+    }
+
+    impl OptionCheckedDivRem for MyInt {
+        type Output = (MyInt, MyInt);
+        fn opt_checked_div_rem(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0
+                .opt_checked_div_rem(rhs.0)
+                .map(|ok| ok.map(|(quot, rem)| (MyInt(quot), MyInt(rem))))
+        }
+    }
+
+    impl OptionCheckedDivRem<i64> for MyInt {
+        type Output = (MyInt, MyInt);
+        fn opt_checked_div_rem(self, rhs: i64) -> Result<Option<Self::Output>, Error> {
+            self.0
+                .opt_checked_div_rem(rhs)
+                .map(|ok| ok.map(|(quot, rem)| (MyInt(quot), MyInt(rem))))
+        }
+    }
+
+    const MY_MINUS_7: MyInt = MyInt(-7);
+    const MY_MINUS_3: MyInt = MyInt(-3);
+    const MY_MINUS_1: MyInt = MyInt(-1);
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const MY_3: MyInt = MyInt(3);
+    const MY_7: MyInt = MyInt(7);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const SOME_MINUS_1: Option<MyInt> = Some(MY_MINUS_1);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_7: Option<MyInt> = Some(MY_7);
+    const SOME_MIN: Option<MyInt> = Some(MY_MIN);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn div_rem_my() {
+        assert_eq!(MY_7.opt_div_rem(MY_2), Some((MY_3, MY_1)));
+        assert_eq!(MY_MINUS_7.opt_div_rem(MY_2), Some((MY_MINUS_3, MY_MINUS_1)));
+        assert_eq!(MY_7.opt_div_rem(SOME_2), Some((MY_3, MY_1)));
+        assert_eq!(MY_7.opt_div_rem(&SOME_2), Some((MY_3, MY_1)));
+        assert_eq!(SOME_7.opt_div_rem(MY_2), Some((MY_3, MY_1)));
+        assert_eq!(SOME_7.opt_div_rem(SOME_2), Some((MY_3, MY_1)));
+        assert_eq!(SOME_7.opt_div_rem(&SOME_2), Some((MY_3, MY_1)));
+        assert_eq!(MY_7.opt_div_rem(NONE), None);
+        assert_eq!(NONE.opt_div_rem(MY_2), None);
+        assert_eq!(NONE.opt_div_rem(SOME_2), None);
+    }
+
+    #[test]
+    fn div_rem_i64() {
+        assert_eq!(MY_7.opt_div_rem(2), Some((MY_3, MY_1)));
+        assert_eq!(MY_7.opt_div_rem(Some(-2)), Some((MY_MINUS_3, MY_1)));
+        assert_eq!(SOME_7.opt_div_rem(&Some(2)), Some((MY_3, MY_1)));
+        assert_eq!(SOME_7.opt_div_rem(Option::<i64>::None), None);
+        assert_eq!(NONE.opt_div_rem(2), None);
+
+        assert_eq!(Some(17u8).opt_div_rem(Some(5)), Some((3, 2)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn div_rem_by_zero_my() {
+        let _ = SOME_7.opt_div_rem(SOME_0);
+    }
+
+    #[test]
+    fn checked_div_rem() {
+        assert_eq!(MY_7.opt_checked_div_rem(MY_2), Ok(Some((MY_3, MY_1))));
+        assert_eq!(SOME_7.opt_checked_div_rem(SOME_2), Ok(Some((MY_3, MY_1))));
+        assert_eq!(SOME_7.opt_checked_div_rem(&Some(2)), Ok(Some((MY_3, MY_1))));
+        assert_eq!(MY_7.opt_checked_div_rem(MY_0), Err(Error::DivisionByZero));
+        assert_eq!(
+            SOME_7.opt_checked_div_rem(Some(0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(MY_MIN.opt_checked_div_rem(MY_MINUS_1), Err(Error::Overflow));
+        assert_eq!(
+            SOME_MIN.opt_checked_div_rem(SOME_MINUS_1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(SOME_MIN.opt_checked_div_rem(NONE), Ok(None));
+        assert_eq!(NONE.opt_checked_div_rem(SOME_0), Ok(None));
+
+        assert_eq!(
+            Some(i64::MIN).opt_checked_div_rem(Some(-1)),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Some(i64::MIN).opt_checked_div_rem(Some(2)),
+            Ok(Some((i64::MIN / 2, 0)))
+        );
+    }
+}
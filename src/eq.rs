@@ -25,6 +25,7 @@ impl<T, Rhs> OptionEq<&Rhs, Rhs> for T
 where
     T: OptionOperations + PartialEq<Rhs>,
 {
+    #[inline]
     fn opt_eq(&self, rhs: &Rhs) -> Option<bool> {
         Some(self.eq(rhs))
     }
@@ -34,6 +35,7 @@ impl<T, Rhs> OptionEq<Rhs> for T
 where
     T: OptionOperations + for<'a> OptionEq<&'a Rhs, Rhs>,
 {
+    #[inline]
     fn opt_eq(&self, rhs: Rhs) -> Option<bool> {
         self.opt_eq(&rhs)
     }
@@ -43,6 +45,7 @@ impl<T, InnerRhs> OptionEq<&Option<InnerRhs>, InnerRhs> for T
 where
     T: OptionOperations + for<'a> OptionEq<&'a InnerRhs, InnerRhs>,
 {
+    #[inline]
     fn opt_eq(&self, rhs: &Option<InnerRhs>) -> Option<bool> {
         rhs.as_ref().and_then(|inner_rhs| self.opt_eq(inner_rhs))
     }
@@ -52,6 +55,7 @@ impl<T, InnerRhs> OptionEq<Option<InnerRhs>, InnerRhs> for T
 where
     T: OptionOperations + for<'a> OptionEq<&'a InnerRhs, InnerRhs>,
 {
+    #[inline]
     fn opt_eq(&self, rhs: Option<InnerRhs>) -> Option<bool> {
         rhs.as_ref().and_then(|inner_rhs| self.opt_eq(inner_rhs))
     }
@@ -61,6 +65,7 @@ impl<T, Rhs> OptionEq<&Rhs, Rhs> for Option<T>
 where
     T: OptionOperations + for<'a> OptionEq<&'a Rhs, Rhs>,
 {
+    #[inline]
     fn opt_eq(&self, rhs: &Rhs) -> Option<bool> {
         self.as_ref().and_then(|inner_self| inner_self.opt_eq(rhs))
     }
@@ -70,6 +75,7 @@ impl<T, Rhs> OptionEq<Rhs> for Option<T>
 where
     T: OptionOperations + for<'a> OptionEq<&'a Rhs, Rhs>,
 {
+    #[inline]
     fn opt_eq(&self, rhs: Rhs) -> Option<bool> {
         self.opt_eq(&rhs)
     }
@@ -79,6 +85,7 @@ impl<T, InnerRhs> OptionEq<&Option<InnerRhs>, InnerRhs> for Option<T>
 where
     T: OptionOperations + for<'a> OptionEq<&'a InnerRhs, InnerRhs>,
 {
+    #[inline]
     fn opt_eq(&self, rhs: &Option<InnerRhs>) -> Option<bool> {
         match (self, rhs) {
             (Some(inner_self), Some(inner_rhs)) => inner_self.opt_eq(inner_rhs),
@@ -92,6 +99,7 @@ impl<T, InnerRhs> OptionEq<Option<InnerRhs>, InnerRhs> for Option<T>
 where
     T: OptionOperations + for<'a> OptionEq<&'a InnerRhs, InnerRhs>,
 {
+    #[inline]
     fn opt_eq(&self, rhs: Option<InnerRhs>) -> Option<bool> {
         match (self, rhs.as_ref()) {
             (Some(inner_self), Some(inner_rhs)) => inner_self.opt_eq(inner_rhs),
@@ -8,12 +8,43 @@ use std::{error, fmt};
 use crate::OptionOperations;
 
 /// Error type which can be returned by some [`OptionOperations`].
+///
+/// New variants may be added in future versions, so `match`es on this
+/// type must include a wildcard arm:
+///
+/// ```
+/// # use option_operations::{Error, OptionCheckedDiv};
+/// let msg = match Some(1u32).opt_checked_div(Some(0u32)) {
+///     Ok(_) => "ok",
+///     Err(Error::DivisionByZero) => "division by zero",
+///     Err(_) => "other error",
+/// };
+/// assert_eq!(msg, "division by zero");
+/// ```
 #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Error {
     /// Division by zero attempted with an [`OptionOperations`].
     DivisionByZero,
     /// An [`OptionOperations`] overflowed.
     Overflow,
+    /// An [`OptionOperations`] resulted in a negative value which can't
+    /// be represented, e.g. `0u32 - 1`, a `Duration` minus a longer one or an
+    /// `Instant` minus a later one.
+    Underflow,
+    /// A floating point [`OptionOperations`] with finite operands
+    /// resulted in an infinite or `NaN` value.
+    NotFinite,
+    /// An [`OptionOperations`] on a `char` resulted in an invalid
+    /// Unicode scalar value.
+    InvalidChar,
+    /// A shift [`OptionOperations`] was attempted with a shift amount
+    /// larger than or equal to the number of bits of the operand.
+    ShiftOverflow,
+    /// An [`OptionOperations`] was attempted with an argument outside
+    /// of its domain, e.g. the logarithm of zero.
+    Domain,
 }
 
 impl Error {
@@ -28,17 +59,210 @@ impl Error {
     pub fn is_overflow(&self) -> bool {
         matches!(self, Error::Overflow)
     }
+
+    /// Returns `true` if this [`Error`] results from an unrepresentable
+    /// negative value.
+    #[must_use]
+    pub fn is_underflow(&self) -> bool {
+        matches!(self, Error::Underflow)
+    }
+
+    /// Returns `true` if this [`Error`] results from a non-finite
+    /// floating point value.
+    #[must_use]
+    pub fn is_not_finite(&self) -> bool {
+        matches!(self, Error::NotFinite)
+    }
+
+    /// Returns `true` if this [`Error`] results from an invalid `char`.
+    #[must_use]
+    pub fn is_invalid_char(&self) -> bool {
+        matches!(self, Error::InvalidChar)
+    }
+
+    /// Returns `true` if this [`Error`] results from a shift amount
+    /// exceeding the number of bits of the operand.
+    #[must_use]
+    pub fn is_shift_overflow(&self) -> bool {
+        matches!(self, Error::ShiftOverflow)
+    }
+
+    /// Returns `true` if this [`Error`] results from an argument outside
+    /// of the domain of the operation.
+    #[must_use]
+    pub fn is_domain(&self) -> bool {
+        matches!(self, Error::Domain)
+    }
 }
 
 #[cfg(feature = "std")]
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Error::DivisionByZero => f.write_str("An Option Operation overflowed"),
-            Error::Overflow => f.write_str("Division by zerp attempted with an Option Operation"),
+            Error::DivisionByZero => {
+                f.write_str("Division by zero attempted with an Option Operation")
+            }
+            Error::Overflow => f.write_str("An Option Operation overflowed"),
+            Error::Underflow => f.write_str("An Option Operation underflowed"),
+            Error::NotFinite => f.write_str("An Option Operation resulted in a non-finite value"),
+            Error::InvalidChar => f.write_str("An Option Operation resulted in an invalid char"),
+            Error::ShiftOverflow => {
+                f.write_str("An Option Operation attempted to shift by too many bits")
+            }
+            Error::Domain => f.write_str("An Option Operation was attempted outside of its domain"),
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl error::Error for Error {}
+
+/// [`Error`] with the name of the [`OptionOperations`] which caused it.
+///
+/// This is returned by the `opt_checked_*_detailed` methods, which are
+/// available with the `detailed-errors` feature.
+#[cfg(feature = "detailed-errors")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DetailedError {
+    kind: Error,
+    op: &'static str,
+}
+
+#[cfg(feature = "detailed-errors")]
+impl DetailedError {
+    /// Builds a [`DetailedError`] of `kind` caused by the operation `op`.
+    #[must_use]
+    pub fn new(kind: Error, op: &'static str) -> Self {
+        DetailedError { kind, op }
+    }
+
+    /// Returns the [`Error`] kind.
+    #[must_use]
+    pub fn kind(&self) -> Error {
+        self.kind
+    }
+
+    /// Returns the name of the operation which caused the error, e.g. `"div"`.
+    #[must_use]
+    pub fn op(&self) -> &'static str {
+        self.op
+    }
+}
+
+#[cfg(feature = "detailed-errors")]
+impl From<DetailedError> for Error {
+    fn from(err: DetailedError) -> Self {
+        err.kind
+    }
+}
+
+#[cfg(all(feature = "detailed-errors", feature = "std"))]
+impl fmt::Display for DetailedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.op, self.kind)
+    }
+}
+
+#[cfg(all(feature = "detailed-errors", feature = "std"))]
+impl error::Error for DetailedError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "std")]
+    #[test]
+    fn display() {
+        use super::Error;
+
+        assert_eq!(
+            Error::DivisionByZero.to_string(),
+            "Division by zero attempted with an Option Operation"
+        );
+        assert_eq!(
+            Error::Overflow.to_string(),
+            "An Option Operation overflowed"
+        );
+        assert_eq!(
+            Error::Underflow.to_string(),
+            "An Option Operation underflowed"
+        );
+        assert_eq!(
+            Error::NotFinite.to_string(),
+            "An Option Operation resulted in a non-finite value"
+        );
+        assert_eq!(
+            Error::InvalidChar.to_string(),
+            "An Option Operation resulted in an invalid char"
+        );
+        assert_eq!(
+            Error::ShiftOverflow.to_string(),
+            "An Option Operation attempted to shift by too many bits"
+        );
+        assert_eq!(
+            Error::Domain.to_string(),
+            "An Option Operation was attempted outside of its domain"
+        );
+    }
+
+    #[cfg(feature = "detailed-errors")]
+    #[test]
+    fn detailed_errors() {
+        use super::{DetailedError, Error};
+        use crate::{OptionCheckedAbs, OptionCheckedAdd, OptionCheckedDiv, OptionCheckedShl};
+
+        let err = Some(i64::MAX).opt_checked_add_detailed(1).unwrap_err();
+        assert_eq!(err.kind(), Error::Overflow);
+        assert_eq!(err.op(), "add");
+
+        let err = Some(1u32).opt_checked_div_detailed(Some(0)).unwrap_err();
+        assert_eq!(err, DetailedError::new(Error::DivisionByZero, "div"));
+        assert_eq!(Error::from(err), Error::DivisionByZero);
+
+        let err = 1u8.opt_checked_shl_detailed(8).unwrap_err();
+        assert_eq!(err, DetailedError::new(Error::ShiftOverflow, "shl"));
+
+        let err = Some(i8::MIN).opt_checked_abs_detailed().unwrap_err();
+        assert_eq!(err, DetailedError::new(Error::Overflow, "abs"));
+
+        assert_eq!(Some(4u32).opt_checked_div_detailed(Some(2)), Ok(Some(2)));
+        assert_eq!(
+            Option::<u32>::None.opt_checked_div_detailed(Some(0)),
+            Ok(None)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use super::Error;
+
+        for err in [
+            Error::DivisionByZero,
+            Error::Overflow,
+            Error::Underflow,
+            Error::NotFinite,
+            Error::InvalidChar,
+            Error::ShiftOverflow,
+            Error::Domain,
+        ] {
+            let json = serde_json::to_string(&err).unwrap();
+            assert_eq!(serde_json::from_str::<Error>(&json).unwrap(), err);
+        }
+
+        assert_eq!(
+            serde_json::to_string(&Error::DivisionByZero).unwrap(),
+            "\"DivisionByZero\""
+        );
+        assert_eq!(
+            serde_json::from_str::<Error>("\"Overflow\"").unwrap(),
+            Error::Overflow
+        );
+        assert_eq!(
+            serde_json::to_string(&Error::Underflow).unwrap(),
+            "\"Underflow\""
+        );
+    }
+}
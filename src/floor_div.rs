@@ -0,0 +1,188 @@
+//! Traits for the floor division [`OptionOperations`].
+
+use crate::{Error, OptionOperations, OptionRoundedDiv, RoundingMode};
+
+option_op_custom!(
+    FloorDiv,
+    floor_div,
+    "floor division",
+    "
+The quotient is rounded toward negative infinity.
+
+# Panics
+
+Most implementations will panic if `rhs` is zero.
+",
+);
+
+impl_for_ints!(OptionFloorDiv, {
+    type Output = Self;
+    #[inline]
+    fn opt_floor_div(self, rhs: Self) -> Option<Self::Output> {
+        self.opt_rounded_div(rhs, RoundingMode::Floor)
+    }
+});
+
+option_op_checked!(
+    FloorDiv,
+    floor_div,
+    "floor division",
+    "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero.",
+);
+
+impl_for_ints!(OptionCheckedFloorDiv, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_floor_div(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        if rhs == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        // The quotient can only overflow for `MIN / -1`. Otherwise, it can't
+        // be decremented past `MIN`, since it's rounded only when the remainder
+        // is non-zero, i.e. when `|rhs| > 1`.
+        self.checked_div(rhs).ok_or(Error::Overflow)?;
+        Ok(self.opt_rounded_div(rhs, RoundingMode::Floor))
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionFloorDiv for MyInt {
+        type Output = MyInt;
+        fn opt_floor_div(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_floor_div(rhs.0).map(MyInt)
+        }
+    }
+
+    impl OptionFloorDiv<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_floor_div(self, rhs: i64) -> Option<Self::Output> {
+            self.0.opt_floor_div(rhs).map(MyInt)
+        }
+    }
+
+    impl OptionCheckedFloorDiv for MyInt {
+        type Output = MyInt;
+        fn opt_checked_floor_div(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_floor_div(rhs.0).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    impl OptionCheckedFloorDiv<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_floor_div(self, rhs: i64) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_floor_div(rhs).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    const MY_MINUS_7: MyInt = MyInt(-7);
+    const MY_MINUS_4: MyInt = MyInt(-4);
+    const MY_MINUS_2: MyInt = MyInt(-2);
+    const MY_MINUS_1: MyInt = MyInt(-1);
+    const MY_0: MyInt = MyInt(0);
+    const MY_2: MyInt = MyInt(2);
+    const MY_3: MyInt = MyInt(3);
+    const MY_7: MyInt = MyInt(7);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const SOME_MINUS_7: Option<MyInt> = Some(MY_MINUS_7);
+    const SOME_MINUS_4: Option<MyInt> = Some(MY_MINUS_4);
+    const SOME_MINUS_1: Option<MyInt> = Some(MY_MINUS_1);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const SOME_7: Option<MyInt> = Some(MY_7);
+    const SOME_MIN: Option<MyInt> = Some(MY_MIN);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn floor_div_my() {
+        assert_eq!(MY_7.opt_floor_div(MY_2), SOME_3);
+        assert_eq!(MY_7.opt_floor_div(SOME_2), SOME_3);
+        assert_eq!(MY_7.opt_floor_div(&SOME_2), SOME_3);
+        assert_eq!(SOME_7.opt_floor_div(MY_2), SOME_3);
+        assert_eq!(SOME_7.opt_floor_div(SOME_2), SOME_3);
+        assert_eq!(SOME_7.opt_floor_div(&SOME_2), SOME_3);
+        assert_eq!(SOME_7.opt_floor_div(2), SOME_3);
+        assert_eq!(SOME_0.opt_floor_div(Some(2)), SOME_0);
+
+        assert_eq!(MY_7.opt_floor_div(NONE), NONE);
+        assert_eq!(SOME_7.opt_floor_div(Option::<i64>::None), NONE);
+        assert_eq!(NONE.opt_floor_div(MY_2), NONE);
+        assert_eq!(NONE.opt_floor_div(SOME_2), NONE);
+    }
+
+    #[test]
+    fn floor_div_negative() {
+        // -7 / 2 = -3.5: floor rounds down where truncation rounds up.
+        assert_eq!(MY_MINUS_7.opt_floor_div(MY_2), SOME_MINUS_4);
+        assert_eq!(-7 / 2, -3);
+        // 7 / -2 = -3.5
+        assert_eq!(MY_7.opt_floor_div(MY_MINUS_2), SOME_MINUS_4);
+        assert_eq!(7 / -2, -3);
+        // -7 / -2 = 3.5: floor and truncation agree.
+        assert_eq!(SOME_MINUS_7.opt_floor_div(MY_MINUS_2), SOME_3);
+        // Exact divisions.
+        assert_eq!(Some(-8i32).opt_floor_div(Some(2)), Some(-4));
+        assert_eq!(Some(-8i32).opt_floor_div(Some(-2)), Some(4));
+        assert_eq!(Some(-1i8).opt_floor_div(Some(i8::MAX)), Some(-1));
+        assert_eq!(Some(1i8).opt_floor_div(Some(i8::MIN)), Some(-1));
+    }
+
+    #[test]
+    fn floor_div_unsigned() {
+        assert_eq!(Some(10_000u64).opt_floor_div(4_096u64), Some(2));
+        assert_eq!(Some(u8::MAX).opt_floor_div(2), Some(127));
+        assert_eq!(Option::<u64>::None.opt_floor_div(2), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn floor_div_by_zero_my() {
+        let _ = SOME_7.opt_floor_div(SOME_0);
+    }
+
+    #[test]
+    fn checked_floor_div() {
+        assert_eq!(MY_7.opt_checked_floor_div(MY_2), Ok(SOME_3));
+        assert_eq!(MY_7.opt_checked_floor_div(SOME_2), Ok(SOME_3));
+        assert_eq!(MY_7.opt_checked_floor_div(&SOME_2), Ok(SOME_3));
+        assert_eq!(SOME_MINUS_7.opt_checked_floor_div(2), Ok(SOME_MINUS_4));
+        assert_eq!(SOME_MINUS_7.opt_checked_floor_div(Some(-2)), Ok(SOME_3));
+        assert_eq!(SOME_7.opt_checked_floor_div(&Some(-2)), Ok(SOME_MINUS_4));
+
+        assert_eq!(MY_7.opt_checked_floor_div(MY_0), Err(Error::DivisionByZero));
+        assert_eq!(
+            SOME_7.opt_checked_floor_div(Some(0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            MY_MIN.opt_checked_floor_div(MY_MINUS_1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            SOME_MIN.opt_checked_floor_div(SOME_MINUS_1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Some(i64::MIN + 1).opt_checked_floor_div(2),
+            Ok(Some(i64::MIN / 2))
+        );
+
+        assert_eq!(Some(10u8).opt_checked_floor_div(3), Ok(Some(3)));
+        assert_eq!(
+            Some(10u8).opt_checked_floor_div(0),
+            Err(Error::DivisionByZero)
+        );
+
+        assert_eq!(MY_7.opt_checked_floor_div(NONE), Ok(None));
+        assert_eq!(NONE.opt_checked_floor_div(SOME_0), Ok(None));
+    }
+}
@@ -0,0 +1,251 @@
+//! Traits for the greatest common divisor and least common multiple
+//! [`OptionOperations`].
+//!
+//! The integer implementations use the binary GCD algorithm. For signed
+//! integers, the result is computed on the absolute values and is returned
+//! as the unsigned counterpart, so that e.g. `gcd(i64::MIN, 0)` can be
+//! represented.
+
+use crate::{Error, OptionOperations};
+
+option_op_custom!(
+    Gcd,
+    gcd,
+    "greatest common divisor",
+    "
+`gcd(0, 0)` is defined as `0`.",
+);
+
+option_op_custom!(
+    Lcm,
+    lcm,
+    "least common multiple",
+    "
+The least common multiple involving a `0` is `0`.
+
+# Panics
+
+Most implementations will panic in debug mode if the result overflows.
+See [`OptionCheckedLcm`] for a non-panicking alternative.",
+);
+
+option_op_checked!(
+    Lcm,
+    lcm,
+    "least common multiple",
+    "
+The least common multiple involving a `0` is `0`.",
+);
+
+macro_rules! binary_gcd {
+    ($($typ_:ty),+ $(,)?) => {
+        paste::paste! {
+            $(
+                #[inline]
+                fn [<gcd_ $typ_>](mut lhs: $typ_, mut rhs: $typ_) -> $typ_ {
+                    if lhs == 0 {
+                        return rhs;
+                    }
+                    if rhs == 0 {
+                        return lhs;
+                    }
+
+                    let shift = (lhs | rhs).trailing_zeros();
+                    lhs >>= lhs.trailing_zeros();
+                    loop {
+                        rhs >>= rhs.trailing_zeros();
+                        if lhs > rhs {
+                            core::mem::swap(&mut lhs, &mut rhs);
+                        }
+                        rhs -= lhs;
+                        if rhs == 0 {
+                            return lhs << shift;
+                        }
+                    }
+                }
+            )+
+        }
+    };
+}
+
+binary_gcd!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_gcd_lcm_for_ints {
+    ($($typ_:ty => $output:ty, $to_unsigned:path),+ $(,)?) => {
+        paste::paste! {
+            $(
+                impl_for!(OptionGcd, $typ_, {
+                    type Output = $output;
+                    #[inline]
+                    fn opt_gcd(self, rhs: Self) -> Option<Self::Output> {
+                        Some([<gcd_ $output>]($to_unsigned(self), $to_unsigned(rhs)))
+                    }
+                });
+
+                impl_for!(OptionLcm, $typ_, {
+                    type Output = $output;
+                    #[inline]
+                    fn opt_lcm(self, rhs: Self) -> Option<Self::Output> {
+                        let (lhs, rhs) = ($to_unsigned(self), $to_unsigned(rhs));
+                        if lhs == 0 || rhs == 0 {
+                            return Some(0);
+                        }
+                        Some(lhs / [<gcd_ $output>](lhs, rhs) * rhs)
+                    }
+                });
+
+                impl_for!(OptionCheckedLcm, $typ_, {
+                    type Output = $output;
+                    #[inline]
+                    fn opt_checked_lcm(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+                        let (lhs, rhs) = ($to_unsigned(self), $to_unsigned(rhs));
+                        if lhs == 0 || rhs == 0 {
+                            return Ok(Some(0));
+                        }
+                        (lhs / [<gcd_ $output>](lhs, rhs))
+                            .checked_mul(rhs)
+                            .ok_or(Error::Overflow)
+                            .map(Some)
+                    }
+                });
+            )+
+        }
+    };
+}
+
+impl_gcd_lcm_for_ints!(
+    i8 => u8, i8::unsigned_abs,
+    i16 => u16, i16::unsigned_abs,
+    i32 => u32, i32::unsigned_abs,
+    i64 => u64, i64::unsigned_abs,
+    i128 => u128, i128::unsigned_abs,
+    isize => usize, isize::unsigned_abs,
+    u8 => u8, core::convert::identity,
+    u16 => u16, core::convert::identity,
+    u32 => u32, core::convert::identity,
+    u64 => u64, core::convert::identity,
+    u128 => u128, core::convert::identity,
+    usize => usize, core::convert::identity,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionGcd for MyInt {
+        type Output = MyInt;
+        fn opt_gcd(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_gcd(rhs.0).map(MyInt)
+        }
+    }
+
+    impl OptionLcm for MyInt {
+        type Output = MyInt;
+        fn opt_lcm(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_lcm(rhs.0).map(MyInt)
+        }
+    }
+
+    impl OptionCheckedLcm for MyInt {
+        type Output = MyInt;
+        fn opt_checked_lcm(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_lcm(rhs.0).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_4: MyInt = MyInt(4);
+    const MY_6: MyInt = MyInt(6);
+    const MY_12: MyInt = MyInt(12);
+    const MY_HALF_MAX: MyInt = MyInt(u64::MAX / 2);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_2: Option<MyInt> = Some(MyInt(2));
+    const SOME_4: Option<MyInt> = Some(MY_4);
+    const SOME_6: Option<MyInt> = Some(MY_6);
+    const SOME_12: Option<MyInt> = Some(MY_12);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn gcd_my() {
+        assert_eq!(MY_4.opt_gcd(MY_6), SOME_2);
+        assert_eq!(MY_4.opt_gcd(SOME_6), SOME_2);
+        assert_eq!(MY_12.opt_gcd(&SOME_4), SOME_4);
+        assert_eq!(SOME_4.opt_gcd(MY_6), SOME_2);
+        assert_eq!(SOME_12.opt_gcd(SOME_6), SOME_6);
+        assert_eq!(SOME_6.opt_gcd(&SOME_4), SOME_2);
+
+        assert_eq!(MY_0.opt_gcd(MY_6), SOME_6);
+        assert_eq!(SOME_6.opt_gcd(MY_0), SOME_6);
+        assert_eq!(SOME_0.opt_gcd(SOME_0), SOME_0);
+
+        assert_eq!(MY_4.opt_gcd(NONE), None);
+        assert_eq!(NONE.opt_gcd(MY_4), None);
+        assert_eq!(NONE.opt_gcd(SOME_4), None);
+    }
+
+    #[test]
+    fn gcd_ints() {
+        assert_eq!(Some(48u32).opt_gcd(Some(18u32)), Some(6));
+        assert_eq!(Some(17u8).opt_gcd(Some(5u8)), Some(1));
+        assert_eq!(Some(u128::MAX).opt_gcd(Some(u128::MAX)), Some(u128::MAX));
+        assert_eq!(Some(1u64 << 40).opt_gcd(Some(3u64 << 20)), Some(1 << 20));
+
+        assert_eq!(Some(-48i32).opt_gcd(Some(18i32)), Some(6u32));
+        assert_eq!(Some(-48i32).opt_gcd(Some(-18i32)), Some(6u32));
+        assert_eq!(Some(i64::MIN).opt_gcd(Some(0i64)), Some(1u64 << 63));
+        assert_eq!(Some(i8::MIN).opt_gcd(Some(i8::MIN)), Some(128u8));
+        assert_eq!(Some(0i16).opt_gcd(Some(0i16)), Some(0u16));
+    }
+
+    #[test]
+    fn lcm_my() {
+        assert_eq!(MY_4.opt_lcm(MY_6), SOME_12);
+        assert_eq!(MY_4.opt_lcm(SOME_6), SOME_12);
+        assert_eq!(MY_4.opt_lcm(&SOME_12), SOME_12);
+        assert_eq!(SOME_6.opt_lcm(MY_4), SOME_12);
+        assert_eq!(SOME_6.opt_lcm(SOME_4), SOME_12);
+        assert_eq!(SOME_4.opt_lcm(&SOME_6), SOME_12);
+
+        assert_eq!(MY_0.opt_lcm(MY_6), SOME_0);
+        assert_eq!(SOME_6.opt_lcm(MY_0), SOME_0);
+        assert_eq!(SOME_0.opt_lcm(SOME_0), SOME_0);
+
+        assert_eq!(MY_4.opt_lcm(NONE), None);
+        assert_eq!(NONE.opt_lcm(SOME_4), None);
+
+        assert_eq!(Some(-4i32).opt_lcm(Some(6i32)), Some(12u32));
+        assert_eq!(Some(21u16).opt_lcm(Some(6u16)), Some(42));
+    }
+
+    #[test]
+    fn checked_lcm() {
+        assert_eq!(MY_4.opt_checked_lcm(MY_6), Ok(SOME_12));
+        assert_eq!(MY_4.opt_checked_lcm(SOME_6), Ok(SOME_12));
+        assert_eq!(MY_4.opt_checked_lcm(&SOME_12), Ok(SOME_12));
+        assert_eq!(SOME_6.opt_checked_lcm(MY_4), Ok(SOME_12));
+        assert_eq!(SOME_6.opt_checked_lcm(SOME_4), Ok(SOME_12));
+        assert_eq!(SOME_4.opt_checked_lcm(&SOME_6), Ok(SOME_12));
+        assert_eq!(SOME_0.opt_checked_lcm(SOME_6), Ok(SOME_0));
+        assert_eq!(SOME_0.opt_checked_lcm(SOME_0), Ok(SOME_0));
+
+        assert_eq!(MY_HALF_MAX.opt_checked_lcm(MY_4), Err(Error::Overflow));
+        assert_eq!(SOME_4.opt_checked_lcm(MY_HALF_MAX), Err(Error::Overflow));
+
+        assert_eq!(MY_4.opt_checked_lcm(NONE), Ok(None));
+        assert_eq!(NONE.opt_checked_lcm(SOME_4), Ok(None));
+        assert_eq!(NONE.opt_checked_lcm(MY_HALF_MAX), Ok(None));
+
+        assert_eq!(Some(i8::MIN).opt_checked_lcm(Some(1i8)), Ok(Some(128u8)));
+        assert_eq!(
+            Some(i8::MIN).opt_checked_lcm(Some(3i8)),
+            Err(Error::Overflow)
+        );
+        assert_eq!(Some(255u8).opt_checked_lcm(Some(2u8)), Err(Error::Overflow));
+    }
+}
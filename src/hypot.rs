@@ -0,0 +1,109 @@
+//! Traits for the hypotenuse [`OptionOperations`].
+
+use crate::OptionOperations;
+
+option_op_custom!(
+    Hypot,
+    hypot,
+    "hypotenuse",
+    "
+The result is the length of the hypotenuse of a right-angle triangle with
+legs of length `self` and `rhs`, i.e. the Euclidean distance computed
+without intermediate overflow or underflow, unlike `sqrt(x * x + y * y)`.
+
+Float implementations are only available with the `std` feature or,
+in `no_std` environments, with the `libm` feature.",
+);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_hypot_for_floats {
+    ($($typ_:ty => $libm_hypot:ident),+ $(,)?) => {
+        $(
+            impl OptionHypot for $typ_ {
+                type Output = Self;
+
+                #[inline]
+                fn opt_hypot(self, rhs: Self) -> Option<Self::Output> {
+                    #[cfg(feature = "std")]
+                    let res = self.hypot(rhs);
+                    #[cfg(not(feature = "std"))]
+                    let res = libm::$libm_hypot(self, rhs);
+
+                    Some(res)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_hypot_for_floats!(f32 => hypotf, f64 => hypot);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyFloat(f64);
+
+    impl OptionOperations for MyFloat {}
+
+    impl OptionHypot for MyFloat {
+        type Output = MyFloat;
+        fn opt_hypot(self, rhs: MyFloat) -> Option<Self::Output> {
+            Some(MyFloat((self.0 * self.0 + rhs.0 * rhs.0).sqrt()))
+        }
+    }
+
+    const MY_3: MyFloat = MyFloat(3.0);
+    const MY_4: MyFloat = MyFloat(4.0);
+    const MY_5: MyFloat = MyFloat(5.0);
+    const SOME_3: Option<MyFloat> = Some(MY_3);
+    const SOME_4: Option<MyFloat> = Some(MY_4);
+    const SOME_5: Option<MyFloat> = Some(MY_5);
+    const NONE: Option<MyFloat> = None;
+
+    #[test]
+    fn hypot_my() {
+        assert_eq!(MY_3.opt_hypot(MY_4), SOME_5);
+        assert_eq!(MY_3.opt_hypot(SOME_4), SOME_5);
+        assert_eq!(MY_3.opt_hypot(&SOME_4), SOME_5);
+        assert_eq!(SOME_3.opt_hypot(MY_4), SOME_5);
+        assert_eq!(SOME_3.opt_hypot(SOME_4), SOME_5);
+        assert_eq!(SOME_3.opt_hypot(&SOME_4), SOME_5);
+        assert_eq!(MY_3.opt_hypot(NONE), None);
+        assert_eq!(SOME_3.opt_hypot(NONE), None);
+        assert_eq!(NONE.opt_hypot(MY_4), None);
+        assert_eq!(NONE.opt_hypot(SOME_4), None);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn hypot_float() {
+        let naive = |x: f64, y: f64| (x * x + y * y).sqrt();
+
+        for &(dx, dy) in &[(3.0, 4.0), (-1.5, 2.0), (0.1, -0.2), (12.0, 0.0)] {
+            let res = Some(dx).opt_hypot(Some(dy)).unwrap();
+            assert!((res - naive(dx, dy)).abs() <= 1e-12 * res);
+        }
+
+        assert_eq!(Some(3.0f32).opt_hypot(4.0), Some(5.0));
+        assert_eq!(Some(3.0f64).opt_hypot(Option::<f64>::None), None);
+        assert_eq!(Option::<f64>::None.opt_hypot(Some(4.0)), None);
+        assert_eq!(Option::<f32>::None.opt_hypot(Option::<f32>::None), None);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn hypot_float_no_overflow() {
+        let big = f64::MAX / 2.0;
+        assert!((big * big).is_infinite());
+        assert_eq!(Some(big).opt_hypot(0.0), Some(big));
+        assert!(Some(big).opt_hypot(big).unwrap().is_finite());
+
+        let tiny = f64::MIN_POSITIVE;
+        assert_eq!(tiny * tiny, 0.0);
+        assert!(Some(tiny).opt_hypot(tiny).unwrap() > 0.0);
+    }
+}
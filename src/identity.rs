@@ -0,0 +1,158 @@
+//! Trait for [`OptionOperations`] treating `None` as the identity element.
+
+use core::iter::{self, Product, Sum};
+use core::ops::{Add, Mul};
+
+use crate::OptionOperations;
+
+/// Trait for values and `Option`s operations where `None` is replaced
+/// by the identity element of the operation.
+///
+/// Contrary to the other [`OptionOperations`], which result in `None` as soon
+/// as an operand is `None`, these operations never short-circuit:
+///
+/// ```
+/// # use option_operations::{OptionAdd, OptionOrIdentity};
+/// let none = Option::<u64>::None;
+///
+/// assert_eq!(none.opt_add(Some(5)), None);
+/// assert_eq!(none.opt_add_or_identity(Some(5)), 5);
+/// assert_eq!(none.opt_add_or_identity(none), 0);
+/// assert_eq!(none.opt_mul_or_identity(none), 1);
+/// ```
+///
+/// The identity element is obtained from the `Sum` and `Product`
+/// implementations on an empty iterator, i.e. `0` and `1` for numeric types.
+///
+/// This trait is auto-implemented for [`OptionOperations`] types and `Option`s
+/// of [`OptionOperations`] types.
+pub trait OptionOrIdentity<T>: Into<Option<T>> {
+    /// Computes the addition, replacing `None` operands with the
+    /// additive identity.
+    ///
+    /// Returns the additive identity if both operands are `None`.
+    ///
+    /// # Panics
+    ///
+    /// Most implementations will panic on overflow in debug mode.
+    #[must_use]
+    fn opt_add_or_identity<Rhs>(self, rhs: Rhs) -> T
+    where
+        Rhs: Into<Option<T>>,
+        T: Add<Output = T> + Sum<T>,
+    {
+        match (self.into(), rhs.into()) {
+            (Some(lhs), Some(rhs)) => lhs + rhs,
+            (Some(value), None) | (None, Some(value)) => value,
+            (None, None) => iter::empty::<T>().sum(),
+        }
+    }
+
+    /// Computes the multiplication, replacing `None` operands with the
+    /// multiplicative identity.
+    ///
+    /// Returns the multiplicative identity if both operands are `None`.
+    ///
+    /// # Panics
+    ///
+    /// Most implementations will panic on overflow in debug mode.
+    #[must_use]
+    fn opt_mul_or_identity<Rhs>(self, rhs: Rhs) -> T
+    where
+        Rhs: Into<Option<T>>,
+        T: Mul<Output = T> + Product<T>,
+    {
+        match (self.into(), rhs.into()) {
+            (Some(lhs), Some(rhs)) => lhs * rhs,
+            (Some(value), None) | (None, Some(value)) => value,
+            (None, None) => iter::empty::<T>().product(),
+        }
+    }
+}
+
+impl<T> OptionOrIdentity<T> for T where T: OptionOperations {}
+
+impl<T> OptionOrIdentity<T> for Option<T> where T: OptionOperations {}
+
+#[cfg(test)]
+mod test {
+    use core::iter::{Product, Sum};
+    use core::ops::{Add, Mul};
+
+    use super::OptionOrIdentity;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl Add<MyInt> for MyInt {
+        type Output = MyInt;
+
+        fn add(self, rhs: MyInt) -> MyInt {
+            MyInt(self.0 + rhs.0)
+        }
+    }
+
+    impl Mul<MyInt> for MyInt {
+        type Output = MyInt;
+
+        fn mul(self, rhs: MyInt) -> MyInt {
+            MyInt(self.0 * rhs.0)
+        }
+    }
+
+    impl Sum<MyInt> for MyInt {
+        fn sum<I: Iterator<Item = MyInt>>(iter: I) -> MyInt {
+            MyInt(iter.map(|my| my.0).sum())
+        }
+    }
+
+    impl Product<MyInt> for MyInt {
+        fn product<I: Iterator<Item = MyInt>>(iter: I) -> MyInt {
+            MyInt(iter.map(|my| my.0).product())
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const MY_3: MyInt = MyInt(3);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn add_or_identity() {
+        assert_eq!(MY_2.opt_add_or_identity(MY_3), MyInt(5));
+        assert_eq!(MY_2.opt_add_or_identity(SOME_3), MyInt(5));
+        assert_eq!(SOME_2.opt_add_or_identity(MY_3), MyInt(5));
+        assert_eq!(SOME_2.opt_add_or_identity(SOME_3), MyInt(5));
+
+        assert_eq!(MY_2.opt_add_or_identity(NONE), MY_2);
+        assert_eq!(SOME_2.opt_add_or_identity(NONE), MY_2);
+        assert_eq!(NONE.opt_add_or_identity(MY_3), MY_3);
+        assert_eq!(NONE.opt_add_or_identity(SOME_3), MY_3);
+        assert_eq!(NONE.opt_add_or_identity(NONE), MY_0);
+
+        assert_eq!(Option::<u8>::None.opt_add_or_identity(Some(5)), 5);
+        assert_eq!(Option::<f64>::None.opt_add_or_identity(None), 0.0);
+    }
+
+    #[test]
+    fn mul_or_identity() {
+        assert_eq!(MY_2.opt_mul_or_identity(MY_3), MyInt(6));
+        assert_eq!(MY_2.opt_mul_or_identity(SOME_3), MyInt(6));
+        assert_eq!(SOME_2.opt_mul_or_identity(MY_3), MyInt(6));
+        assert_eq!(SOME_2.opt_mul_or_identity(SOME_3), MyInt(6));
+
+        assert_eq!(MY_2.opt_mul_or_identity(NONE), MY_2);
+        assert_eq!(SOME_2.opt_mul_or_identity(NONE), MY_2);
+        assert_eq!(NONE.opt_mul_or_identity(SOME_3), MY_3);
+        assert_eq!(NONE.opt_mul_or_identity(NONE), MY_1);
+
+        assert_eq!(Some(4u32).opt_mul_or_identity(Option::<u32>::None), 4);
+        assert_eq!(Option::<i16>::None.opt_mul_or_identity(None), 1);
+    }
+}
@@ -0,0 +1,243 @@
+//! Traits for the integer logarithm [`OptionOperations`].
+
+use crate::{Error, OptionOperations};
+
+option_op_custom!(
+    Ilog,
+    ilog,
+    "integer logarithm with respect to an arbitrary base, rounded down",
+    "
+# Panics
+
+Most implementations will panic if `self` is less than or equal to zero
+or if `rhs`, the base, is less than 2.
+See [`OptionCheckedIlog`] for a non-panicking alternative.",
+);
+
+impl_for_ints!(OptionIlog, {
+    type Output = u32;
+    #[inline]
+    fn opt_ilog(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.ilog(rhs))
+    }
+});
+
+option_op_checked!(
+    Ilog,
+    ilog,
+    "integer logarithm with respect to an arbitrary base, rounded down",
+    "- Returns `Err(Error::Domain)` if `self` is less than or equal to zero
+or if `rhs`, the base, is less than 2.",
+);
+
+impl_for_ints!(OptionCheckedIlog, {
+    type Output = u32;
+    #[inline]
+    fn opt_checked_ilog(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        self.checked_ilog(rhs).ok_or(Error::Domain).map(Some)
+    }
+});
+
+option_unary_op_custom!(
+    Ilog2,
+    ilog2,
+    "base 2 integer logarithm, rounded down",
+    "
+# Panics
+
+Most implementations will panic if `self` is less than or equal to zero.
+See [`OptionCheckedIlog2`] for a non-panicking alternative.",
+);
+
+impl_for_ints!(OptionIlog2, {
+    type Output = u32;
+    #[inline]
+    fn opt_ilog2(self) -> Option<Self::Output> {
+        Some(self.ilog2())
+    }
+});
+
+option_unary_op_checked!(
+    Ilog2,
+    ilog2,
+    "base 2 integer logarithm, rounded down",
+    "- Returns `Err(Error::Domain)` if `self` is less than or equal to zero.",
+);
+
+impl_for_ints!(OptionCheckedIlog2, {
+    type Output = u32;
+    #[inline]
+    fn opt_checked_ilog2(self) -> Result<Option<Self::Output>, Error> {
+        self.checked_ilog2().ok_or(Error::Domain).map(Some)
+    }
+});
+
+option_unary_op_custom!(
+    Ilog10,
+    ilog10,
+    "base 10 integer logarithm, rounded down",
+    "
+# Panics
+
+Most implementations will panic if `self` is less than or equal to zero.
+See [`OptionCheckedIlog10`] for a non-panicking alternative.",
+);
+
+impl_for_ints!(OptionIlog10, {
+    type Output = u32;
+    #[inline]
+    fn opt_ilog10(self) -> Option<Self::Output> {
+        Some(self.ilog10())
+    }
+});
+
+option_unary_op_checked!(
+    Ilog10,
+    ilog10,
+    "base 10 integer logarithm, rounded down",
+    "- Returns `Err(Error::Domain)` if `self` is less than or equal to zero.",
+);
+
+impl_for_ints!(OptionCheckedIlog10, {
+    type Output = u32;
+    #[inline]
+    fn opt_checked_ilog10(self) -> Result<Option<Self::Output>, Error> {
+        self.checked_ilog10().ok_or(Error::Domain).map(Some)
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionIlog for MyInt {
+        type Output = u32;
+        fn opt_ilog(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_ilog(rhs.0)
+        }
+    }
+
+    impl OptionCheckedIlog for MyInt {
+        type Output = u32;
+        fn opt_checked_ilog(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_ilog(rhs.0)
+        }
+    }
+
+    impl OptionIlog2 for MyInt {
+        type Output = u32;
+        fn opt_ilog2(self) -> Option<Self::Output> {
+            self.0.opt_ilog2()
+        }
+    }
+
+    impl OptionCheckedIlog2 for MyInt {
+        type Output = u32;
+        fn opt_checked_ilog2(self) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_ilog2()
+        }
+    }
+
+    impl OptionIlog10 for MyInt {
+        type Output = u32;
+        fn opt_ilog10(self) -> Option<Self::Output> {
+            self.0.opt_ilog10()
+        }
+    }
+
+    impl OptionCheckedIlog10 for MyInt {
+        type Output = u32;
+        fn opt_checked_ilog10(self) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_ilog10()
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_3: MyInt = MyInt(3);
+    const MY_100: MyInt = MyInt(100);
+    const MY_1000: MyInt = MyInt(1000);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const SOME_100: Option<MyInt> = Some(MY_100);
+    const SOME_1000: Option<MyInt> = Some(MY_1000);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn ilog() {
+        assert_eq!(MY_100.opt_ilog(MY_3), Some(4));
+        assert_eq!(MY_100.opt_ilog(SOME_3), Some(4));
+        assert_eq!(MY_1000.opt_ilog(&Some(MyInt(10))), Some(3));
+        assert_eq!(SOME_100.opt_ilog(MY_3), Some(4));
+        assert_eq!(SOME_3.opt_ilog(SOME_3), Some(1));
+        assert_eq!(Some(MY_1).opt_ilog(&SOME_3), Some(0));
+
+        assert_eq!(MY_100.opt_ilog(NONE), None);
+        assert_eq!(NONE.opt_ilog(MY_3), None);
+        assert_eq!(NONE.opt_ilog(SOME_3), None);
+
+        assert_eq!(Some(u32::MAX).opt_ilog(Some(16u32)), Some(7));
+        assert_eq!(Some(81i32).opt_ilog(Some(3i32)), Some(4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ilog_zero() {
+        let _ = SOME_0.opt_ilog(MY_3);
+    }
+
+    #[test]
+    fn checked_ilog() {
+        assert_eq!(MY_100.opt_checked_ilog(MY_3), Ok(Some(4)));
+        assert_eq!(MY_100.opt_checked_ilog(SOME_3), Ok(Some(4)));
+        assert_eq!(SOME_1000.opt_checked_ilog(&Some(MyInt(10))), Ok(Some(3)));
+        assert_eq!(SOME_3.opt_checked_ilog(SOME_3), Ok(Some(1)));
+
+        assert_eq!(MY_0.opt_checked_ilog(MY_3), Err(Error::Domain));
+        assert_eq!(SOME_100.opt_checked_ilog(MY_1), Err(Error::Domain));
+        assert_eq!(SOME_100.opt_checked_ilog(SOME_0), Err(Error::Domain));
+
+        assert_eq!(MY_0.opt_checked_ilog(NONE), Ok(None));
+        assert_eq!(NONE.opt_checked_ilog(MY_0), Ok(None));
+
+        assert_eq!(Some(-8i32).opt_checked_ilog(Some(2i32)), Err(Error::Domain));
+        assert_eq!(Some(8i32).opt_checked_ilog(Some(-2i32)), Err(Error::Domain));
+    }
+
+    #[test]
+    fn ilog2_ilog10() {
+        assert_eq!(MY_100.opt_ilog2(), Some(6));
+        assert_eq!(SOME_1000.opt_ilog2(), Some(9));
+        assert_eq!(NONE.opt_ilog2(), None);
+        assert_eq!(Some(u128::MAX).opt_ilog2(), Some(127));
+
+        assert_eq!(MY_100.opt_ilog10(), Some(2));
+        assert_eq!(SOME_1000.opt_ilog10(), Some(3));
+        assert_eq!(Some(MY_1).opt_ilog10(), Some(0));
+        assert_eq!(NONE.opt_ilog10(), None);
+        assert_eq!(Some(i8::MAX).opt_ilog10(), Some(2));
+    }
+
+    #[test]
+    fn checked_ilog2_ilog10() {
+        assert_eq!(MY_100.opt_checked_ilog2(), Ok(Some(6)));
+        assert_eq!(SOME_3.opt_checked_ilog2(), Ok(Some(1)));
+        assert_eq!(SOME_0.opt_checked_ilog2(), Err(Error::Domain));
+        assert_eq!(NONE.opt_checked_ilog2(), Ok(None));
+
+        assert_eq!(Some(0u32).opt_checked_ilog2(), Err(Error::Domain));
+        assert_eq!(Some(-1i64).opt_checked_ilog2(), Err(Error::Domain));
+        assert_eq!(Some(1u32).opt_checked_ilog2(), Ok(Some(0)));
+
+        assert_eq!(MY_1000.opt_checked_ilog10(), Ok(Some(3)));
+        assert_eq!(SOME_0.opt_checked_ilog10(), Err(Error::Domain));
+        assert_eq!(NONE.opt_checked_ilog10(), Ok(None));
+        assert_eq!(Some(-10i16).opt_checked_ilog10(), Err(Error::Domain));
+    }
+}
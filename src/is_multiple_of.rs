@@ -0,0 +1,155 @@
+//! Trait for the multiple predicate [`OptionOperations`].
+
+use crate::OptionOperations;
+
+/// Trait for values and `Option`s that can be tested for being a multiple
+/// of another value.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionIsMultipleOf<Option<InnerRhs>>` for `T`.
+/// - `OptionIsMultipleOf<Rhs>` for `Option<T>`.
+/// - `OptionIsMultipleOf<Option<InnerRhs>>` for `Option<T>`.
+/// - ... and some variants with references.
+///
+/// Note that since the `std` library doesn't define any `IsMultipleOf` trait,
+/// users must provide the base implementation for the inner type.
+pub trait OptionIsMultipleOf<Rhs = Self, InnerRhs = Rhs> {
+    /// Tests whether `self` is an integer multiple of `rhs`.
+    ///
+    /// Following `u32::is_multiple_of`, `0` is the only multiple of `0`:
+    /// `x.opt_is_multiple_of(0)` is `Some(true)` if `x` is `0` and
+    /// `Some(false)` otherwise.
+    ///
+    /// Returns `None` if at least one argument is `None`.
+    ///
+    /// ```
+    /// # use option_operations::OptionIsMultipleOf;
+    /// let stride = Some(16u32);
+    /// assert_eq!(Some(64u32).opt_is_multiple_of(stride), Some(true));
+    /// assert_eq!(Some(65u32).opt_is_multiple_of(stride), Some(false));
+    /// assert_eq!(Some(65u32).opt_is_multiple_of(Option::<u32>::None), None);
+    /// ```
+    #[must_use]
+    fn opt_is_multiple_of(self, rhs: Rhs) -> Option<bool>;
+}
+
+impl<T, InnerRhs> OptionIsMultipleOf<Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + OptionIsMultipleOf<InnerRhs>,
+{
+    #[inline]
+    fn opt_is_multiple_of(self, rhs: Option<InnerRhs>) -> Option<bool> {
+        rhs.and_then(|inner_rhs| self.opt_is_multiple_of(inner_rhs))
+    }
+}
+
+impl<T, InnerRhs> OptionIsMultipleOf<&Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + OptionIsMultipleOf<InnerRhs>,
+    InnerRhs: Copy,
+{
+    #[inline]
+    fn opt_is_multiple_of(self, rhs: &Option<InnerRhs>) -> Option<bool> {
+        rhs.as_ref()
+            .and_then(|inner_rhs| self.opt_is_multiple_of(*inner_rhs))
+    }
+}
+
+impl<T, Rhs> OptionIsMultipleOf<Rhs> for Option<T>
+where
+    T: OptionOperations + OptionIsMultipleOf<Rhs>,
+{
+    #[inline]
+    fn opt_is_multiple_of(self, rhs: Rhs) -> Option<bool> {
+        self.and_then(|inner_self| inner_self.opt_is_multiple_of(rhs))
+    }
+}
+
+impl<T, InnerRhs> OptionIsMultipleOf<Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + OptionIsMultipleOf<InnerRhs>,
+{
+    #[inline]
+    fn opt_is_multiple_of(self, rhs: Option<InnerRhs>) -> Option<bool> {
+        self.zip(rhs)
+            .and_then(|(inner_self, inner_rhs)| inner_self.opt_is_multiple_of(inner_rhs))
+    }
+}
+
+impl<T, InnerRhs> OptionIsMultipleOf<&Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + OptionIsMultipleOf<InnerRhs>,
+    InnerRhs: Copy,
+{
+    #[inline]
+    fn opt_is_multiple_of(self, rhs: &Option<InnerRhs>) -> Option<bool> {
+        self.zip(rhs.as_ref())
+            .and_then(|(inner_self, inner_rhs)| inner_self.opt_is_multiple_of(*inner_rhs))
+    }
+}
+
+impl_for_unsigned_ints!(OptionIsMultipleOf, {
+    #[inline]
+    fn opt_is_multiple_of(self, rhs: Self) -> Option<bool> {
+        Some(self.is_multiple_of(rhs))
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::OptionIsMultipleOf;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionIsMultipleOf for MyInt {
+        fn opt_is_multiple_of(self, rhs: MyInt) -> Option<bool> {
+            self.0.opt_is_multiple_of(rhs.0)
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_3: MyInt = MyInt(3);
+    const MY_4: MyInt = MyInt(4);
+    const MY_12: MyInt = MyInt(12);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const SOME_4: Option<MyInt> = Some(MY_4);
+    const SOME_12: Option<MyInt> = Some(MY_12);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn is_multiple_of() {
+        assert_eq!(MY_12.opt_is_multiple_of(MY_3), Some(true));
+        assert_eq!(MY_12.opt_is_multiple_of(SOME_4), Some(true));
+        assert_eq!(MY_4.opt_is_multiple_of(&SOME_3), Some(false));
+        assert_eq!(SOME_12.opt_is_multiple_of(MY_4), Some(true));
+        assert_eq!(SOME_3.opt_is_multiple_of(SOME_12), Some(false));
+        assert_eq!(SOME_12.opt_is_multiple_of(&SOME_12), Some(true));
+        assert_eq!(SOME_0.opt_is_multiple_of(SOME_3), Some(true));
+
+        assert_eq!(MY_12.opt_is_multiple_of(NONE), None);
+        assert_eq!(SOME_12.opt_is_multiple_of(&NONE), None);
+        assert_eq!(NONE.opt_is_multiple_of(MY_3), None);
+        assert_eq!(NONE.opt_is_multiple_of(SOME_3), None);
+
+        assert_eq!(Some(64u8).opt_is_multiple_of(Some(16u8)), Some(true));
+        assert_eq!(Some(u128::MAX).opt_is_multiple_of(Some(3u128)), Some(true));
+    }
+
+    #[test]
+    fn is_multiple_of_zero() {
+        assert_eq!(MY_0.opt_is_multiple_of(MY_0), Some(true));
+        assert_eq!(MY_3.opt_is_multiple_of(MY_0), Some(false));
+        assert_eq!(SOME_0.opt_is_multiple_of(SOME_0), Some(true));
+        assert_eq!(SOME_12.opt_is_multiple_of(&SOME_0), Some(false));
+        assert_eq!(NONE.opt_is_multiple_of(SOME_0), None);
+
+        assert_eq!(Some(0u32).opt_is_multiple_of(Some(0u32)), Some(true));
+        assert_eq!(Some(1u32).opt_is_multiple_of(Some(0u32)), Some(false));
+    }
+}
@@ -0,0 +1,504 @@
+//! Iterator adapters folding `Option`s with [`OptionOperations`].
+
+use core::iter::{self, Product, Sum};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{Error, OptionAdd, OptionCheckedAdd, OptionCheckedMul, OptionMul, OptionOperations};
+
+/// Extension trait for iterators over `Option<T>`.
+///
+/// The folding methods short-circuit as soon as a `None` is encountered,
+/// in which case the result is `None`. An empty iterator results in
+/// the additive or multiplicative identity wrapped in `Some`.
+///
+/// This trait is auto-implemented for any `Iterator<Item = Option<T>>`.
+pub trait OptionIteratorExt<T>: Iterator<Item = Option<T>> + Sized {
+    /// Sums the elements of the iterator.
+    ///
+    /// Returns `None` if at least one element is `None`.
+    ///
+    /// ```
+    /// # use option_operations::OptionIteratorExt;
+    /// assert_eq!([Some(1), Some(2), Some(3)].iter().copied().opt_sum(), Some(6));
+    /// assert_eq!([Some(1), None, Some(3)].iter().copied().opt_sum(), None);
+    /// assert_eq!(core::iter::empty::<Option<u32>>().opt_sum(), Some(0));
+    /// ```
+    #[must_use]
+    fn opt_sum(self) -> Option<T>
+    where
+        T: OptionOperations + OptionAdd<T, Output = T> + Sum<T>,
+    {
+        let mut acc = iter::empty::<T>().sum::<T>();
+        for item in self {
+            acc = acc.opt_add(item?)?;
+        }
+
+        Some(acc)
+    }
+
+    /// Multiplies the elements of the iterator.
+    ///
+    /// Returns `None` if at least one element is `None`.
+    #[must_use]
+    fn opt_product(self) -> Option<T>
+    where
+        T: OptionOperations + OptionMul<T, Output = T> + Product<T>,
+    {
+        let mut acc = iter::empty::<T>().product::<T>();
+        for item in self {
+            acc = acc.opt_mul(item?)?;
+        }
+
+        Some(acc)
+    }
+
+    /// Sums the elements of the iterator, checking for overflows.
+    ///
+    /// - Returns `Ok(Some(result))` if `result` could be computed.
+    /// - Returns `Ok(None)` if at least one element is `None`.
+    /// - Returns `Err(Error::Overflow)` if an overflow occured.
+    fn opt_checked_sum(self) -> Result<Option<T>, Error>
+    where
+        T: OptionOperations + OptionCheckedAdd<T, Output = T> + Sum<T>,
+    {
+        let mut acc = iter::empty::<T>().sum::<T>();
+        for item in self {
+            match item.map(|item| acc.opt_checked_add(item)).transpose()? {
+                Some(Some(res)) => acc = res,
+                _ => return Ok(None),
+            }
+        }
+
+        Ok(Some(acc))
+    }
+
+    /// Multiplies the elements of the iterator, checking for overflows.
+    ///
+    /// - Returns `Ok(Some(result))` if `result` could be computed.
+    /// - Returns `Ok(None)` if at least one element is `None`.
+    /// - Returns `Err(Error::Overflow)` if an overflow occured.
+    fn opt_checked_product(self) -> Result<Option<T>, Error>
+    where
+        T: OptionOperations + OptionCheckedMul<T, Output = T> + Product<T>,
+    {
+        let mut acc = iter::empty::<T>().product::<T>();
+        for item in self {
+            match item.map(|item| acc.opt_checked_mul(item)).transpose()? {
+                Some(Some(res)) => acc = res,
+                _ => return Ok(None),
+            }
+        }
+
+        Ok(Some(acc))
+    }
+
+    /// Yields the running sum of the elements of the iterator.
+    ///
+    /// The `policy` defines how `None` elements, i.e. gaps, are handled.
+    /// See [`GapPolicy`].
+    ///
+    /// ```
+    /// # use option_operations::{GapPolicy, OptionIteratorExt};
+    /// let values = [Some(1), Some(2), None, Some(3)];
+    ///
+    /// let hold = values.iter().copied().opt_running_sum(GapPolicy::Hold);
+    /// assert!(hold.eq([Some(1), Some(3), None, Some(6)].iter().copied()));
+    ///
+    /// let reset = values.iter().copied().opt_running_sum(GapPolicy::Reset);
+    /// assert!(reset.eq([Some(1), Some(3), None, Some(3)].iter().copied()));
+    ///
+    /// let skip = values.iter().copied().opt_running_sum(GapPolicy::Skip);
+    /// assert!(skip.eq([Some(1), Some(3), Some(6)].iter().copied()));
+    /// ```
+    fn opt_running_sum(self, policy: GapPolicy) -> OptionRunningSum<Self, T>
+    where
+        T: OptionOperations + OptionAdd<T, Output = T> + Sum<T> + Copy,
+    {
+        OptionRunningSum {
+            iter: self,
+            acc: iter::empty::<T>().sum::<T>(),
+            policy,
+        }
+    }
+}
+
+/// Policy for `None` elements in [`OptionIteratorExt::opt_running_sum`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GapPolicy {
+    /// Yields `None` for the gap and holds the accumulator.
+    Hold,
+    /// Yields `None` for the gap and resets the accumulator to zero.
+    Reset,
+    /// Doesn't yield anything for the gap and holds the accumulator.
+    Skip,
+}
+
+/// Iterator yielding the running sum of `Option`s.
+///
+/// This `struct` is created by [`OptionIteratorExt::opt_running_sum`].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OptionRunningSum<I, T> {
+    iter: I,
+    acc: T,
+    policy: GapPolicy,
+}
+
+impl<I, T> Iterator for OptionRunningSum<I, T>
+where
+    I: Iterator<Item = Option<T>>,
+    T: OptionOperations + OptionAdd<T, Output = T> + Sum<T> + Copy,
+{
+    type Item = Option<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Some(item) => {
+                    let res = self.acc.opt_add(item);
+                    if let Some(acc) = res {
+                        self.acc = acc;
+                    }
+                    return Some(res);
+                }
+                None => match self.policy {
+                    GapPolicy::Hold => return Some(None),
+                    GapPolicy::Reset => {
+                        self.acc = iter::empty::<T>().sum::<T>();
+                        return Some(None);
+                    }
+                    GapPolicy::Skip => (),
+                },
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        match self.policy {
+            GapPolicy::Skip => (0, upper),
+            _ => (lower, upper),
+        }
+    }
+}
+
+impl<I, T> OptionIteratorExt<T> for I where I: Iterator<Item = Option<T>> {}
+
+/// Sums the `Option`s of `values`, checking for overflows.
+///
+/// This is a standalone form of [`OptionIteratorExt::opt_checked_sum`]
+/// accepting any `IntoIterator`, convenient in `?` chains:
+///
+/// ```
+/// # use option_operations::{opt_checked_sum, Error};
+/// # fn total() -> Result<(), Error> {
+/// let total = opt_checked_sum([Some(1u8), Some(2), Some(3)])?;
+/// assert_eq!(total, Some(6));
+/// # Ok(())
+/// # }
+/// # total().unwrap();
+/// ```
+///
+/// - Returns `Ok(Some(result))` if `result` could be computed.
+/// - Returns `Ok(None)` if at least one element is `None`.
+/// - Returns `Err(Error::Overflow)` if an overflow occured.
+#[inline]
+pub fn opt_checked_sum<I, T>(values: I) -> Result<Option<T>, Error>
+where
+    I: IntoIterator<Item = Option<T>>,
+    T: OptionOperations + OptionCheckedAdd<T, Output = T> + Sum<T>,
+{
+    values.into_iter().opt_checked_sum()
+}
+
+/// Multiplies the `Option`s of `values`, checking for overflows.
+///
+/// This is a standalone form of [`OptionIteratorExt::opt_checked_product`]
+/// accepting any `IntoIterator`.
+///
+/// - Returns `Ok(Some(result))` if `result` could be computed.
+/// - Returns `Ok(None)` if at least one element is `None`.
+/// - Returns `Err(Error::Overflow)` if an overflow occured.
+#[inline]
+pub fn opt_checked_product<I, T>(values: I) -> Result<Option<T>, Error>
+where
+    I: IntoIterator<Item = Option<T>>,
+    T: OptionOperations + OptionCheckedMul<T, Output = T> + Product<T>,
+{
+    values.into_iter().opt_checked_product()
+}
+
+/// Multiplies the `Option`s of `slice` by `factor`, checking for overflows.
+///
+/// The `None` elements of `slice` are kept as is. If `factor` is `None`,
+/// all the elements of the result are `None`.
+///
+/// ```
+/// # use option_operations::{opt_checked_scale_slice, Error};
+/// let samples = [Some(1i32), None, Some(-3)];
+/// assert_eq!(opt_checked_scale_slice(&samples, 2), Ok(vec![Some(2), None, Some(-6)]));
+/// assert_eq!(opt_checked_scale_slice(&samples, None), Ok(vec![None, None, None]));
+/// assert_eq!(opt_checked_scale_slice(&samples, i32::MAX), Err(Error::Overflow));
+/// ```
+///
+/// - Returns `Ok(scaled)` if the elements could be scaled.
+/// - Returns `Err(Error::Overflow)` on the first element which overflows.
+#[cfg(feature = "alloc")]
+pub fn opt_checked_scale_slice<T, Rhs>(
+    slice: &[Option<T>],
+    factor: impl Into<Option<Rhs>>,
+) -> Result<Vec<Option<T::Output>>, Error>
+where
+    T: OptionOperations + OptionCheckedMul<Rhs> + Copy,
+    Rhs: Copy,
+{
+    let factor = factor.into();
+    slice
+        .iter()
+        .map(|value| value.opt_checked_mul(factor))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use core::iter::Sum;
+    use core::ops::Add;
+
+    use super::{opt_checked_product, opt_checked_sum, GapPolicy, OptionIteratorExt};
+    use crate::{Error, OptionCheckedMul, OptionOperations};
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl Add<MyInt> for MyInt {
+        type Output = MyInt;
+
+        fn add(self, rhs: MyInt) -> MyInt {
+            MyInt(self.0.add(rhs.0))
+        }
+    }
+
+    impl Sum for MyInt {
+        fn sum<I: Iterator<Item = MyInt>>(iter: I) -> MyInt {
+            MyInt(iter.map(|my| my.0).sum())
+        }
+    }
+
+    impl OptionCheckedMul for MyInt {
+        type Output = MyInt;
+        fn opt_checked_mul(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_mul(rhs.0).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_3: Option<MyInt> = Some(MyInt(3));
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn sum_my() {
+        assert_eq!([SOME_1, SOME_2].iter().copied().opt_sum(), SOME_3);
+        assert_eq!([SOME_1, NONE, SOME_2].iter().copied().opt_sum(), NONE);
+        assert_eq!([NONE].iter().copied().opt_sum(), NONE);
+        assert_eq!(core::iter::empty::<Option<MyInt>>().opt_sum(), SOME_0);
+    }
+
+    #[test]
+    fn sum_product() {
+        assert_eq!(
+            [Some(2), Some(3), Some(4)].iter().copied().opt_sum(),
+            Some(9)
+        );
+        assert_eq!(
+            [Some(2), Some(3), Some(4)].iter().copied().opt_product(),
+            Some(24)
+        );
+        assert_eq!([Some(2), None].iter().copied().opt_product(), None);
+        assert_eq!(core::iter::empty::<Option<u8>>().opt_product(), Some(1));
+        assert_eq!(
+            [Some(0.5f64), Some(4.0)].iter().copied().opt_product(),
+            Some(2.0)
+        );
+    }
+
+    #[test]
+    fn checked_sum_product() {
+        assert_eq!(
+            [Some(100u8), Some(100)].iter().copied().opt_checked_sum(),
+            Ok(Some(200))
+        );
+        assert_eq!(
+            [Some(200u8), Some(100)].iter().copied().opt_checked_sum(),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            [Some(200u8), None, Some(100)]
+                .iter()
+                .copied()
+                .opt_checked_sum(),
+            Ok(None)
+        );
+        assert_eq!(
+            [None, Some(200u8), Some(100)]
+                .iter()
+                .copied()
+                .opt_checked_sum(),
+            Ok(None)
+        );
+        assert_eq!(
+            [Some(16u8), Some(16)].iter().copied().opt_checked_product(),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            [Some(15u8), Some(17)].iter().copied().opt_checked_product(),
+            Ok(Some(255))
+        );
+        assert_eq!(
+            core::iter::empty::<Option<u8>>().opt_checked_sum(),
+            Ok(Some(0))
+        );
+        assert_eq!(
+            core::iter::empty::<Option<u8>>().opt_checked_product(),
+            Ok(Some(1))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn vec_of_options() {
+        let values: Vec<Option<i32>> = vec![Some(1), Some(-2), Some(3)];
+        assert_eq!(values.iter().copied().opt_sum(), Some(2));
+        assert_eq!(values.iter().copied().opt_product(), Some(-6));
+        assert_eq!(values.into_iter().opt_checked_sum(), Ok(Some(2)));
+
+        let values: Vec<Option<i32>> = vec![Some(i32::MAX), None, Some(1)];
+        assert_eq!(values.iter().copied().opt_sum(), None);
+        assert_eq!(values.into_iter().opt_checked_sum(), Ok(None));
+
+        let values: Vec<Option<i32>> = vec![Some(i32::MAX), Some(1), None];
+        assert_eq!(values.into_iter().opt_checked_sum(), Err(Error::Overflow));
+
+        let values: Vec<Option<i32>> = Vec::new();
+        assert_eq!(values.iter().copied().opt_sum(), Some(0));
+        assert_eq!(values.into_iter().opt_product(), Some(1));
+    }
+
+    #[test]
+    fn checked_collectors() {
+        assert_eq!(opt_checked_sum([Some(100u8), Some(100)]), Ok(Some(200)));
+        assert_eq!(
+            opt_checked_sum([Some(200u8), Some(100)]),
+            Err(Error::Overflow)
+        );
+        assert_eq!(opt_checked_sum([Some(1u8), None, Some(2)]), Ok(None));
+        // The first `None` short-circuits, even before an overflow.
+        assert_eq!(opt_checked_sum([None, Some(200u8), Some(100)]), Ok(None));
+        assert_eq!(
+            opt_checked_sum([Some(200u8), Some(100), None]),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            opt_checked_sum(core::iter::empty::<Option<u32>>()),
+            Ok(Some(0))
+        );
+        assert_eq!(
+            opt_checked_sum([Some(-2i8), Some(-3)].iter().copied()),
+            Ok(Some(-5))
+        );
+
+        assert_eq!(opt_checked_product([Some(16u8), Some(15)]), Ok(Some(240)));
+        assert_eq!(
+            opt_checked_product([Some(16u8), Some(16)]),
+            Err(Error::Overflow)
+        );
+        assert_eq!(opt_checked_product([Some(2u8), None]), Ok(None));
+        assert_eq!(
+            opt_checked_product(core::iter::empty::<Option<u64>>()),
+            Ok(Some(1))
+        );
+    }
+
+    #[test]
+    fn running_sum() {
+        const VALUES: [Option<i64>; 7] = [Some(1), Some(2), None, None, Some(3), None, Some(4)];
+
+        let hold = VALUES.iter().copied().opt_running_sum(GapPolicy::Hold);
+        assert!(
+            hold.eq([Some(1), Some(3), None, None, Some(6), None, Some(10)]
+                .iter()
+                .copied())
+        );
+
+        let reset = VALUES.iter().copied().opt_running_sum(GapPolicy::Reset);
+        assert!(
+            reset.eq([Some(1), Some(3), None, None, Some(3), None, Some(4)]
+                .iter()
+                .copied())
+        );
+
+        let skip = VALUES.iter().copied().opt_running_sum(GapPolicy::Skip);
+        assert!(skip.eq([Some(1), Some(3), Some(6), Some(10)].iter().copied()));
+
+        let gaps = [None, None].iter().copied();
+        assert_eq!(
+            gaps.clone().opt_running_sum(GapPolicy::Skip).next(),
+            None::<Option<i64>>
+        );
+        assert_eq!(gaps.opt_running_sum(GapPolicy::Hold).count(), 2);
+
+        let my = [Some(MyInt(1)), None, Some(MyInt(2))].iter().copied();
+        assert!(my
+            .opt_running_sum(GapPolicy::Reset)
+            .eq([Some(MyInt(1)), None, Some(MyInt(2))].iter().copied()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn checked_scale_slice() {
+        use super::opt_checked_scale_slice;
+        use alloc::vec;
+
+        let samples = [Some(1i32), None, Some(-3), Some(100)];
+        assert_eq!(
+            opt_checked_scale_slice(&samples, 3),
+            Ok(vec![Some(3), None, Some(-9), Some(300)])
+        );
+        assert_eq!(
+            opt_checked_scale_slice(&samples, Some(-1)),
+            Ok(vec![Some(-1), None, Some(3), Some(-100)])
+        );
+        assert_eq!(
+            opt_checked_scale_slice(&samples, Option::<i32>::None),
+            Ok(vec![None, None, None, None])
+        );
+        assert_eq!(
+            opt_checked_scale_slice(&[] as &[Option<i32>], 2),
+            Ok(vec![])
+        );
+
+        // Overflow in the middle of the slice.
+        let samples = [Some(1i32), None, Some(i32::MAX / 2 + 1), Some(2)];
+        assert_eq!(opt_checked_scale_slice(&samples, 2), Err(Error::Overflow));
+        assert_eq!(
+            opt_checked_scale_slice(&[Some(i32::MIN)], -1),
+            Err(Error::Overflow)
+        );
+
+        let my = [Some(MyInt(2)), None];
+        assert_eq!(
+            opt_checked_scale_slice(&my, MyInt(3)),
+            Ok(vec![Some(MyInt(6)), None])
+        );
+    }
+}
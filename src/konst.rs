@@ -0,0 +1,139 @@
+//! `const` functions for the checked [`OptionOperations`] on primitive integers.
+//!
+//! Trait methods can't be called in `const` contexts. The functions in
+//! this module provide the checked operations for each primitive integer
+//! type, e.g. [`opt_checked_div_i64`], so they can be used to build
+//! `const` values:
+//!
+//! ```
+//! # use option_operations::{konst::opt_checked_div_i64, Error};
+//! const HALF: Result<Option<i64>, Error> = opt_checked_div_i64(Some(10), Some(2));
+//! assert_eq!(HALF, Ok(Some(5)));
+//! ```
+
+// Required for doc
+#[allow(unused)]
+use crate::OptionOperations;
+
+use crate::Error;
+
+macro_rules! const_checked_op {
+    ($typ_:ident, $op:ident, $op_name:tt, $zero_check:tt, $error:ident) => {
+        paste::paste! {
+            #[doc = "Computes the checked " $op_name " of two `Option<" $typ_ ">`s in `const` contexts."]
+            ///
+            /// - Returns `Ok(Some(result))` if `result` could be computed.
+            /// - Returns `Ok(None)` if at least one argument is `None`.
+            #[doc = const_checked_op!(@error_doc $error)]
+            #[doc = const_checked_op!(@zero_doc $zero_check)]
+            pub const fn [<opt_checked_ $op _ $typ_>](
+                lhs: Option<$typ_>,
+                rhs: Option<$typ_>,
+            ) -> Result<Option<$typ_>, Error> {
+                match (lhs, rhs) {
+                    (Some(lhs), Some(rhs)) => {
+                        if $zero_check && rhs == 0 {
+                            return Err(Error::DivisionByZero);
+                        }
+                        match lhs.[<checked_ $op>](rhs) {
+                            Some(res) => Ok(Some(res)),
+                            None => Err(Error::$error),
+                        }
+                    }
+                    _ => Ok(None),
+                }
+            }
+        }
+    };
+
+    (@error_doc Overflow) => {
+        "- Returns `Err(Error::Overflow)` if an overflow occured."
+    };
+
+    (@error_doc Underflow) => {
+        "- Returns `Err(Error::Underflow)` if the result would be negative."
+    };
+
+    (@zero_doc true) => {
+        "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero."
+    };
+
+    (@zero_doc false) => {
+        ""
+    };
+}
+
+macro_rules! const_checked_ops {
+    ($sub_error:ident: $($typ_:ident),+ $(,)?) => {
+        $(
+            const_checked_op!($typ_, add, addition, false, Overflow);
+            const_checked_op!($typ_, sub, substraction, false, $sub_error);
+            const_checked_op!($typ_, mul, multiplication, false, Overflow);
+            const_checked_op!($typ_, div, division, true, Overflow);
+            const_checked_op!($typ_, rem, remainder, true, Overflow);
+        )+
+    };
+}
+
+const_checked_ops!(Overflow: i8, i16, i32, i64, i128, isize);
+// Consistent with `OptionCheckedSub` for unsigned integers.
+const_checked_ops!(Underflow: u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SUM: Result<Option<u32>, Error> = opt_checked_add_u32(Some(1), Some(2));
+    const OVERFLOW: Result<Option<u8>, Error> = opt_checked_add_u8(Some(u8::MAX), Some(1));
+    const QUOTIENT: Result<Option<i64>, Error> = opt_checked_div_i64(Some(10), Some(2));
+    const DIV_BY_ZERO: Result<Option<i64>, Error> = opt_checked_div_i64(Some(10), Some(0));
+    const NONE_DIV: Result<Option<i64>, Error> = opt_checked_div_i64(None, Some(0));
+
+    const TABLE: [Result<Option<i16>, Error>; 3] = [
+        opt_checked_mul_i16(Some(2), Some(3)),
+        opt_checked_sub_i16(Some(i16::MIN), Some(1)),
+        opt_checked_rem_i16(Some(7), None),
+    ];
+
+    #[test]
+    fn const_checked() {
+        assert_eq!(SUM, Ok(Some(3)));
+        assert_eq!(OVERFLOW, Err(Error::Overflow));
+        assert_eq!(QUOTIENT, Ok(Some(5)));
+        assert_eq!(DIV_BY_ZERO, Err(Error::DivisionByZero));
+        assert_eq!(NONE_DIV, Ok(None));
+        assert_eq!(TABLE, [Ok(Some(6)), Err(Error::Overflow), Ok(None)]);
+
+        assert_eq!(
+            opt_checked_div_i8(Some(i8::MIN), Some(-1)),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            opt_checked_rem_u128(Some(7), Some(0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(opt_checked_rem_u128(Some(7), Some(4)), Ok(Some(3)));
+    }
+
+    #[test]
+    fn const_checked_sub_unsigned() {
+        assert_eq!(opt_checked_sub_u8(Some(0), Some(1)), Err(Error::Underflow));
+        assert_eq!(opt_checked_sub_u16(Some(0), Some(1)), Err(Error::Underflow));
+        assert_eq!(opt_checked_sub_u32(Some(0), Some(1)), Err(Error::Underflow));
+        assert_eq!(opt_checked_sub_u64(Some(0), Some(1)), Err(Error::Underflow));
+        assert_eq!(
+            opt_checked_sub_u128(Some(0), Some(1)),
+            Err(Error::Underflow)
+        );
+        assert_eq!(
+            opt_checked_sub_usize(Some(0), Some(1)),
+            Err(Error::Underflow)
+        );
+        assert_eq!(opt_checked_sub_u8(Some(1), Some(1)), Ok(Some(0)));
+        assert_eq!(opt_checked_sub_u64(None, Some(1)), Ok(None));
+        assert_eq!(
+            opt_checked_add_u8(Some(u8::MAX), Some(1)),
+            Err(Error::Overflow)
+        );
+    }
+}
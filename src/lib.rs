@@ -80,9 +80,36 @@
 //! assert_eq!(none.opt_lt(&some_0), None);
 //! assert_eq!(none.opt_min(some_0), None);
 //! ```
+//!
+//! # Custom types
+//!
+//! Custom types participate in `option-operations` by implementing
+//! [`OptionOperations`]. The [`impl_option_operations_for`] macro
+//! implements it for a list of types at once:
+//!
+//! ```
+//! # use option_operations::{impl_option_operations_for, OptionOrd};
+//! #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+//! struct Meters(u32);
+//!
+//! #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+//! struct Seconds(u32);
+//!
+//! impl_option_operations_for!(Meters, Seconds);
+//!
+//! assert_eq!(Some(Meters(1)).opt_lt(Meters(2)), Some(true));
+//! assert_eq!(Some(Seconds(1)).opt_lt(None), None);
+//! ```
+//!
+//! For newtypes over a primitive integer, [`impl_option_operations`] also
+//! forwards the checked, overflowing, wrapping and saturating arithmetic
+//! to the inner type.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 /// Trait for inner types participating in `option-operations`.
 ///
 /// The purpose of this trait is twofold:
@@ -90,76 +117,457 @@
 /// - Auto-implement various `Option*` traits such as `OptionOrd`.
 /// - Prevent some conflicting auto-implementation of traits on
 ///   `Option<T>`.
+///
+/// The latter is also why this trait can't be blanket-implemented, e.g.
+/// for all `num_traits::Num` types: the compiler can't rule out that
+/// `Option<T>` implements the bound, which would make the `Option<T>`
+/// auto-implementations conflict with the `T` ones. Custom types must thus
+/// implement this trait explicitly, which [`impl_option_operations_for`]
+/// makes concise for several types at once.
+///
+/// Tuples of up to 3 [`OptionOperations`] types are [`OptionOperations`]
+/// types. Tuples of `Option`s also implement [`OptionAdd`], [`OptionSub`]
+/// and [`OptionMul`] element-wise. A `None` element only leads to a `None`
+/// for that element:
+///
+/// ```
+/// # use option_operations::OptionAdd;
+/// let pos: (Option<f64>, Option<f64>) = (Some(1.0), Some(2.0));
+///
+/// let offset: (Option<f64>, Option<f64>) = (Some(0.5), Some(-1.0));
+/// assert_eq!(pos.opt_add(offset), Some((Some(1.5), Some(1.0))));
+///
+/// let offset: (Option<f64>, Option<f64>) = (None, Some(-1.0));
+/// assert_eq!(pos.opt_add(offset), Some((None, Some(1.0))));
+/// ```
 pub trait OptionOperations {}
 
 impl<T: OptionOperations> OptionOperations for &T {}
 impl<T: OptionOperations> OptionOperations for &mut T {}
+impl<T: OptionOperations> OptionOperations for core::num::Wrapping<T> {}
+impl<T: OptionOperations> OptionOperations for core::num::Saturating<T> {}
+impl OptionOperations for char {}
+impl OptionOperations for core::cmp::Ordering {}
+
+/// Applies `f` to the inner values of `a` and `b` if both are `Some`.
+///
+/// This is the building block of most auto-implementations in this crate.
+/// It can be used to define option-aware operations which are not covered
+/// by the provided traits.
+///
+/// Returns `None` if at least one argument is `None`.
+///
+/// ```
+/// # use option_operations::opt_zip_with;
+/// assert_eq!(opt_zip_with(Some(2u64), Some(10u32), |a, b| a.pow(b)), Some(1024));
+/// assert_eq!(opt_zip_with(Some(2u64), None, |a, b: u32| a.pow(b)), None);
+/// ```
+#[inline]
+pub fn opt_zip_with<A, B, C, F>(a: Option<A>, b: Option<B>, f: F) -> Option<C>
+where
+    F: FnOnce(A, B) -> C,
+{
+    a.zip(b).map(|(a, b)| f(a, b))
+}
+
+/// Applies `f` to the inner values of `a`, `b` and `c` if all are `Some`.
+///
+/// See [`opt_zip_with`].
+///
+/// Returns `None` if at least one argument is `None`.
+///
+/// ```
+/// # use option_operations::opt_zip_with3;
+/// let mul_add = |a: f64, b: f64, c: f64| a * b + c;
+/// assert_eq!(opt_zip_with3(Some(2.0), Some(3.0), Some(1.0), mul_add), Some(7.0));
+/// assert_eq!(opt_zip_with3(Some(2.0), None, Some(1.0), mul_add), None);
+/// ```
+#[inline]
+pub fn opt_zip_with3<A, B, C, D, F>(a: Option<A>, b: Option<B>, c: Option<C>, f: F) -> Option<D>
+where
+    F: FnOnce(A, B, C) -> D,
+{
+    match (a, b, c) {
+        (Some(a), Some(b), Some(c)) => Some(f(a, b, c)),
+        _ => None,
+    }
+}
 
 #[macro_use]
 mod macros;
 
 impl_for_all!(OptionOperations);
+impl_for_non_zero_ints!(OptionOperations);
+
+pub mod abs;
+pub use abs::{OptionAbs, OptionCheckedAbs, OptionOverflowingAbs, OptionWrappingAbs};
+
+pub mod abs_diff;
+pub use abs_diff::OptionAbsDiff;
 
 pub mod add;
 pub use add::{
-    OptionAdd, OptionAddAssign, OptionCheckedAdd, OptionOverflowingAdd, OptionSaturatingAdd,
-    OptionWrappingAdd,
+    OptionAdd, OptionAddAssign, OptionCheckedAdd, OptionCheckedAddAssign, OptionOverflowingAdd,
+    OptionSaturatingAdd, OptionWrappingAdd,
 };
 
+pub mod approx_eq;
+pub use approx_eq::OptionApproxEq;
+
+pub mod bitand;
+pub use bitand::{OptionBitAnd, OptionBitAndAssign};
+
+pub mod bitor;
+pub use bitor::{OptionBitOr, OptionBitOrAssign};
+
+pub mod bits;
+pub use bits::{OptionCountOnes, OptionLeadingZeros, OptionTrailingZeros};
+
+pub mod bitxor;
+pub use bitxor::{OptionBitXor, OptionBitXorAssign};
+
+pub mod ceil_div;
+pub use ceil_div::{OptionCeilDiv, OptionCheckedCeilDiv};
+
+pub mod clamp;
+pub use clamp::{OptionClamp, OptionClampAssign, OptionClampToRange};
+
+pub mod convert;
+pub use convert::{opt_parse, OptionCheckedCastUnsigned, OptionTryInto};
+
+pub mod copysign;
+pub use copysign::OptionCopysign;
+
+pub mod display;
+pub use display::{DisplayOr, OptionDisplay};
+
+pub mod div_rem;
+pub use div_rem::{OptionCheckedDivRem, OptionDivRem};
+
 pub mod error;
+#[cfg(feature = "detailed-errors")]
+pub use error::DetailedError;
 pub use error::Error;
 
 pub mod div;
 pub use div::{
-    OptionCheckedDiv, OptionDiv, OptionDivAssign, OptionOverflowingDiv, OptionWrappingDiv,
+    OptionCheckedDiv, OptionCheckedDivAssign, OptionCheckedDivWidening, OptionDiv, OptionDivAssign,
+    OptionDivOr, OptionOverflowingDiv, OptionSaturatingDiv, OptionWrappingDiv,
 };
 
+pub mod div_euclid;
+pub use div_euclid::{OptionCheckedDivEuclid, OptionDivEuclid, OptionDivEuclidAssign};
+
 pub mod eq;
 pub use eq::OptionEq;
 
+pub mod floor_div;
+pub use floor_div::{OptionCheckedFloorDiv, OptionFloorDiv};
+
+pub mod gcd;
+pub use gcd::{OptionCheckedLcm, OptionGcd, OptionLcm};
+
+pub mod hypot;
+pub use hypot::OptionHypot;
+
+pub mod identity;
+pub use identity::OptionOrIdentity;
+
+pub mod ilog;
+pub use ilog::{
+    OptionCheckedIlog, OptionCheckedIlog10, OptionCheckedIlog2, OptionIlog, OptionIlog10,
+    OptionIlog2,
+};
+
+pub mod is_multiple_of;
+pub use is_multiple_of::OptionIsMultipleOf;
+
+pub mod iter;
+#[cfg(feature = "alloc")]
+pub use iter::opt_checked_scale_slice;
+pub use iter::{opt_checked_product, opt_checked_sum, GapPolicy, OptionIteratorExt};
+
+pub mod konst;
+
+pub mod midpoint;
+pub use midpoint::OptionMidpoint;
+
 pub mod min_max;
-pub use min_max::OptionMinMax;
+pub use min_max::{OptionMinMax, Side};
 
 pub mod mul;
 pub use mul::{
-    OptionCheckedMul, OptionMul, OptionMulAssign, OptionOverflowingMul, OptionSaturatingMul,
-    OptionWrappingMul,
+    OptionCheckedMul, OptionCheckedMulAssign, OptionMul, OptionMulAssign, OptionOverflowingMul,
+    OptionSaturatingMul, OptionWrappingMul,
+};
+
+pub mod mul_add;
+pub use mul_add::{OptionCheckedMulAdd, OptionMulAdd};
+
+pub mod neg;
+pub use neg::{
+    OptionCheckedNeg, OptionCheckedNegAssign, OptionNeg, OptionNegAssign, OptionOverflowingNeg,
+    OptionSaturatingNeg, OptionWrappingNeg,
 };
 
+mod newtype;
+
+pub mod next_power_of_two;
+pub use next_power_of_two::{OptionCheckedNextPowerOfTwo, OptionNextPowerOfTwo};
+
+pub mod not;
+pub use not::{OptionBitNot, OptionBitNotAssign};
+
+#[cfg(feature = "num-traits")]
+pub mod num;
+#[cfg(feature = "num-traits")]
+pub use num::NumOperations;
+
 pub mod ord;
-pub use ord::OptionOrd;
+pub use ord::{NoneOrdering, OptionOrd};
+
+pub mod partial_cmp;
+pub use partial_cmp::OptionPartialCmp;
+
+pub mod pow;
+pub use pow::{
+    OptionCheckedPow, OptionOverflowingPow, OptionPow, OptionSaturatingPow, OptionWrappingPow,
+};
+
+pub mod range;
+pub use range::{MapRangeValue, OptionMapRange, OptionRangeContains};
+
+pub mod reciprocal;
+pub use reciprocal::{OptionCheckedReciprocal, OptionReciprocal};
 
 pub mod rem;
 pub use rem::{
-    OptionCheckedRem, OptionOverflowingRem, OptionRem, OptionRemAssign, OptionWrappingRem,
+    OptionCheckedRem, OptionCheckedRemAssign, OptionOverflowingRem, OptionRem, OptionRemAssign,
+    OptionWrappingRem,
 };
 
+pub mod rem_euclid;
+pub use rem_euclid::{OptionCheckedRemEuclid, OptionRemEuclid, OptionRemEuclidAssign};
+
+pub mod rotate;
+pub use rotate::{OptionRotateLeft, OptionRotateRight};
+
+pub mod rounded_div;
+pub use rounded_div::{OptionRoundedDiv, RoundingMode};
+
+pub mod shl;
+pub use shl::{
+    OptionCheckedShl, OptionCheckedShlAssign, OptionOverflowingShl, OptionShl, OptionShlAssign,
+    OptionWrappingShl, OptionWrappingShlAssign,
+};
+
+pub mod shr;
+pub use shr::{
+    OptionCheckedShr, OptionCheckedShrAssign, OptionOverflowingShr, OptionShr, OptionShrAssign,
+    OptionWrappingShr, OptionWrappingShrAssign,
+};
+
+pub mod sign;
+pub use sign::{OptionIsNegative, OptionIsPositive};
+
+pub mod signum;
+pub use signum::OptionSignum;
+
+#[cfg(feature = "alloc")]
+pub mod sort;
+#[cfg(feature = "alloc")]
+pub use sort::OptionSliceSortExt;
+
+pub mod sqrt;
+pub use sqrt::{OptionCheckedIsqrt, OptionCheckedSqrt, OptionIsqrt, OptionSqrt};
+
 pub mod sub;
 pub use sub::{
-    OptionCheckedSub, OptionOverflowingSub, OptionSaturatingSub, OptionSub, OptionSubAssign,
-    OptionWrappingSub,
+    OptionCheckedSub, OptionCheckedSubAssign, OptionMonus, OptionOverflowingSub,
+    OptionSaturatingSub, OptionSub, OptionSubAssign, OptionWrappingSub,
 };
 
+pub mod then;
+pub use then::OptionThen;
+
+pub mod total_cmp;
+pub use total_cmp::OptionTotalCmp;
+
+mod tuple;
+
+pub mod util;
+pub use util::{opt_result_transpose, result_opt_transpose};
+
 pub mod prelude {
+    pub use crate::abs::{OptionAbs, OptionCheckedAbs, OptionOverflowingAbs, OptionWrappingAbs};
+    pub use crate::abs_diff::OptionAbsDiff;
     pub use crate::add::{
-        OptionAdd, OptionAddAssign, OptionCheckedAdd, OptionOverflowingAdd, OptionSaturatingAdd,
-        OptionWrappingAdd,
+        OptionAdd, OptionAddAssign, OptionCheckedAdd, OptionCheckedAddAssign, OptionOverflowingAdd,
+        OptionSaturatingAdd, OptionWrappingAdd,
     };
+    pub use crate::approx_eq::OptionApproxEq;
+    pub use crate::bitand::{OptionBitAnd, OptionBitAndAssign};
+    pub use crate::bitor::{OptionBitOr, OptionBitOrAssign};
+    pub use crate::bits::{OptionCountOnes, OptionLeadingZeros, OptionTrailingZeros};
+    pub use crate::bitxor::{OptionBitXor, OptionBitXorAssign};
+    pub use crate::ceil_div::{OptionCeilDiv, OptionCheckedCeilDiv};
+    pub use crate::clamp::{OptionClamp, OptionClampAssign, OptionClampToRange};
+    pub use crate::convert::{OptionCheckedCastUnsigned, OptionTryInto};
+    pub use crate::copysign::OptionCopysign;
+    pub use crate::display::{DisplayOr, OptionDisplay};
     pub use crate::div::{
-        OptionCheckedDiv, OptionDiv, OptionDivAssign, OptionOverflowingDiv, OptionWrappingDiv,
+        OptionCheckedDiv, OptionCheckedDivAssign, OptionCheckedDivWidening, OptionDiv,
+        OptionDivAssign, OptionDivOr, OptionOverflowingDiv, OptionSaturatingDiv, OptionWrappingDiv,
+    };
+    pub use crate::div_euclid::{OptionCheckedDivEuclid, OptionDivEuclid, OptionDivEuclidAssign};
+    pub use crate::div_rem::{OptionCheckedDivRem, OptionDivRem};
+    pub use crate::floor_div::{OptionCheckedFloorDiv, OptionFloorDiv};
+    pub use crate::gcd::{OptionCheckedLcm, OptionGcd, OptionLcm};
+    pub use crate::hypot::OptionHypot;
+    pub use crate::identity::OptionOrIdentity;
+    pub use crate::ilog::{
+        OptionCheckedIlog, OptionCheckedIlog10, OptionCheckedIlog2, OptionIlog, OptionIlog10,
+        OptionIlog2,
     };
-    pub use crate::min_max::OptionMinMax;
+    pub use crate::is_multiple_of::OptionIsMultipleOf;
+    pub use crate::iter::{GapPolicy, OptionIteratorExt};
+    pub use crate::midpoint::OptionMidpoint;
+    pub use crate::min_max::{OptionMinMax, Side};
     pub use crate::mul::{
-        OptionCheckedMul, OptionMul, OptionMulAssign, OptionOverflowingMul, OptionSaturatingMul,
-        OptionWrappingMul,
+        OptionCheckedMul, OptionCheckedMulAssign, OptionMul, OptionMulAssign, OptionOverflowingMul,
+        OptionSaturatingMul, OptionWrappingMul,
+    };
+    pub use crate::mul_add::{OptionCheckedMulAdd, OptionMulAdd};
+    pub use crate::neg::{
+        OptionCheckedNeg, OptionCheckedNegAssign, OptionNeg, OptionNegAssign, OptionOverflowingNeg,
+        OptionSaturatingNeg, OptionWrappingNeg,
     };
-    pub use crate::ord::OptionOrd;
+    pub use crate::next_power_of_two::{OptionCheckedNextPowerOfTwo, OptionNextPowerOfTwo};
+    pub use crate::not::{OptionBitNot, OptionBitNotAssign};
+    #[cfg(feature = "num-traits")]
+    pub use crate::num::NumOperations;
+    pub use crate::ord::{NoneOrdering, OptionOrd};
+    pub use crate::partial_cmp::OptionPartialCmp;
+    pub use crate::pow::{
+        OptionCheckedPow, OptionOverflowingPow, OptionPow, OptionSaturatingPow, OptionWrappingPow,
+    };
+    pub use crate::range::{MapRangeValue, OptionMapRange, OptionRangeContains};
+    pub use crate::reciprocal::{OptionCheckedReciprocal, OptionReciprocal};
     pub use crate::rem::{
-        OptionCheckedRem, OptionOverflowingRem, OptionRem, OptionRemAssign, OptionWrappingRem,
+        OptionCheckedRem, OptionCheckedRemAssign, OptionOverflowingRem, OptionRem, OptionRemAssign,
+        OptionWrappingRem,
+    };
+    pub use crate::rem_euclid::{OptionCheckedRemEuclid, OptionRemEuclid, OptionRemEuclidAssign};
+    pub use crate::rotate::{OptionRotateLeft, OptionRotateRight};
+    pub use crate::rounded_div::{OptionRoundedDiv, RoundingMode};
+    pub use crate::shl::{
+        OptionCheckedShl, OptionCheckedShlAssign, OptionOverflowingShl, OptionShl, OptionShlAssign,
+        OptionWrappingShl, OptionWrappingShlAssign,
+    };
+    pub use crate::shr::{
+        OptionCheckedShr, OptionCheckedShrAssign, OptionOverflowingShr, OptionShr, OptionShrAssign,
+        OptionWrappingShr, OptionWrappingShrAssign,
     };
+    pub use crate::sign::{OptionIsNegative, OptionIsPositive};
+    pub use crate::signum::OptionSignum;
+    #[cfg(feature = "alloc")]
+    pub use crate::sort::OptionSliceSortExt;
+    pub use crate::sqrt::{OptionCheckedIsqrt, OptionCheckedSqrt, OptionIsqrt, OptionSqrt};
     pub use crate::sub::{
-        OptionCheckedSub, OptionOverflowingSub, OptionSaturatingSub, OptionSub, OptionSubAssign,
-        OptionWrappingSub,
+        OptionCheckedSub, OptionCheckedSubAssign, OptionMonus, OptionOverflowingSub,
+        OptionSaturatingSub, OptionSub, OptionSubAssign, OptionWrappingSub,
     };
+    pub use crate::then::OptionThen;
+    pub use crate::total_cmp::OptionTotalCmp;
     pub use crate::OptionOperations;
+
+    /// Only the assignment traits, e.g. [`OptionAddAssign`].
+    ///
+    /// Importing this module instead of the whole [`prelude`](crate::prelude)
+    /// avoids method-name ambiguities with the by-value traits in code
+    /// which only mutates values in place.
+    ///
+    /// ```
+    /// use option_operations::prelude::assign::*;
+    ///
+    /// let mut total = Some(40u64);
+    /// total.opt_add_assign(Some(2));
+    /// assert_eq!(total, Some(42));
+    ///
+    /// let mut shift = Some(1u8);
+    /// assert!(shift.opt_checked_shl_assign(8).is_err());
+    /// assert_eq!(shift, Some(1));
+    /// ```
+    pub mod assign {
+        pub use crate::add::{OptionAddAssign, OptionCheckedAddAssign};
+        pub use crate::bitand::OptionBitAndAssign;
+        pub use crate::bitor::OptionBitOrAssign;
+        pub use crate::bitxor::OptionBitXorAssign;
+        pub use crate::clamp::OptionClampAssign;
+        pub use crate::div::{OptionCheckedDivAssign, OptionDivAssign};
+        pub use crate::div_euclid::OptionDivEuclidAssign;
+        pub use crate::mul::{OptionCheckedMulAssign, OptionMulAssign};
+        pub use crate::neg::{OptionCheckedNegAssign, OptionNegAssign};
+        pub use crate::not::OptionBitNotAssign;
+        pub use crate::rem::{OptionCheckedRemAssign, OptionRemAssign};
+        pub use crate::rem_euclid::OptionRemEuclidAssign;
+        pub use crate::shl::{OptionCheckedShlAssign, OptionShlAssign, OptionWrappingShlAssign};
+        pub use crate::shr::{OptionCheckedShrAssign, OptionShrAssign, OptionWrappingShrAssign};
+        pub use crate::sub::{OptionCheckedSubAssign, OptionSubAssign};
+        pub use crate::OptionOperations;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    #[allow(unused)]
+    use crate::Error;
+
+    // Exercises the impls each feature provides, so that running the test
+    // suite with a given set of features checks that this set builds.
+    #[test]
+    fn feature_matrix() {
+        assert_eq!(Some(1u8).opt_checked_add(1), Ok(Some(2)));
+        assert_eq!(Some(1.5f64).opt_checked_mul(2.0), Ok(Some(3.0)));
+        assert_eq!(Some('a').opt_checked_add(1), Ok(Some('b')));
+
+        #[cfg(feature = "duration")]
+        {
+            use core::time::Duration;
+
+            let secs_1 = Duration::from_secs(1);
+            assert_eq!(
+                Some(secs_1).opt_checked_add(secs_1),
+                Ok(Some(Duration::from_secs(2)))
+            );
+            assert_eq!(secs_1.opt_checked_div(0u32), Err(Error::DivisionByZero));
+            assert_eq!(Some(secs_1).opt_lt(Duration::MAX), Some(true));
+        }
+
+        #[cfg(all(feature = "std", feature = "duration"))]
+        {
+            use std::time::{Duration, Instant};
+
+            let now = Instant::now();
+            assert_eq!(Some(now).opt_checked_add(Duration::ZERO), Ok(Some(now)));
+            assert_eq!(now.opt_checked_sub(Duration::ZERO), Ok(Some(now)));
+            assert_eq!(now.opt_checked_sub(now), Ok(Some(Duration::ZERO)));
+        }
+
+        #[cfg(any(feature = "std", feature = "libm"))]
+        assert_eq!(Some(2.0f64).opt_mul_add(3.0, 1.0), Some(7.0));
+
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(
+                crate::opt_checked_scale_slice(&[Some(2u8), None], 3),
+                Ok(alloc::vec![Some(6), None])
+            );
+
+            let mut values = alloc::vec![Some(2u8), None, Some(1)];
+            values.opt_sort(NoneOrdering::Last);
+            assert_eq!(values, [Some(1), Some(2), None]);
+        }
+    }
 }
@@ -1,62 +1,99 @@
 macro_rules! impl_for {
-    ($trait:ident, $typ_:ty, $block:tt) => {
+    ($trait:path, $typ_:ty, $block:tt) => {
         impl $trait for $typ_ $block
     };
 }
 
-macro_rules! impl_for_ints {
-    ($trait:ident, $block:tt) => {
+macro_rules! impl_for_signed_ints {
+    ($trait:path, $block:tt) => {
         impl_for!($trait, i8, $block);
         impl_for!($trait, i16, $block);
         impl_for!($trait, i32, $block);
         impl_for!($trait, i64, $block);
         impl_for!($trait, i128, $block);
+        impl_for!($trait, isize, $block);
+    };
+}
+
+macro_rules! impl_for_unsigned_ints {
+    ($trait:path, $block:tt) => {
         impl_for!($trait, u8, $block);
         impl_for!($trait, u16, $block);
         impl_for!($trait, u32, $block);
         impl_for!($trait, u64, $block);
         impl_for!($trait, u128, $block);
+        impl_for!($trait, usize, $block);
+    };
+}
+
+macro_rules! impl_for_ints {
+    ($trait:path, $block:tt) => {
+        impl_for_signed_ints!($trait, $block);
+        impl_for_unsigned_ints!($trait, $block);
+    };
+}
+
+macro_rules! impl_for_non_zero_ints {
+    ($trait:path, $block:tt) => {
+        impl_for!($trait, core::num::NonZeroI8, $block);
+        impl_for!($trait, core::num::NonZeroI16, $block);
+        impl_for!($trait, core::num::NonZeroI32, $block);
+        impl_for!($trait, core::num::NonZeroI64, $block);
+        impl_for!($trait, core::num::NonZeroI128, $block);
+        impl_for!($trait, core::num::NonZeroIsize, $block);
+        impl_for!($trait, core::num::NonZeroU8, $block);
+        impl_for!($trait, core::num::NonZeroU16, $block);
+        impl_for!($trait, core::num::NonZeroU32, $block);
+        impl_for!($trait, core::num::NonZeroU64, $block);
+        impl_for!($trait, core::num::NonZeroU128, $block);
+        impl_for!($trait, core::num::NonZeroUsize, $block);
+    };
+
+    ($trait:path) => {
+        impl_for_non_zero_ints!($trait, {});
     };
 }
 
 macro_rules! impl_for_floats {
-    ($trait:ident, $block:tt) => {
+    ($trait:path, $block:tt) => {
         impl_for!($trait, f32, $block);
         impl_for!($trait, f64, $block);
     };
 }
 
 macro_rules! impl_for_numerics {
-    ($trait:ident, $block:tt) => {
+    ($trait:path, $block:tt) => {
         impl_for_ints!($trait, $block);
         impl_for_floats!($trait, $block);
     };
 }
 
 macro_rules! impl_for_time_types {
-    ($trait:ident, $block:tt) => {
+    ($trait:path, $block:tt) => {
+        #[cfg(feature = "duration")]
         impl_for!($trait, core::time::Duration, $block);
-        #[cfg(feature = "std")]
+        #[cfg(all(feature = "std", feature = "duration"))]
         impl_for!($trait, std::time::Instant, $block);
-        #[cfg(feature = "std")]
+        #[cfg(all(feature = "std", feature = "duration"))]
         impl_for!($trait, std::time::SystemTime, $block);
     };
 }
 
 macro_rules! impl_for_ints_and_duration {
-    ($trait:ident, $block:tt) => {
+    ($trait:path, $block:tt) => {
         impl_for_ints!($trait, $block);
+        #[cfg(feature = "duration")]
         impl_for!($trait, core::time::Duration, $block);
     };
 }
 
 macro_rules! impl_for_all {
-    ($trait:ident, $block:tt) => {
+    ($trait:path, $block:tt) => {
         impl_for_numerics!($trait, $block);
         impl_for_time_types!($trait, $block);
     };
 
-    ($trait:ident) => {
+    ($trait:path) => {
         impl_for_all!($trait, {});
     };
 }
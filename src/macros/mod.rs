@@ -7,9 +7,18 @@ mod option_op;
 #[macro_use]
 mod option_op_assign;
 
+#[macro_use]
+mod option_op_assign_custom;
+
 #[macro_use]
 mod option_op_checked;
 
+#[macro_use]
+mod option_op_checked_assign;
+
+#[macro_use]
+mod option_op_custom;
+
 #[macro_use]
 mod option_op_overflowing;
 
@@ -19,8 +28,14 @@ mod option_op_saturating;
 #[macro_use]
 mod option_op_wrapping;
 
+#[macro_use]
+mod option_unary_op_checked;
+
+#[macro_use]
+mod option_unary_op_custom;
+
 macro_rules! common_option_op {
-    ($trait:ident, $op:ident, $op_name:ident $(, $extra_doc:expr)? $(,)?) => {
+    ($trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
         paste::paste! {
             option_op!(
                 $trait,
@@ -1,5 +1,5 @@
 macro_rules! option_op {
-    ($op_trait:ident, $op:ident, $op_name:ident $(, $extra_doc:expr)? $(,)?) => {
+    ($op_trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
         paste::paste! {
             #[doc = "Trait for values and `Option`s " $op_name "."]
             ///
@@ -12,6 +12,11 @@ macro_rules! option_op {
             ///
             /// This trait is auto-implemented for [`OptionOperations`] types implementing
             #[doc = "`" $op_trait "<Rhs>`."]
+            ///
+            /// Since `&T` is an [`OptionOperations`] type if `T` is, `Option<&T>`
+            /// can be used directly, e.g. when iterating over a `&[Option<T>]`,
+            #[doc = "as long as `&T` implements `" $op_trait "<Rhs>`, which is the case"]
+            /// for the primitive types.
             pub trait [<Option $op_trait>]<Rhs= Self, InnerRhs = Rhs> {
                 #[doc = "The resulting inner type after applying the " $op_name "."]
                 type Output;
@@ -30,6 +35,7 @@ macro_rules! option_op {
             {
                 type Output = <T as $op_trait<Rhs>>::Output;
 
+                #[inline]
                 fn [<opt_ $op>](self, rhs: Rhs) -> Option<Self::Output> {
                     Some(self.$op(rhs))
                 }
@@ -41,6 +47,7 @@ macro_rules! option_op {
             {
                 type Output = <T as [<Option $op_trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_ $op>](self, rhs: Option<InnerRhs>) -> Option<Self::Output> {
                     rhs.and_then(|inner_rhs| self.[<opt_ $op>](inner_rhs))
                 }
@@ -53,6 +60,7 @@ macro_rules! option_op {
             {
                 type Output = <T as [<Option $op_trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_ $op>](self, rhs: &Option<InnerRhs>) -> Option<Self::Output> {
                     rhs.as_ref().and_then(|inner_rhs| self.[<opt_ $op>](*inner_rhs))
                 }
@@ -64,6 +72,7 @@ macro_rules! option_op {
             {
                 type Output = <T as [<Option $op_trait>]<Rhs>>::Output;
 
+                #[inline]
                 fn [<opt_ $op>](self, rhs: Rhs) -> Option<Self::Output> {
                     self.and_then(|inner_self| inner_self.[<opt_ $op>](rhs))
                 }
@@ -75,6 +84,7 @@ macro_rules! option_op {
             {
                 type Output = <T as [<Option $op_trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_ $op>](self, rhs: Option<InnerRhs>) -> Option<Self::Output> {
                     self.zip(rhs)
                         .and_then(|(inner_self, inner_rhs)| inner_self.[<opt_ $op>](inner_rhs))
@@ -88,6 +98,7 @@ macro_rules! option_op {
             {
                 type Output = <T as [<Option $op_trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_ $op>](self, rhs: &Option<InnerRhs>) -> Option<Self::Output> {
                     self.zip(rhs.as_ref())
                         .and_then(|(inner_self, inner_rhs)| inner_self.[<opt_ $op>](*inner_rhs))
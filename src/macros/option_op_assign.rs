@@ -1,5 +1,5 @@
 macro_rules! option_op_assign {
-    ($trait:ident, $op:ident, $op_name:ident $(, $extra_doc:expr)? $(,)?) => {
+    ($trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
         paste::paste! {
             #[doc = "Trait for values and `Option`s " $op_name " assignment."]
             ///
@@ -24,6 +24,7 @@ macro_rules! option_op_assign {
             where
                 T: OptionOperations + [<$trait Assign>]<Rhs>,
             {
+                #[inline]
                 fn [<opt_ $op _assign>](&mut self, rhs: Rhs) {
                     self.[<$op _assign>](rhs)
                 }
@@ -33,6 +34,7 @@ macro_rules! option_op_assign {
             where
                 T: OptionOperations + [<Option $trait Assign>]<InnerRhs>,
             {
+                #[inline]
                 fn [<opt_ $op _assign>](&mut self, rhs: Option<InnerRhs>) {
                     if let Some(inner_rhs) = rhs {
                         self.[<opt_ $op _assign>](inner_rhs)
@@ -45,6 +47,7 @@ macro_rules! option_op_assign {
                 T: OptionOperations + [<Option $trait Assign>]<InnerRhs>,
                 InnerRhs: Copy,
             {
+                #[inline]
                 fn [<opt_ $op _assign>](&mut self, rhs: &Option<InnerRhs>) {
                     if let Some(inner_rhs) = rhs.as_ref() {
                         self.[<opt_ $op _assign>](*inner_rhs)
@@ -56,6 +59,7 @@ macro_rules! option_op_assign {
             where
                 T: OptionOperations + [<Option $trait Assign>]<Rhs>,
             {
+                #[inline]
                 fn [<opt_ $op _assign>](&mut self, rhs: Rhs) {
                     if let Some(inner_self) = self {
                         inner_self.[<opt_ $op _assign>](rhs)
@@ -67,6 +71,7 @@ macro_rules! option_op_assign {
             where
                 T: OptionOperations + [<Option $trait Assign>]<InnerRhs>,
             {
+                #[inline]
                 fn [<opt_ $op _assign>](&mut self, rhs: Option<InnerRhs>) {
                     if let Some((inner_self, inner_rhs)) = self.as_mut().zip(rhs) {
                         inner_self.[<opt_ $op _assign>](inner_rhs)
@@ -79,6 +84,7 @@ macro_rules! option_op_assign {
                 T: OptionOperations + [<Option $trait Assign>]<InnerRhs>,
                 InnerRhs: Copy,
             {
+                #[inline]
                 fn [<opt_ $op _assign>](&mut self, rhs: &Option<InnerRhs>) {
                     if let Some((inner_self, inner_rhs)) = self.as_mut().zip(rhs.as_ref()) {
                         inner_self.[<opt_ $op _assign>](*inner_rhs)
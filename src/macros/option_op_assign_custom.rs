@@ -0,0 +1,91 @@
+macro_rules! option_op_assign_custom {
+    ($trait:ident<$rhs_default:ty>, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
+        paste::paste! {
+            #[doc = "Trait for values and `Option`s " $op_name " assignment."]
+            ///
+            /// Implementing this trait leads to the following auto-implementations:
+            ///
+            #[doc = "- `" [<Option $trait Assign>] "<Option<InnerRhs>>` for `T`."]
+            #[doc = "- `" [<Option $trait Assign>] "<Rhs>` for `Option<T>`."]
+            #[doc = "- `" [<Option $trait Assign>] "<Option<InnerRhs>>` for `Option<T>`."]
+            /// - ... and some variants with references.
+            ///
+            /// Note that since the `std` library doesn't define any
+            #[doc = "`" $trait "Assign` trait, "]
+            /// users must provide the base implementation for the inner type.
+            pub trait [<Option $trait Assign>]<Rhs = $rhs_default, InnerRhs = Rhs> {
+                #[doc = "Performs the " $op_name " assignment."]
+                ///
+                /// `self` is unchanged if `rhs` is `None`.
+                $(#[doc = $extra_doc])?
+                fn [<opt_ $op _assign>](&mut self, rhs: Rhs);
+            }
+
+            impl<T, InnerRhs> [<Option $trait Assign>]<Option<InnerRhs>, InnerRhs> for T
+            where
+                T: OptionOperations + [<Option $trait Assign>]<InnerRhs>,
+            {
+                #[inline]
+                fn [<opt_ $op _assign>](&mut self, rhs: Option<InnerRhs>) {
+                    if let Some(inner_rhs) = rhs {
+                        self.[<opt_ $op _assign>](inner_rhs)
+                    }
+                }
+            }
+
+            impl<T, InnerRhs> [<Option $trait Assign>]<&Option<InnerRhs>, InnerRhs> for T
+            where
+                T: OptionOperations + [<Option $trait Assign>]<InnerRhs>,
+                InnerRhs: Copy,
+            {
+                #[inline]
+                fn [<opt_ $op _assign>](&mut self, rhs: &Option<InnerRhs>) {
+                    if let Some(inner_rhs) = rhs.as_ref() {
+                        self.[<opt_ $op _assign>](*inner_rhs)
+                    }
+                }
+            }
+
+            impl<T, Rhs> [<Option $trait Assign>]<Rhs> for Option<T>
+            where
+                T: OptionOperations + [<Option $trait Assign>]<Rhs>,
+            {
+                #[inline]
+                fn [<opt_ $op _assign>](&mut self, rhs: Rhs) {
+                    if let Some(inner_self) = self {
+                        inner_self.[<opt_ $op _assign>](rhs)
+                    }
+                }
+            }
+
+            impl<T, InnerRhs> [<Option $trait Assign>]<Option<InnerRhs>, InnerRhs> for Option<T>
+            where
+                T: OptionOperations + [<Option $trait Assign>]<InnerRhs>,
+            {
+                #[inline]
+                fn [<opt_ $op _assign>](&mut self, rhs: Option<InnerRhs>) {
+                    if let Some((inner_self, inner_rhs)) = self.as_mut().zip(rhs) {
+                        inner_self.[<opt_ $op _assign>](inner_rhs)
+                    }
+                }
+            }
+
+            impl<T, InnerRhs> [<Option $trait Assign>]<&Option<InnerRhs>, InnerRhs> for Option<T>
+            where
+                T: OptionOperations + [<Option $trait Assign>]<InnerRhs>,
+                InnerRhs: Copy,
+            {
+                #[inline]
+                fn [<opt_ $op _assign>](&mut self, rhs: &Option<InnerRhs>) {
+                    if let Some((inner_self, inner_rhs)) = self.as_mut().zip(rhs.as_ref()) {
+                        inner_self.[<opt_ $op _assign>](*inner_rhs)
+                    }
+                }
+            }
+        }
+    };
+
+    ($trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
+        option_op_assign_custom!($trait<Self>, $op, $op_name $(, $extra_doc)?);
+    };
+}
@@ -1,5 +1,5 @@
 macro_rules! option_op_checked {
-    ($trait:ident, $op:ident, $op_name:ident $(, $extra_doc:expr)? $(,)?) => {
+    ($trait:ident<$rhs_default:ty>, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
         paste::paste! {
             #[doc = "Trait for values and `Option`s checked " $op_name "."]
             ///
@@ -13,7 +13,7 @@ macro_rules! option_op_checked {
             /// Note that since the `std` library doesn't define any
             #[doc = "`" [<Checked $trait >] "` trait, "]
             /// users must provide the base implementation for the inner type.
-            pub trait [<OptionChecked $trait>]<Rhs = Self, InnerRhs = Rhs> {
+            pub trait [<OptionChecked $trait>]<Rhs = $rhs_default, InnerRhs = Rhs> {
                 #[doc = "The resulting inner type after applying the " $op_name "."]
                 type Output;
 
@@ -24,6 +24,22 @@ macro_rules! option_op_checked {
                 /// - Returns `Err(Error::Overflow)` if an overflow occured.
                 $(#[doc = $extra_doc])?
                 fn [<opt_checked_ $op>](self, rhs: Rhs) -> Result<Option<Self::Output>, Error>;
+
+                #[doc = "Computes the checked " $op_name ", reporting the operation on error."]
+                ///
+                #[doc = "Same as [`opt_checked_" $op "`](Self::opt_checked_" $op "), but the [`Error`]"]
+                /// is wrapped in a [`DetailedError`](crate::DetailedError).
+                #[cfg(feature = "detailed-errors")]
+                fn [<opt_checked_ $op _detailed>](
+                    self,
+                    rhs: Rhs,
+                ) -> Result<Option<Self::Output>, crate::DetailedError>
+                where
+                    Self: Sized,
+                {
+                    self.[<opt_checked_ $op>](rhs)
+                        .map_err(|err| crate::DetailedError::new(err, stringify!($op)))
+                }
             }
 
             impl<T, InnerRhs> [<OptionChecked $trait>]<Option<InnerRhs>, InnerRhs> for T
@@ -32,6 +48,7 @@ macro_rules! option_op_checked {
             {
                 type Output = <T as [<OptionChecked $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_checked_ $op>](self, rhs: Option<InnerRhs>) -> Result<Option<Self::Output>, Error> {
                     if let Some(inner_rhs) = rhs {
                         self.[<opt_checked_ $op>](inner_rhs)
@@ -48,6 +65,7 @@ macro_rules! option_op_checked {
             {
                 type Output = <T as [<OptionChecked $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_checked_ $op>](self, rhs: &Option<InnerRhs>) -> Result<Option<Self::Output>, Error> {
                     if let Some(inner_rhs) = rhs.as_ref() {
                         self.[<opt_checked_ $op>](*inner_rhs)
@@ -63,6 +81,7 @@ macro_rules! option_op_checked {
             {
                 type Output = <T as [<OptionChecked $trait>]<Rhs>>::Output;
 
+                #[inline]
                 fn [<opt_checked_ $op>](self, rhs: Rhs) -> Result<Option<Self::Output>, Error> {
                     if let Some(inner_self) = self {
                         inner_self.[<opt_checked_ $op>](rhs)
@@ -78,6 +97,7 @@ macro_rules! option_op_checked {
             {
                 type Output = <T as [<OptionChecked $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_checked_ $op>](self, rhs: Option<InnerRhs>) -> Result<Option<Self::Output>, Error> {
                     if let (Some(inner_self), Some(inner_rhs)) = (self, rhs) {
                         inner_self.[<opt_checked_ $op>](inner_rhs)
@@ -94,6 +114,7 @@ macro_rules! option_op_checked {
             {
                 type Output = <T as [<OptionChecked $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_checked_ $op>](self, rhs: &Option<InnerRhs>) -> Result<Option<Self::Output>, Error> {
                     if let (Some(inner_self), Some(inner_rhs)) = (self, rhs.as_ref()) {
                         inner_self.[<opt_checked_ $op>](*inner_rhs)
@@ -104,4 +125,8 @@ macro_rules! option_op_checked {
             }
         }
     };
+
+    ($trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
+        option_op_checked!($trait<Self>, $op, $op_name $(, $extra_doc)?);
+    };
 }
@@ -0,0 +1,105 @@
+macro_rules! option_op_checked_assign {
+    ($trait:ident<$rhs_default:ty>, $op:ident, $op_name:tt, $error_doc:expr $(, $extra_doc:expr)? $(,)?) => {
+        paste::paste! {
+            #[doc = "Trait for values and `Option`s checked " $op_name " assignment."]
+            ///
+            /// Implementing this trait leads to the following auto-implementations:
+            ///
+            #[doc = "- `" [<OptionChecked $trait Assign>] "<Option<InnerRhs>>` for `T`."]
+            #[doc = "- `" [<OptionChecked $trait Assign>] "<Rhs>` for `Option<T>`."]
+            #[doc = "- `" [<OptionChecked $trait Assign>] "<Option<InnerRhs>>` for `Option<T>`."]
+            /// - ... and some variants with references.
+            ///
+            /// Note that since the `std` library doesn't define any
+            #[doc = "`Checked" $trait "Assign` trait, "]
+            /// users must provide the base implementation for the inner type.
+            pub trait [<OptionChecked $trait Assign>]<Rhs = $rhs_default, InnerRhs = Rhs> {
+                #[doc = "Performs the checked " $op_name " assignment."]
+                ///
+                /// - Returns `Ok(())` if the result could be computed or if
+                ///   at least one argument is `None`.
+                #[doc = $error_doc]
+                $(#[doc = $extra_doc])?
+                ///
+                /// `self` is unchanged if `rhs` is `None` or if an error is returned.
+                fn [<opt_checked_ $op _assign>](&mut self, rhs: Rhs) -> Result<(), Error>;
+            }
+
+            impl<T, InnerRhs> [<OptionChecked $trait Assign>]<Option<InnerRhs>, InnerRhs> for T
+            where
+                T: OptionOperations + [<OptionChecked $trait Assign>]<InnerRhs>,
+            {
+                #[inline]
+                fn [<opt_checked_ $op _assign>](&mut self, rhs: Option<InnerRhs>) -> Result<(), Error> {
+                    if let Some(inner_rhs) = rhs {
+                        self.[<opt_checked_ $op _assign>](inner_rhs)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+
+            impl<T, InnerRhs> [<OptionChecked $trait Assign>]<&Option<InnerRhs>, InnerRhs> for T
+            where
+                T: OptionOperations + [<OptionChecked $trait Assign>]<InnerRhs>,
+                InnerRhs: Copy,
+            {
+                #[inline]
+                fn [<opt_checked_ $op _assign>](&mut self, rhs: &Option<InnerRhs>) -> Result<(), Error> {
+                    if let Some(inner_rhs) = rhs.as_ref() {
+                        self.[<opt_checked_ $op _assign>](*inner_rhs)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+
+            impl<T, Rhs> [<OptionChecked $trait Assign>]<Rhs> for Option<T>
+            where
+                T: OptionOperations + [<OptionChecked $trait Assign>]<Rhs>,
+            {
+                #[inline]
+                fn [<opt_checked_ $op _assign>](&mut self, rhs: Rhs) -> Result<(), Error> {
+                    if let Some(inner_self) = self {
+                        inner_self.[<opt_checked_ $op _assign>](rhs)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+
+            impl<T, InnerRhs> [<OptionChecked $trait Assign>]<Option<InnerRhs>, InnerRhs> for Option<T>
+            where
+                T: OptionOperations + [<OptionChecked $trait Assign>]<InnerRhs>,
+            {
+                #[inline]
+                fn [<opt_checked_ $op _assign>](&mut self, rhs: Option<InnerRhs>) -> Result<(), Error> {
+                    if let Some((inner_self, inner_rhs)) = self.as_mut().zip(rhs) {
+                        inner_self.[<opt_checked_ $op _assign>](inner_rhs)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+
+            impl<T, InnerRhs> [<OptionChecked $trait Assign>]<&Option<InnerRhs>, InnerRhs> for Option<T>
+            where
+                T: OptionOperations + [<OptionChecked $trait Assign>]<InnerRhs>,
+                InnerRhs: Copy,
+            {
+                #[inline]
+                fn [<opt_checked_ $op _assign>](&mut self, rhs: &Option<InnerRhs>) -> Result<(), Error> {
+                    if let Some((inner_self, inner_rhs)) = self.as_mut().zip(rhs.as_ref()) {
+                        inner_self.[<opt_checked_ $op _assign>](*inner_rhs)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+        }
+    };
+
+    ($trait:ident, $op:ident, $op_name:tt, $error_doc:expr $(, $extra_doc:expr)? $(,)?) => {
+        option_op_checked_assign!($trait<Self>, $op, $op_name, $error_doc $(, $extra_doc)?);
+    };
+}
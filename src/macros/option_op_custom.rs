@@ -0,0 +1,97 @@
+macro_rules! option_op_custom {
+    ($op_trait:ident<$rhs_default:ty>, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
+        paste::paste! {
+            #[doc = "Trait for values and `Option`s " $op_name "."]
+            ///
+            /// Implementing this trait leads to the following auto-implementations:
+            ///
+            #[doc = "- `" [<Option $op_trait>] "<Option<InnerRhs>>` for `T`."]
+            #[doc = "- `" [<Option $op_trait>] "<Rhs>` for `Option<T>`."]
+            #[doc = "- `" [<Option $op_trait>] "<Option<InnerRhs>>` for `Option<T>`."]
+            /// - ... and some variants with references.
+            ///
+            /// Note that since the `std` library doesn't define any
+            #[doc = "`" $op_trait "` trait, "]
+            /// users must provide the base implementation for the inner type.
+            pub trait [<Option $op_trait>]<Rhs = $rhs_default, InnerRhs = Rhs> {
+                #[doc = "The resulting inner type after applying the " $op_name "."]
+                type Output;
+
+                #[doc = "Computes the " $op_name "."]
+                ///
+                /// Returns `None` if at least one argument is `None`.
+                $(#[doc = $extra_doc])?
+                #[must_use]
+                fn [<opt_ $op>](self, rhs: Rhs) -> Option<Self::Output>;
+            }
+
+            impl<T, InnerRhs> [<Option $op_trait>]<Option<InnerRhs>, InnerRhs> for T
+            where
+                T: OptionOperations + [<Option $op_trait>]<InnerRhs>,
+            {
+                type Output = <T as [<Option $op_trait>]<InnerRhs>>::Output;
+
+                #[inline]
+                fn [<opt_ $op>](self, rhs: Option<InnerRhs>) -> Option<Self::Output> {
+                    rhs.and_then(|inner_rhs| self.[<opt_ $op>](inner_rhs))
+                }
+            }
+
+            impl<T, InnerRhs> [<Option $op_trait>]<&Option<InnerRhs>, InnerRhs> for T
+            where
+                T: OptionOperations + [<Option $op_trait>]<InnerRhs>,
+                InnerRhs: Copy,
+            {
+                type Output = <T as [<Option $op_trait>]<InnerRhs>>::Output;
+
+                #[inline]
+                fn [<opt_ $op>](self, rhs: &Option<InnerRhs>) -> Option<Self::Output> {
+                    rhs.as_ref().and_then(|inner_rhs| self.[<opt_ $op>](*inner_rhs))
+                }
+            }
+
+            impl<T, Rhs> [<Option $op_trait>]<Rhs> for Option<T>
+            where
+                T: OptionOperations + [<Option $op_trait>]<Rhs>,
+            {
+                type Output = <T as [<Option $op_trait>]<Rhs>>::Output;
+
+                #[inline]
+                fn [<opt_ $op>](self, rhs: Rhs) -> Option<Self::Output> {
+                    self.and_then(|inner_self| inner_self.[<opt_ $op>](rhs))
+                }
+            }
+
+            impl<T, InnerRhs> [<Option $op_trait>]<Option<InnerRhs>, InnerRhs> for Option<T>
+            where
+                T: OptionOperations + [<Option $op_trait>]<InnerRhs>,
+            {
+                type Output = <T as [<Option $op_trait>]<InnerRhs>>::Output;
+
+                #[inline]
+                fn [<opt_ $op>](self, rhs: Option<InnerRhs>) -> Option<Self::Output> {
+                    self.zip(rhs)
+                        .and_then(|(inner_self, inner_rhs)| inner_self.[<opt_ $op>](inner_rhs))
+                }
+            }
+
+            impl<T, InnerRhs> [<Option $op_trait>]<&Option<InnerRhs>, InnerRhs> for Option<T>
+            where
+                T: OptionOperations + [<Option $op_trait>]<InnerRhs>,
+                InnerRhs: Copy,
+            {
+                type Output = <T as [<Option $op_trait>]<InnerRhs>>::Output;
+
+                #[inline]
+                fn [<opt_ $op>](self, rhs: &Option<InnerRhs>) -> Option<Self::Output> {
+                    self.zip(rhs.as_ref())
+                        .and_then(|(inner_self, inner_rhs)| inner_self.[<opt_ $op>](*inner_rhs))
+                }
+            }
+        }
+    };
+
+    ($op_trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
+        option_op_custom!($op_trait<Self>, $op, $op_name $(, $extra_doc)?);
+    };
+}
@@ -1,5 +1,5 @@
 macro_rules! option_op_overflowing {
-    ($trait:ident, $op:ident, $op_name:ident $(, $extra_doc:expr)? $(,)?) => {
+    ($trait:ident<$rhs_default:ty>, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
         paste::paste! {
             #[doc = "Trait for values and `Option`s overflowing " $op_name "."]
             ///
@@ -13,7 +13,7 @@ macro_rules! option_op_overflowing {
             /// Note that since the `std` library doesn't define any
             #[doc = "`" [<Overflowing $trait >] "` trait, "]
             /// users must provide the base implementation for the inner type.
-            pub trait [<OptionOverflowing $trait>]<Rhs = Self, InnerRhs = Rhs> {
+            pub trait [<OptionOverflowing $trait>]<Rhs = $rhs_default, InnerRhs = Rhs> {
                 #[doc = "The resulting inner type after applying the " $op_name "."]
                 type Output;
 
@@ -32,6 +32,7 @@ macro_rules! option_op_overflowing {
             {
                 type Output = <T as [<OptionOverflowing $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_overflowing_ $op>](self, rhs: Option<InnerRhs>) -> Option<(Self::Output, bool)> {
                     rhs.and_then(|inner_rhs| self.[<opt_overflowing_ $op>](inner_rhs))
                 }
@@ -44,6 +45,7 @@ macro_rules! option_op_overflowing {
             {
                 type Output = <T as [<OptionOverflowing $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_overflowing_ $op>](self, rhs: &Option<InnerRhs>) -> Option<(Self::Output, bool)> {
                     rhs.as_ref()
                         .and_then(|inner_rhs| self.[<opt_overflowing_ $op>](*inner_rhs))
@@ -56,6 +58,7 @@ macro_rules! option_op_overflowing {
             {
                 type Output = <T as [<OptionOverflowing $trait>]<Rhs>>::Output;
 
+                #[inline]
                 fn [<opt_overflowing_ $op>](self, rhs: Rhs) -> Option<(Self::Output, bool)> {
                     self.and_then(|inner_self| inner_self.[<opt_overflowing_ $op>](rhs))
                 }
@@ -67,6 +70,7 @@ macro_rules! option_op_overflowing {
             {
                 type Output = <T as [<OptionOverflowing $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_overflowing_ $op>](self, rhs: Option<InnerRhs>) -> Option<(Self::Output, bool)> {
                     self.zip(rhs)
                         .and_then(|(inner_self, inner_rhs)| inner_self.[<opt_overflowing_ $op>](inner_rhs))
@@ -80,6 +84,7 @@ macro_rules! option_op_overflowing {
             {
                 type Output = <T as [<OptionOverflowing $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_overflowing_ $op>](self, rhs: &Option<InnerRhs>) -> Option<(Self::Output, bool)> {
                     self.zip(rhs.as_ref())
                         .and_then(|(inner_self, inner_rhs)| inner_self.[<opt_overflowing_ $op>](*inner_rhs))
@@ -87,4 +92,8 @@ macro_rules! option_op_overflowing {
             }
         }
     };
+
+    ($trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
+        option_op_overflowing!($trait<Self>, $op, $op_name $(, $extra_doc)?);
+    };
 }
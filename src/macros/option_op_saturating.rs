@@ -1,5 +1,5 @@
 macro_rules! option_op_saturating {
-    ($trait:ident, $op:ident, $op_name:ident $(,)?) => {
+    ($trait:ident<$rhs_default:ty>, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
         paste::paste! {
             #[doc = "Trait for values and `Option`s saturating " $op_name "."]
             ///
@@ -13,7 +13,7 @@ macro_rules! option_op_saturating {
             /// Note that since the `std` library doesn't define any
             #[doc = "`" [<Saturating $trait >] "` trait, "]
             /// users must provide the base implementation for the inner type.
-            pub trait [<OptionSaturating $trait>]<Rhs = Self, InnerRhs = Rhs> {
+            pub trait [<OptionSaturating $trait>]<Rhs = $rhs_default, InnerRhs = Rhs> {
                 #[doc = "The resulting inner type after applying the " $op_name "."]
                 type Output;
 
@@ -21,6 +21,7 @@ macro_rules! option_op_saturating {
                 /// saturating at the numeric bounds instead of overflowing.
                 ///
                 /// Returns `None` if at least one argument is `None`.
+                $(#[doc = $extra_doc])?
                 fn [<opt_saturating_ $op>](self, rhs: Rhs) -> Option<Self::Output>;
             }
 
@@ -30,6 +31,7 @@ macro_rules! option_op_saturating {
             {
                 type Output = <T as [<OptionSaturating $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_saturating_ $op>](self, rhs: Option<InnerRhs>) -> Option<Self::Output> {
                     rhs.and_then(|inner_rhs| self.[<opt_saturating_ $op>](inner_rhs))
                 }
@@ -42,6 +44,7 @@ macro_rules! option_op_saturating {
             {
                 type Output = <T as [<OptionSaturating $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_saturating_ $op>](self, rhs: &Option<InnerRhs>) -> Option<Self::Output> {
                     rhs.as_ref()
                         .and_then(|inner_rhs| self.[<opt_saturating_ $op>](*inner_rhs))
@@ -54,6 +57,7 @@ macro_rules! option_op_saturating {
             {
                 type Output = <T as [<OptionSaturating $trait>]<Rhs>>::Output;
 
+                #[inline]
                 fn [<opt_saturating_ $op>](self, rhs: Rhs) -> Option<Self::Output> {
                     self.and_then(|inner_self| inner_self.[<opt_saturating_ $op>](rhs))
                 }
@@ -65,6 +69,7 @@ macro_rules! option_op_saturating {
             {
                 type Output = <T as [<OptionSaturating $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_saturating_ $op>](self, rhs: Option<InnerRhs>) -> Option<Self::Output> {
                     self.zip(rhs)
                         .and_then(|(inner_self, inner_rhs)| inner_self.[<opt_saturating_ $op>](inner_rhs))
@@ -78,6 +83,7 @@ macro_rules! option_op_saturating {
             {
                 type Output = <T as [<OptionSaturating $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_saturating_ $op>](self, rhs: &Option<InnerRhs>) -> Option<Self::Output> {
                     self.zip(rhs.as_ref())
                         .and_then(|(inner_self, inner_rhs)| inner_self.[<opt_saturating_ $op>](*inner_rhs))
@@ -85,4 +91,8 @@ macro_rules! option_op_saturating {
             }
         }
     };
+
+    ($trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
+        option_op_saturating!($trait<Self>, $op, $op_name $(, $extra_doc)?);
+    };
 }
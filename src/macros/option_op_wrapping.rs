@@ -1,5 +1,5 @@
 macro_rules! option_op_wrapping {
-    ($trait:ident, $op:ident, $op_name:ident $(, $extra_doc:expr)? $(,)?) => {
+    ($trait:ident<$rhs_default:ty>, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
         paste::paste! {
             #[doc = "Trait for values and `Option`s wrapping " $op_name "."]
             ///
@@ -13,7 +13,7 @@ macro_rules! option_op_wrapping {
             /// Note that since the `std` library doesn't define any
             #[doc = "`" [<Wrapping $trait >] "` trait, "]
             /// users must provide the base implementation for the inner type.
-            pub trait [<OptionWrapping $trait>]<Rhs = Self, InnerRhs = Rhs> {
+            pub trait [<OptionWrapping $trait>]<Rhs = $rhs_default, InnerRhs = Rhs> {
                 #[doc = "The resulting inner type after applying the " $op_name "."]
                 type Output;
 
@@ -31,6 +31,7 @@ macro_rules! option_op_wrapping {
             {
                 type Output = <T as [<OptionWrapping $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_wrapping_ $op>](self, rhs: Option<InnerRhs>) -> Option<Self::Output> {
                     rhs.and_then(|inner_rhs| self.[<opt_wrapping_ $op>](inner_rhs))
                 }
@@ -43,6 +44,7 @@ macro_rules! option_op_wrapping {
             {
                 type Output = <T as [<OptionWrapping $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_wrapping_ $op>](self, rhs: &Option<InnerRhs>) -> Option<Self::Output> {
                     rhs.as_ref()
                         .and_then(|inner_rhs| self.[<opt_wrapping_ $op>](*inner_rhs))
@@ -55,6 +57,7 @@ macro_rules! option_op_wrapping {
             {
                 type Output = <T as [<OptionWrapping $trait>]<Rhs>>::Output;
 
+                #[inline]
                 fn [<opt_wrapping_ $op>](self, rhs: Rhs) -> Option<Self::Output> {
                     self.and_then(|inner_self| inner_self.[<opt_wrapping_ $op>](rhs))
                 }
@@ -66,6 +69,7 @@ macro_rules! option_op_wrapping {
             {
                 type Output = <T as [<OptionWrapping $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_wrapping_ $op>](self, rhs: Option<InnerRhs>) -> Option<Self::Output> {
                     self.zip(rhs)
                         .and_then(|(inner_self, inner_rhs)| inner_self.[<opt_wrapping_ $op>](inner_rhs))
@@ -79,6 +83,7 @@ macro_rules! option_op_wrapping {
             {
                 type Output = <T as [<OptionWrapping $trait>]<InnerRhs>>::Output;
 
+                #[inline]
                 fn [<opt_wrapping_ $op>](self, rhs: &Option<InnerRhs>) -> Option<Self::Output> {
                     self.zip(rhs.as_ref())
                         .and_then(|(inner_self, inner_rhs)| inner_self.[<opt_wrapping_ $op>](*inner_rhs))
@@ -86,4 +91,8 @@ macro_rules! option_op_wrapping {
             }
         }
     };
+
+    ($trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
+        option_op_wrapping!($trait<Self>, $op, $op_name $(, $extra_doc)?);
+    };
 }
@@ -0,0 +1,56 @@
+macro_rules! option_unary_op_checked {
+    ($trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
+        paste::paste! {
+            #[doc = "Trait for values and `Option`s checked " $op_name "."]
+            ///
+            /// Implementing this trait leads to the following auto-implementations:
+            ///
+            #[doc = "- `" [<OptionChecked $trait>] "` for `Option<T>`."]
+            ///
+            /// Note that since the `std` library doesn't define any
+            #[doc = "`" [<Checked $trait >] "` trait, "]
+            /// users must provide the base implementation for the inner type.
+            pub trait [<OptionChecked $trait>] {
+                #[doc = "The resulting inner type after applying the " $op_name "."]
+                type Output;
+
+                #[doc = "Computes the checked " $op_name "."]
+                ///
+                /// - Returns `Ok(Some(result))` if `result` could be computed.
+                /// - Returns `Ok(None)` if `self` is `None`.
+                /// - Returns `Err(Error::Overflow)` if an overflow occured.
+                $(#[doc = $extra_doc])?
+                fn [<opt_checked_ $op>](self) -> Result<Option<Self::Output>, Error>;
+
+                #[doc = "Computes the checked " $op_name ", reporting the operation on error."]
+                ///
+                #[doc = "Same as [`opt_checked_" $op "`](Self::opt_checked_" $op "), but the [`Error`]"]
+                /// is wrapped in a [`DetailedError`](crate::DetailedError).
+                #[cfg(feature = "detailed-errors")]
+                fn [<opt_checked_ $op _detailed>](self) -> Result<Option<Self::Output>, crate::DetailedError>
+                where
+                    Self: Sized,
+                {
+                    self.[<opt_checked_ $op>]()
+                        .map_err(|err| crate::DetailedError::new(err, stringify!($op)))
+                }
+            }
+
+            impl<T> [<OptionChecked $trait>] for Option<T>
+            where
+                T: OptionOperations + [<OptionChecked $trait>],
+            {
+                type Output = <T as [<OptionChecked $trait>]>::Output;
+
+                #[inline]
+                fn [<opt_checked_ $op>](self) -> Result<Option<Self::Output>, Error> {
+                    if let Some(inner_self) = self {
+                        inner_self.[<opt_checked_ $op>]()
+                    } else {
+                        Ok(None)
+                    }
+                }
+            }
+        }
+    };
+}
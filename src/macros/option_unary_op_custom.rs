@@ -0,0 +1,38 @@
+macro_rules! option_unary_op_custom {
+    ($op_trait:ident, $op:ident, $op_name:tt $(, $extra_doc:expr)? $(,)?) => {
+        paste::paste! {
+            #[doc = "Trait for values and `Option`s " $op_name "."]
+            ///
+            /// Implementing this trait leads to the following auto-implementations:
+            ///
+            #[doc = "- `" [<Option $op_trait>] "` for `Option<T>`."]
+            ///
+            /// Note that since the `std` library doesn't define any
+            #[doc = "`" $op_trait "` trait, "]
+            /// users must provide the base implementation for the inner type.
+            pub trait [<Option $op_trait>] {
+                #[doc = "The resulting inner type after applying the " $op_name "."]
+                type Output;
+
+                #[doc = "Computes the " $op_name "."]
+                ///
+                /// Returns `None` if `self` is `None`.
+                $(#[doc = $extra_doc])?
+                #[must_use]
+                fn [<opt_ $op>](self) -> Option<Self::Output>;
+            }
+
+            impl<T> [<Option $op_trait>] for Option<T>
+            where
+                T: OptionOperations + [<Option $op_trait>],
+            {
+                type Output = <T as [<Option $op_trait>]>::Output;
+
+                #[inline]
+                fn [<opt_ $op>](self) -> Option<Self::Output> {
+                    self.and_then(|inner_self| inner_self.[<opt_ $op>]())
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,108 @@
+//! Traits for the midpoint [`OptionOperations`].
+
+use crate::OptionOperations;
+
+option_op_custom!(
+    Midpoint,
+    midpoint,
+    "midpoint",
+    "
+The midpoint is computed as if with infinite precision, so it never
+overflows, e.g. the midpoint of `i64::MAX` and `i64::MAX` is `i64::MAX`.
+
+Integer implementations round towards zero for signed integers and
+towards negative infinity, i.e. down, for unsigned integers.",
+);
+
+impl_for_ints!(OptionMidpoint, {
+    type Output = Self;
+    #[inline]
+    fn opt_midpoint(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.midpoint(rhs))
+    }
+});
+
+impl_for_floats!(OptionMidpoint, {
+    type Output = Self;
+    #[inline]
+    fn opt_midpoint(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.midpoint(rhs))
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionMidpoint for MyInt {
+        type Output = MyInt;
+        fn opt_midpoint(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_midpoint(rhs.0).map(MyInt)
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_2: MyInt = MyInt(2);
+    const MY_4: MyInt = MyInt(4);
+    const MY_MAX: MyInt = MyInt(i64::MAX);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_4: Option<MyInt> = Some(MY_4);
+    const SOME_MAX: Option<MyInt> = Some(MY_MAX);
+    const SOME_MIN: Option<MyInt> = Some(MY_MIN);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn midpoint_my() {
+        assert_eq!(MY_0.opt_midpoint(MY_4), SOME_2);
+        assert_eq!(MY_0.opt_midpoint(SOME_4), SOME_2);
+        assert_eq!(MY_4.opt_midpoint(&Some(MY_0)), SOME_2);
+        assert_eq!(SOME_4.opt_midpoint(MY_0), SOME_2);
+        assert_eq!(SOME_2.opt_midpoint(SOME_2), SOME_2);
+        assert_eq!(SOME_4.opt_midpoint(&Some(MY_0)), SOME_2);
+
+        assert_eq!(MY_0.opt_midpoint(NONE), None);
+        assert_eq!(NONE.opt_midpoint(MY_0), None);
+        assert_eq!(NONE.opt_midpoint(SOME_2), None);
+    }
+
+    #[test]
+    fn midpoint_no_overflow() {
+        assert_eq!(SOME_MAX.opt_midpoint(SOME_MAX), SOME_MAX);
+        assert_eq!(SOME_MIN.opt_midpoint(SOME_MIN), SOME_MIN);
+        assert_eq!(
+            SOME_MAX.opt_midpoint(MyInt(i64::MAX - 2)),
+            Some(MyInt(i64::MAX - 1))
+        );
+        assert_eq!(
+            SOME_MIN.opt_midpoint(MyInt(i64::MIN + 2)),
+            Some(MyInt(i64::MIN + 1))
+        );
+
+        // `i64::MIN + i64::MAX` is `-1`, which is rounded towards zero.
+        assert_eq!(SOME_MIN.opt_midpoint(SOME_MAX), Some(MY_0));
+        assert_eq!(SOME_MAX.opt_midpoint(SOME_MIN), Some(MY_0));
+        assert_eq!(Some(-3i64).opt_midpoint(Some(0i64)), Some(-1));
+        assert_eq!(Some(3i64).opt_midpoint(Some(0i64)), Some(1));
+
+        // Unsigned integers are rounded down.
+        assert_eq!(
+            Some(u64::MAX).opt_midpoint(Some(u64::MAX - 1)),
+            Some(u64::MAX - 1)
+        );
+        assert_eq!(Some(0u8).opt_midpoint(Some(255u8)), Some(127));
+    }
+
+    #[test]
+    fn midpoint_float() {
+        assert_eq!(Some(1.0f64).opt_midpoint(Some(2.0f64)), Some(1.5));
+        assert_eq!(Some(f64::MAX).opt_midpoint(f64::MAX), Some(f64::MAX));
+        assert_eq!(Some(-1.0f32).opt_midpoint(Option::<f32>::None), None);
+    }
+}
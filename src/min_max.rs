@@ -4,8 +4,22 @@
 #[allow(unused)]
 use crate::OptionOperations;
 
+use core::cmp::Ordering;
+
 use crate::OptionOrd;
 
+/// The operand selected by [`OptionMinMax::opt_min_by_side`]
+/// or [`OptionMinMax::opt_max_by_side`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Side {
+    /// The left operand, i.e. `self`, was selected.
+    Left,
+    /// The right operand, i.e. `other`, was selected.
+    Right,
+    /// Both operands are equal. The left operand is returned.
+    Equal,
+}
+
 /// Trait for values and `Option`s that can be compared
 /// to get the minimum or maximum.
 ///
@@ -32,27 +46,78 @@ pub trait OptionMinMax<Other, Inner = Other> {
     /// at most one argument is `None`.
     #[must_use]
     fn opt_max(self, other: Other) -> Option<Inner>;
+
+    /// Compares and returns the minimum of two values
+    /// along with the [`Side`] which was selected.
+    ///
+    /// Returns `None` if they can't be compared, e.g. if
+    /// at most one argument is `None`.
+    #[must_use]
+    fn opt_min_by_side(self, other: Other) -> Option<(Inner, Side)>;
+
+    /// Compares and returns the maximum of two values
+    /// along with the [`Side`] which was selected.
+    ///
+    /// Returns `None` if they can't be compared, e.g. if
+    /// at most one argument is `None`.
+    #[must_use]
+    fn opt_max_by_side(self, other: Other) -> Option<(Inner, Side)>;
+}
+
+fn min_by_side<T>(lhs: T, rhs: T) -> Option<(T, Side)>
+where
+    T: for<'a> OptionOrd<&'a T, T>,
+{
+    lhs.opt_cmp(&rhs).map(|ordering| match ordering {
+        Ordering::Less => (lhs, Side::Left),
+        Ordering::Equal => (lhs, Side::Equal),
+        Ordering::Greater => (rhs, Side::Right),
+    })
+}
+
+fn max_by_side<T>(lhs: T, rhs: T) -> Option<(T, Side)>
+where
+    T: for<'a> OptionOrd<&'a T, T>,
+{
+    lhs.opt_cmp(&rhs).map(|ordering| match ordering {
+        Ordering::Greater => (lhs, Side::Left),
+        Ordering::Equal => (lhs, Side::Equal),
+        Ordering::Less => (rhs, Side::Right),
+    })
 }
 
 impl<T> OptionMinMax<T> for T
 where
     T: for<'a> OptionOrd<&'a T, T>,
 {
+    #[inline]
     fn opt_min(self, other: T) -> Option<T> {
         self.opt_lt(&other)
             .map(|is_lt| if is_lt { self } else { other })
     }
 
+    #[inline]
     fn opt_max(self, other: T) -> Option<T> {
         self.opt_gt(&other)
             .map(|is_gt| if is_gt { self } else { other })
     }
+
+    #[inline]
+    fn opt_min_by_side(self, other: T) -> Option<(T, Side)> {
+        min_by_side(self, other)
+    }
+
+    #[inline]
+    fn opt_max_by_side(self, other: T) -> Option<(T, Side)> {
+        max_by_side(self, other)
+    }
 }
 
 impl<T> OptionMinMax<Option<T>, T> for T
 where
     T: for<'a> OptionOrd<&'a T, T>,
 {
+    #[inline]
     fn opt_min(self, other: Option<T>) -> Option<T> {
         other.and_then(|inner_other| {
             self.opt_lt(&inner_other)
@@ -60,18 +125,30 @@ where
         })
     }
 
+    #[inline]
     fn opt_max(self, other: Option<T>) -> Option<T> {
         other.and_then(|inner_other| {
             self.opt_gt(&inner_other)
                 .map(|is_gt| if is_gt { self } else { inner_other })
         })
     }
+
+    #[inline]
+    fn opt_min_by_side(self, other: Option<T>) -> Option<(T, Side)> {
+        other.and_then(|inner_other| min_by_side(self, inner_other))
+    }
+
+    #[inline]
+    fn opt_max_by_side(self, other: Option<T>) -> Option<(T, Side)> {
+        other.and_then(|inner_other| max_by_side(self, inner_other))
+    }
 }
 
 impl<T> OptionMinMax<T> for Option<T>
 where
     T: for<'a> OptionOrd<&'a T, T>,
 {
+    #[inline]
     fn opt_min(self, other: T) -> Option<T> {
         self.and_then(|inner_self| {
             inner_self
@@ -80,6 +157,7 @@ where
         })
     }
 
+    #[inline]
     fn opt_max(self, other: T) -> Option<T> {
         self.and_then(|inner_self| {
             inner_self
@@ -87,12 +165,23 @@ where
                 .map(|is_gt| if is_gt { inner_self } else { other })
         })
     }
+
+    #[inline]
+    fn opt_min_by_side(self, other: T) -> Option<(T, Side)> {
+        self.and_then(|inner_self| min_by_side(inner_self, other))
+    }
+
+    #[inline]
+    fn opt_max_by_side(self, other: T) -> Option<(T, Side)> {
+        self.and_then(|inner_self| max_by_side(inner_self, other))
+    }
 }
 
 impl<T> OptionMinMax<Option<T>, T> for Option<T>
 where
     T: for<'a> OptionOrd<&'a T, T>,
 {
+    #[inline]
     fn opt_min(self, other: Option<T>) -> Option<T> {
         self.zip(other).and_then(|(inner_self, inner_other)| {
             inner_self
@@ -101,6 +190,7 @@ where
         })
     }
 
+    #[inline]
     fn opt_max(self, other: Option<T>) -> Option<T> {
         self.zip(other).and_then(|(inner_self, inner_other)| {
             inner_self
@@ -108,11 +198,129 @@ where
                 .map(|is_gt| if is_gt { inner_self } else { inner_other })
         })
     }
+
+    #[inline]
+    fn opt_min_by_side(self, other: Option<T>) -> Option<(T, Side)> {
+        self.zip(other)
+            .and_then(|(inner_self, inner_other)| min_by_side(inner_self, inner_other))
+    }
+
+    #[inline]
+    fn opt_max_by_side(self, other: Option<T>) -> Option<(T, Side)> {
+        self.zip(other)
+            .and_then(|(inner_self, inner_other)| max_by_side(inner_self, inner_other))
+    }
+}
+
+/// Returns the minimum of any number of `Option`s.
+///
+/// By default, `None`s are ignored and the minimum of the `Some(_)`s
+/// is returned. The result is `None` if all the arguments are `None`
+/// or if there is no argument:
+///
+/// ```
+/// # use option_operations::opt_min;
+/// assert_eq!(opt_min!(Some(3), None, Some(1), Some(2)), Some(1));
+/// assert_eq!(opt_min!(None, Some(3)), Some(3));
+/// assert_eq!(opt_min!(Option::<u64>::None, None), None);
+/// ```
+///
+/// In `strict` mode, the result is `None` if any argument is `None`,
+/// which is consistent with [`OptionMinMax::opt_min`]:
+///
+/// ```
+/// # use option_operations::opt_min;
+/// assert_eq!(opt_min!(strict: Some(3), Some(1), Some(2)), Some(1));
+/// assert_eq!(opt_min!(strict: Some(3), None, Some(2)), None);
+/// ```
+///
+/// In both modes, the result is `None` if two `Some(_)`s can't be compared,
+/// e.g. a `NaN`.
+#[macro_export]
+macro_rules! opt_min {
+    (strict: $($arg:expr),* $(,)?) => {
+        $crate::min_max::__opt_reduce(
+            [$($arg),*],
+            true,
+            |lhs, rhs| $crate::OptionMinMax::opt_min(lhs, rhs),
+        )
+    };
+    ($($arg:expr),* $(,)?) => {
+        $crate::min_max::__opt_reduce(
+            [$($arg),*],
+            false,
+            |lhs, rhs| $crate::OptionMinMax::opt_min(lhs, rhs),
+        )
+    };
+}
+
+/// Returns the maximum of any number of `Option`s.
+///
+/// By default, `None`s are ignored and the maximum of the `Some(_)`s
+/// is returned. The result is `None` if all the arguments are `None`
+/// or if there is no argument:
+///
+/// ```
+/// # use option_operations::opt_max;
+/// assert_eq!(opt_max!(Some(3), None, Some(1), Some(2)), Some(3));
+/// assert_eq!(opt_max!(None, Some(1)), Some(1));
+/// assert_eq!(opt_max!(Option::<u64>::None, None), None);
+/// ```
+///
+/// In `strict` mode, the result is `None` if any argument is `None`,
+/// which is consistent with [`OptionMinMax::opt_max`]:
+///
+/// ```
+/// # use option_operations::opt_max;
+/// assert_eq!(opt_max!(strict: Some(3), Some(1), Some(2)), Some(3));
+/// assert_eq!(opt_max!(strict: Some(3), None, Some(2)), None);
+/// ```
+///
+/// In both modes, the result is `None` if two `Some(_)`s can't be compared,
+/// e.g. a `NaN`.
+#[macro_export]
+macro_rules! opt_max {
+    (strict: $($arg:expr),* $(,)?) => {
+        $crate::min_max::__opt_reduce(
+            [$($arg),*],
+            true,
+            |lhs, rhs| $crate::OptionMinMax::opt_max(lhs, rhs),
+        )
+    };
+    ($($arg:expr),* $(,)?) => {
+        $crate::min_max::__opt_reduce(
+            [$($arg),*],
+            false,
+            |lhs, rhs| $crate::OptionMinMax::opt_max(lhs, rhs),
+        )
+    };
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn __opt_reduce<T, F, const N: usize>(values: [Option<T>; N], strict: bool, f: F) -> Option<T>
+where
+    F: Fn(T, T) -> Option<T>,
+{
+    let mut res = None;
+    for value in IntoIterator::into_iter(values) {
+        let value = match value {
+            Some(value) => value,
+            None if strict => return None,
+            None => continue,
+        };
+        res = Some(match res {
+            Some(res) => f(res, value)?,
+            None => value,
+        });
+    }
+
+    res
 }
 
 #[cfg(test)]
 mod test {
-    use super::OptionMinMax;
+    use super::{OptionMinMax, Side};
     use crate::OptionOperations;
 
     #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
@@ -167,4 +375,78 @@ mod test {
 
         assert_eq!(SOME_1.opt_max(NONE).or(SOME_1), SOME_1);
     }
+
+    #[test]
+    fn min_by_side() {
+        assert_eq!(SOME_1.opt_min_by_side(SOME_2), Some((MY_1, Side::Left)));
+        assert_eq!(SOME_2.opt_min_by_side(SOME_1), Some((MY_1, Side::Right)));
+        assert_eq!(SOME_1.opt_min_by_side(SOME_1), Some((MY_1, Side::Equal)));
+        assert_eq!(SOME_1.opt_min_by_side(NONE), None);
+
+        assert_eq!(SOME_2.opt_min_by_side(MY_1), Some((MY_1, Side::Right)));
+        assert_eq!(MY_1.opt_min_by_side(MY_2), Some((MY_1, Side::Left)));
+        assert_eq!(MY_2.opt_min_by_side(SOME_2), Some((MY_2, Side::Equal)));
+
+        assert_eq!(MY_1.opt_min_by_side(NONE), None);
+        assert_eq!(NONE.opt_min_by_side(MY_1), None);
+
+        assert_eq!(1.0f64.opt_min_by_side(f64::NAN), None);
+        assert_eq!(Some(f64::NAN).opt_min_by_side(Some(1.0)), None);
+        assert_eq!(
+            Some(1.0f64).opt_min_by_side(Some(1.0)),
+            Some((1.0, Side::Equal))
+        );
+    }
+
+    #[test]
+    fn max_by_side() {
+        assert_eq!(SOME_1.opt_max_by_side(SOME_2), Some((MY_2, Side::Right)));
+        assert_eq!(SOME_2.opt_max_by_side(SOME_1), Some((MY_2, Side::Left)));
+        assert_eq!(SOME_2.opt_max_by_side(SOME_2), Some((MY_2, Side::Equal)));
+        assert_eq!(SOME_1.opt_max_by_side(NONE), None);
+
+        assert_eq!(SOME_1.opt_max_by_side(MY_2), Some((MY_2, Side::Right)));
+        assert_eq!(MY_2.opt_max_by_side(MY_1), Some((MY_2, Side::Left)));
+        assert_eq!(MY_1.opt_max_by_side(SOME_1), Some((MY_1, Side::Equal)));
+
+        assert_eq!(MY_1.opt_max_by_side(NONE), None);
+        assert_eq!(NONE.opt_max_by_side(MY_1), None);
+
+        assert_eq!(f64::NAN.opt_max_by_side(1.0), None);
+        assert_eq!(Some(1.0f64).opt_max_by_side(Some(f64::NAN)), None);
+        assert_eq!(
+            Some(2.0f64).opt_max_by_side(Some(1.0)),
+            Some((2.0, Side::Left))
+        );
+    }
+
+    #[test]
+    fn min_max_macros() {
+        assert_eq!(opt_min!(SOME_2, NONE, SOME_1), SOME_1);
+        assert_eq!(opt_max!(SOME_2, NONE, SOME_1), SOME_2);
+        assert_eq!(opt_min!(NONE, NONE, SOME_2,), SOME_2);
+        assert_eq!(opt_max!(SOME_1), SOME_1);
+        assert_eq!(opt_min!(NONE, NONE), NONE);
+        assert_eq!(opt_max!(NONE), NONE);
+        assert_eq!(opt_min!(), NONE);
+
+        assert_eq!(opt_min!(strict: SOME_2, SOME_1, SOME_2), SOME_1);
+        assert_eq!(opt_max!(strict: SOME_1, SOME_2, SOME_1,), SOME_2);
+        assert_eq!(opt_min!(strict: SOME_2, NONE, SOME_1), NONE);
+        assert_eq!(opt_max!(strict: NONE, SOME_2), NONE);
+        assert_eq!(opt_max!(strict: SOME_1), SOME_1);
+        assert_eq!(opt_max!(strict:), NONE);
+
+        let candidates = (Some(2.5f64), None, Some(-1.0));
+        assert_eq!(
+            opt_min!(candidates.0, candidates.1, candidates.2),
+            Some(-1.0)
+        );
+        assert_eq!(
+            opt_max!(candidates.0, candidates.1, candidates.2),
+            Some(2.5)
+        );
+        assert_eq!(opt_max!(Some(1.0), Some(f64::NAN), Some(2.0)), None);
+        assert_eq!(opt_min!(strict: Some(f64::NAN), Some(2.0)), None);
+    }
 }
@@ -8,6 +8,7 @@ common_option_op!(Mul, mul, multiplication);
 
 impl_for_ints!(OptionOverflowingMul, {
     type Output = Self;
+    #[inline]
     fn opt_overflowing_mul(self, rhs: Self) -> Option<(Self::Output, bool)> {
         Some(self.overflowing_mul(rhs))
     }
@@ -15,38 +16,138 @@ impl_for_ints!(OptionOverflowingMul, {
 
 impl_for_ints!(OptionWrappingMul, {
     type Output = Self;
+    #[inline]
     fn opt_wrapping_mul(self, rhs: Self) -> Option<Self::Output> {
         Some(self.wrapping_mul(rhs))
     }
 });
 
-option_op_checked!(Mul, mul, multiplication);
+option_op_checked!(
+    Mul,
+    mul,
+    multiplication,
+    "- Returns `Err(Error::NotFinite)` if the result of a floating point operation
+is infinite or `NaN` while the operands are finite.
+- Returns `Err(Error::Underflow)` if a `Duration` is multiplied by a negative
+`f64`, and `Err(Error::Domain)` if the `f64` is infinite or `NaN`.",
+);
 
 impl_for_ints!(OptionCheckedMul, {
     type Output = Self;
+    #[inline]
     fn opt_checked_mul(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
         self.checked_mul(rhs).ok_or(Error::Overflow).map(Some)
     }
 });
 
+impl_for_floats!(OptionCheckedMul, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_mul(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        let res = self.mul(rhs);
+        if !res.is_finite() && self.is_finite() && rhs.is_finite() {
+            return Err(Error::NotFinite);
+        }
+        Ok(Some(res))
+    }
+});
+
+impl_for_non_zero_ints!(OptionCheckedMul, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_mul(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        self.checked_mul(rhs).ok_or(Error::Overflow).map(Some)
+    }
+});
+
+#[cfg(feature = "duration")]
 impl OptionCheckedMul<u32> for core::time::Duration {
     type Output = Self;
+    #[inline]
     fn opt_checked_mul(self, rhs: u32) -> Result<Option<Self::Output>, Error> {
         self.checked_mul(rhs).ok_or(Error::Overflow).map(Some)
     }
 }
 
+#[cfg(feature = "duration")]
+impl OptionCheckedMul<f64> for core::time::Duration {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_mul(self, rhs: f64) -> Result<Option<Self::Output>, Error> {
+        if !rhs.is_finite() {
+            return Err(Error::Domain);
+        }
+        let secs = self.as_secs_f64() * rhs;
+        if secs < 0.0 {
+            return Err(Error::Underflow);
+        }
+        core::time::Duration::try_from_secs_f64(secs)
+            .map_err(|_| Error::Overflow)
+            .map(Some)
+    }
+}
+
+option_op_checked_assign!(
+    Mul,
+    mul,
+    multiplication,
+    "- Returns `Err(Error::Overflow)` if an overflow occured.",
+    "- Returns `Err(Error::NotFinite)` if the result of a floating point operation
+is infinite or `NaN` while the operands are finite.",
+);
+
+impl_for_ints!(OptionCheckedMulAssign, {
+    #[inline]
+    fn opt_checked_mul_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        *self = self.checked_mul(rhs).ok_or(Error::Overflow)?;
+        Ok(())
+    }
+});
+
+impl_for_floats!(OptionCheckedMulAssign, {
+    #[inline]
+    fn opt_checked_mul_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        if let Some(res) = (*self).opt_checked_mul(rhs)? {
+            *self = res;
+        }
+        Ok(())
+    }
+});
+
+#[cfg(feature = "duration")]
+impl OptionCheckedMulAssign<u32> for core::time::Duration {
+    #[inline]
+    fn opt_checked_mul_assign(&mut self, rhs: u32) -> Result<(), Error> {
+        *self = self.checked_mul(rhs).ok_or(Error::Overflow)?;
+        Ok(())
+    }
+}
+
 option_op_saturating!(Mul, mul, multiplication);
 
 impl_for_ints!(OptionSaturatingMul, {
     type Output = Self;
+    #[inline]
     fn opt_saturating_mul(self, rhs: Self) -> Option<Self::Output> {
         Some(self.saturating_mul(rhs))
     }
 });
 
+impl<T> OptionSaturatingMul for core::num::Saturating<T>
+where
+    Self: OptionOperations + Mul<Output = Self>,
+{
+    type Output = Self;
+    #[inline]
+    fn opt_saturating_mul(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.mul(rhs))
+    }
+}
+
+#[cfg(feature = "duration")]
 impl OptionSaturatingMul<u32> for core::time::Duration {
     type Output = Self;
+    #[inline]
     fn opt_saturating_mul(self, rhs: u32) -> Option<Self::Output> {
         Some(self.saturating_mul(rhs))
     }
@@ -216,22 +317,22 @@ mod test {
         assert_eq!(none, NONE);
     }
 
-    #[test]
-    fn checked_mul() {
-        impl OptionCheckedMul for MyInt {
-            type Output = MyInt;
-            fn opt_checked_mul(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
-                self.0.opt_checked_mul(rhs.0).map(|ok| ok.map(MyInt))
-            }
+    impl OptionCheckedMul for MyInt {
+        type Output = MyInt;
+        fn opt_checked_mul(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_mul(rhs.0).map(|ok| ok.map(MyInt))
         }
+    }
 
-        impl OptionCheckedMul<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_checked_mul(self, rhs: u64) -> Result<Option<Self::Output>, Error> {
-                self.0.opt_checked_mul(rhs).map(|ok| ok.map(MyInt))
-            }
+    impl OptionCheckedMul<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_mul(self, rhs: u64) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_mul(rhs).map(|ok| ok.map(MyInt))
         }
+    }
 
+    #[test]
+    fn checked_mul() {
         assert_eq!(MY_1.opt_checked_mul(MY_2), Ok(SOME_2));
         assert_eq!(MY_2.opt_checked_mul(SOME_5), Ok(SOME_10));
         assert_eq!(MY_1.opt_checked_mul(&SOME_0), Ok(SOME_0));
@@ -252,21 +353,78 @@ mod test {
     }
 
     #[test]
-    fn saturating_mul() {
-        impl OptionSaturatingMul for MyInt {
-            type Output = MyInt;
-            fn opt_saturating_mul(self, rhs: MyInt) -> Option<Self::Output> {
-                self.0.opt_saturating_mul(rhs.0).map(MyInt)
-            }
+    fn checked_mul_pointer_sized() {
+        // Derive the boundaries from the target's pointer width
+        // so the test holds on 16, 32 and 64-bit targets.
+        let half_bits = 1usize << (usize::BITS / 2);
+        assert_eq!(
+            Some(half_bits - 1).opt_checked_mul(half_bits - 1),
+            Ok(Some((half_bits - 1) * (half_bits - 1)))
+        );
+        assert_eq!(
+            Some(half_bits).opt_checked_mul(half_bits),
+            Err(Error::Overflow)
+        );
+        assert_eq!(usize::MAX.opt_checked_mul(1usize), Ok(Some(usize::MAX)));
+        assert_eq!(
+            usize::MAX.opt_checked_mul(Some(2usize)),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Some(usize::MAX).opt_checked_mul(Option::<usize>::None),
+            Ok(None)
+        );
+
+        assert_eq!(
+            Some(isize::MAX).opt_checked_mul(-1isize),
+            Ok(Some(-isize::MAX))
+        );
+        assert_eq!(isize::MIN.opt_checked_mul(-1isize), Err(Error::Overflow));
+        assert_eq!(
+            Some(isize::MIN / 2).opt_checked_mul(Some(2isize)),
+            Ok(Some(isize::MIN))
+        );
+        assert_eq!(
+            Some(isize::MAX / 2 + 1).opt_checked_mul(Some(2isize)),
+            Err(Error::Overflow)
+        );
+    }
+
+    #[test]
+    fn checked_mul_float() {
+        assert_eq!(Some(1.5f64).opt_checked_mul(2.0), Ok(Some(3.0)));
+        assert_eq!(1.5f32.opt_checked_mul(Some(-2.0)), Ok(Some(-3.0)));
+        assert_eq!(f64::MAX.opt_checked_mul(2.0), Err(Error::NotFinite));
+        assert_eq!(
+            Some(f32::MAX).opt_checked_mul(&Some(f32::MIN)),
+            Err(Error::NotFinite)
+        );
+        assert_eq!(
+            Some(f64::INFINITY).opt_checked_mul(2.0),
+            Ok(Some(f64::INFINITY))
+        );
+        assert_eq!(
+            Some(f64::MAX).opt_checked_mul(Option::<f64>::None),
+            Ok(None)
+        );
+    }
+
+    impl OptionSaturatingMul for MyInt {
+        type Output = MyInt;
+        fn opt_saturating_mul(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_saturating_mul(rhs.0).map(MyInt)
         }
+    }
 
-        impl OptionSaturatingMul<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_saturating_mul(self, rhs: u64) -> Option<Self::Output> {
-                self.0.opt_saturating_mul(rhs).map(MyInt)
-            }
+    impl OptionSaturatingMul<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_saturating_mul(self, rhs: u64) -> Option<Self::Output> {
+            self.0.opt_saturating_mul(rhs).map(MyInt)
         }
+    }
 
+    #[test]
+    fn saturating_mul() {
         assert_eq!(MY_1.opt_saturating_mul(MY_2), SOME_2);
         assert_eq!(MY_0.opt_saturating_mul(MY_2), SOME_0);
         assert_eq!(MY_MAX.opt_saturating_mul(MY_2), SOME_MAX);
@@ -280,26 +438,46 @@ mod test {
         assert_eq!(NONE.opt_saturating_mul(SOME_MAX), NONE);
     }
 
+    #[cfg(feature = "duration")]
     #[test]
-    fn overflowing_mul() {
-        impl OptionOverflowingMul for MyInt {
-            type Output = MyInt;
-            fn opt_overflowing_mul(self, rhs: MyInt) -> Option<(Self::Output, bool)> {
-                self.0
-                    .opt_overflowing_mul(rhs.0)
-                    .map(|(val, flag)| (MyInt(val), flag))
-            }
+    fn saturating_mul_duration() {
+        use core::time::Duration;
+
+        let secs_2 = Duration::from_secs(2);
+        assert_eq!(secs_2.opt_saturating_mul(3), Some(Duration::from_secs(6)));
+        assert_eq!(Duration::MAX.opt_saturating_mul(2), Some(Duration::MAX));
+        assert_eq!(
+            Some(Duration::from_secs(u64::MAX / 2)).opt_saturating_mul(Some(3u32)),
+            Some(Duration::MAX)
+        );
+        assert_eq!(
+            Duration::MAX.opt_saturating_mul(&Some(0u32)),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(Some(secs_2).opt_saturating_mul(Option::<u32>::None), None);
+        assert_eq!(Option::<Duration>::None.opt_saturating_mul(2), None);
+    }
+
+    impl OptionOverflowingMul for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_mul(self, rhs: MyInt) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_mul(rhs.0)
+                .map(|(val, flag)| (MyInt(val), flag))
         }
+    }
 
-        impl OptionOverflowingMul<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_overflowing_mul(self, rhs: u64) -> Option<(Self::Output, bool)> {
-                self.0
-                    .opt_overflowing_mul(rhs)
-                    .map(|(val, flag)| (MyInt(val), flag))
-            }
+    impl OptionOverflowingMul<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_mul(self, rhs: u64) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_mul(rhs)
+                .map(|(val, flag)| (MyInt(val), flag))
         }
+    }
 
+    #[test]
+    fn overflowing_mul() {
         assert_eq!(MY_1.opt_overflowing_mul(MY_2), Some((MY_2, false)));
         assert_eq!(MY_1.opt_overflowing_mul(MY_0), Some((MY_0, false)));
         assert_eq!(
@@ -334,22 +512,22 @@ mod test {
         assert_eq!(NONE.opt_overflowing_mul(SOME_MAX), None);
     }
 
-    #[test]
-    fn wrapping_mul() {
-        impl OptionWrappingMul for MyInt {
-            type Output = MyInt;
-            fn opt_wrapping_mul(self, rhs: MyInt) -> Option<Self::Output> {
-                self.0.opt_wrapping_mul(rhs.0).map(MyInt)
-            }
+    impl OptionWrappingMul for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_mul(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_wrapping_mul(rhs.0).map(MyInt)
         }
+    }
 
-        impl OptionWrappingMul<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_wrapping_mul(self, rhs: u64) -> Option<Self::Output> {
-                self.0.opt_wrapping_mul(rhs).map(MyInt)
-            }
+    impl OptionWrappingMul<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_mul(self, rhs: u64) -> Option<Self::Output> {
+            self.0.opt_wrapping_mul(rhs).map(MyInt)
         }
+    }
 
+    #[test]
+    fn wrapping_mul() {
         assert_eq!(MY_1.opt_wrapping_mul(MY_2), SOME_2);
         assert_eq!(MY_1.opt_wrapping_mul(MY_0), SOME_0);
         assert_eq!(MY_MAX.opt_wrapping_mul(MY_2), SOME_MAX_MINUS_1);
@@ -362,4 +540,224 @@ mod test {
         assert_eq!(MY_MAX.opt_wrapping_mul(NONE), None);
         assert_eq!(NONE.opt_wrapping_mul(SOME_MAX), None);
     }
+
+    #[test]
+    fn mul_wrapping_type() {
+        use core::num::Wrapping;
+
+        assert_eq!(
+            Some(Wrapping(16u8)).opt_mul(Wrapping(16u8)),
+            Some(Wrapping(0))
+        );
+        assert_eq!(
+            Wrapping(u32::MAX).opt_mul(Some(Wrapping(2))),
+            Some(Wrapping(u32::MAX - 1))
+        );
+        assert_eq!(
+            Some(Wrapping(2u8)).opt_mul(&Option::<Wrapping<u8>>::None),
+            None
+        );
+    }
+
+    #[test]
+    fn mul_saturating_type() {
+        use core::num::Saturating;
+
+        assert_eq!(
+            Some(Saturating(16u8)).opt_mul(Saturating(16u8)),
+            Some(Saturating(255))
+        );
+        assert_eq!(
+            Some(Saturating(15u8)).opt_mul(Saturating(17u8)),
+            Some(Saturating(255))
+        );
+        assert_eq!(
+            Saturating(i32::MIN).opt_mul(Some(Saturating(2))),
+            Some(Saturating(i32::MIN))
+        );
+        assert_eq!(
+            Some(Saturating(2u8)).opt_saturating_mul(&Option::<Saturating<u8>>::None),
+            None
+        );
+        assert_eq!(
+            Saturating(u32::MAX).opt_saturating_mul(Saturating(2)),
+            Some(Saturating(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn checked_mul_non_zero() {
+        use core::num::{NonZeroI64, NonZeroIsize, NonZeroU32, NonZeroUsize};
+
+        let two = NonZeroU32::new(2).unwrap();
+        let max = NonZeroU32::new(u32::MAX).unwrap();
+        assert_eq!(two.opt_checked_mul(two), Ok(NonZeroU32::new(4)));
+        assert_eq!(Some(two).opt_checked_mul(Some(two)), Ok(NonZeroU32::new(4)));
+        assert_eq!(max.opt_checked_mul(two), Err(Error::Overflow));
+        assert_eq!(Some(max).opt_checked_mul(&Some(two)), Err(Error::Overflow));
+        assert_eq!(
+            Some(max).opt_checked_mul(Option::<NonZeroU32>::None),
+            Ok(None)
+        );
+
+        let minus_one = NonZeroI64::new(-1).unwrap();
+        let min = NonZeroI64::new(i64::MIN).unwrap();
+        assert_eq!(minus_one.opt_checked_mul(minus_one), Ok(NonZeroI64::new(1)));
+        assert_eq!(min.opt_checked_mul(Some(minus_one)), Err(Error::Overflow));
+
+        let two = NonZeroUsize::new(2).unwrap();
+        let max = NonZeroUsize::new(usize::MAX).unwrap();
+        assert_eq!(Some(two).opt_checked_mul(two), Ok(NonZeroUsize::new(4)));
+        assert_eq!(max.opt_checked_mul(Some(two)), Err(Error::Overflow));
+
+        let minus_one = NonZeroIsize::new(-1).unwrap();
+        let min = NonZeroIsize::new(isize::MIN).unwrap();
+        assert_eq!(
+            minus_one.opt_checked_mul(&Some(minus_one)),
+            Ok(NonZeroIsize::new(1))
+        );
+        assert_eq!(min.opt_checked_mul(minus_one), Err(Error::Overflow));
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn checked_mul_duration_f64() {
+        use core::time::Duration;
+
+        let millis_100 = Duration::from_millis(100);
+        assert_eq!(
+            millis_100.opt_checked_mul(1.5),
+            Ok(Some(millis_100.mul_f64(1.5)))
+        );
+        assert_eq!(
+            Some(millis_100).opt_checked_mul(Some(1.5)),
+            Ok(Some(Duration::from_millis(150)))
+        );
+        assert_eq!(
+            Some(millis_100).opt_checked_mul(&Some(0.0)),
+            Ok(Some(Duration::ZERO))
+        );
+        assert_eq!(millis_100.opt_checked_mul(f64::NAN), Err(Error::Domain));
+        assert_eq!(
+            millis_100.opt_checked_mul(f64::INFINITY),
+            Err(Error::Domain)
+        );
+        assert_eq!(
+            Duration::ZERO.opt_checked_mul(f64::NEG_INFINITY),
+            Err(Error::Domain)
+        );
+        assert_eq!(millis_100.opt_checked_mul(-1.5), Err(Error::Underflow));
+        assert_eq!(
+            Duration::from_secs(1).opt_checked_mul(-1.0),
+            Err(Error::Underflow)
+        );
+        assert_eq!(
+            Duration::ZERO.opt_checked_mul(-1.0),
+            Ok(Some(Duration::ZERO))
+        );
+        assert_eq!(Duration::MAX.opt_checked_mul(2.0), Err(Error::Overflow));
+        assert_eq!(
+            Duration::MAX.opt_checked_mul(f64::MAX),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Some(millis_100).opt_checked_mul(Option::<f64>::None),
+            Ok(None)
+        );
+        assert_eq!(Option::<Duration>::None.opt_checked_mul(1.5), Ok(None));
+    }
+
+    impl OptionCheckedMulAssign for MyInt {
+        fn opt_checked_mul_assign(&mut self, rhs: MyInt) -> Result<(), Error> {
+            self.0.opt_checked_mul_assign(rhs.0)
+        }
+    }
+
+    impl OptionCheckedMulAssign<u64> for MyInt {
+        fn opt_checked_mul_assign(&mut self, rhs: u64) -> Result<(), Error> {
+            self.0.opt_checked_mul_assign(rhs)
+        }
+    }
+
+    #[test]
+    fn checked_mul_assign() {
+        let mut my = MY_5;
+        assert_eq!(my.opt_checked_mul_assign(MY_2), Ok(()));
+        assert_eq!(my, MY_10);
+
+        let mut my = MY_5;
+        assert_eq!(my.opt_checked_mul_assign(SOME_2), Ok(()));
+        assert_eq!(my, MY_10);
+
+        let mut my = MY_5;
+        assert_eq!(my.opt_checked_mul_assign(&SOME_0), Ok(()));
+        assert_eq!(my, MY_0);
+
+        let mut my = MY_MAX;
+        assert_eq!(my.opt_checked_mul_assign(MY_2), Err(Error::Overflow));
+        assert_eq!(my, MY_MAX);
+
+        let mut my = MY_5;
+        assert_eq!(my.opt_checked_mul_assign(NONE), Ok(()));
+        assert_eq!(my, MY_5);
+
+        let mut some = SOME_HALF_MAX;
+        assert_eq!(some.opt_checked_mul_assign(2), Ok(()));
+        assert_eq!(some, SOME_MAX_MINUS_1);
+
+        let mut some = SOME_MAX;
+        assert_eq!(some.opt_checked_mul_assign(Some(2)), Err(Error::Overflow));
+        assert_eq!(some, SOME_MAX);
+
+        let mut some = SOME_MAX;
+        assert_eq!(some.opt_checked_mul_assign(&SOME_1), Ok(()));
+        assert_eq!(some, SOME_MAX);
+
+        let mut some = SOME_10;
+        assert_eq!(some.opt_checked_mul_assign(NONE), Ok(()));
+        assert_eq!(some, SOME_10);
+
+        let mut none = NONE;
+        assert_eq!(none.opt_checked_mul_assign(SOME_MAX), Ok(()));
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    fn checked_mul_assign_primitive() {
+        let mut some = Some(i32::MIN);
+        assert_eq!(some.opt_checked_mul_assign(-1), Err(Error::Overflow));
+        assert_eq!(some, Some(i32::MIN));
+
+        let mut some = Some(1.5f64);
+        assert_eq!(some.opt_checked_mul_assign(2.0), Ok(()));
+        assert_eq!(some, Some(3.0));
+
+        let mut some = Some(f32::MAX);
+        assert_eq!(some.opt_checked_mul_assign(2.0), Err(Error::NotFinite));
+        assert_eq!(some, Some(f32::MAX));
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn checked_mul_assign_duration() {
+        use core::time::Duration;
+
+        let mut some = Some(Duration::from_secs(2));
+        assert_eq!(some.opt_checked_mul_assign(3), Ok(()));
+        assert_eq!(some, Some(Duration::from_secs(6)));
+
+        let mut duration = Duration::from_millis(5);
+        assert_eq!(duration.opt_checked_mul_assign(Some(2)), Ok(()));
+        assert_eq!(duration, Duration::from_millis(10));
+        assert_eq!(duration.opt_checked_mul_assign(Option::<u32>::None), Ok(()));
+        assert_eq!(duration, Duration::from_millis(10));
+
+        let mut some = Some(Duration::MAX);
+        assert_eq!(some.opt_checked_mul_assign(&Some(2)), Err(Error::Overflow));
+        assert_eq!(some, Some(Duration::MAX));
+
+        let mut none = Option::<Duration>::None;
+        assert_eq!(none.opt_checked_mul_assign(2), Ok(()));
+        assert_eq!(none, None);
+    }
 }
@@ -0,0 +1,299 @@
+//! Traits for the fused multiply-add [`OptionOperations`].
+
+use crate::{Error, OptionOperations};
+
+/// Trait for values and `Option`s fused multiply-add.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionMulAdd<Mul, Add>` for `Option<T>`.
+///
+/// Implementations are expected to accept `Mul` and `Add` as either
+/// `T` or `Option<T>`, which is the case of the provided implementations
+/// for integers and floats.
+///
+/// Note that since the `std` library doesn't define any `MulAdd` trait,
+/// users must provide the base implementation for the inner type.
+pub trait OptionMulAdd<Mul = Self, Add = Mul> {
+    /// The resulting inner type after applying the fused multiply-add.
+    type Output;
+
+    /// Computes `(self * mul) + add`.
+    ///
+    /// Float implementations use `mul_add`, which computes the result
+    /// with only one rounding error. They are only available with the
+    /// `std` feature or, in `no_std` environments, with the `libm` feature.
+    ///
+    /// Returns `None` if at least one argument is `None`.
+    ///
+    /// ```
+    /// # use option_operations::OptionMulAdd;
+    /// assert_eq!(Some(3i32).opt_mul_add(Some(4), Some(5)), Some(17));
+    /// assert_eq!(Some(3i32).opt_mul_add(4, Option::<i32>::None), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Most integer implementations will panic in debug mode on overflow.
+    /// See [`OptionCheckedMulAdd`] for a non-panicking alternative.
+    #[must_use]
+    fn opt_mul_add(self, mul: Mul, add: Add) -> Option<Self::Output>;
+}
+
+impl<T, Mul, Add> OptionMulAdd<Mul, Add> for Option<T>
+where
+    T: OptionOperations + OptionMulAdd<Mul, Add>,
+{
+    type Output = <T as OptionMulAdd<Mul, Add>>::Output;
+
+    #[inline]
+    fn opt_mul_add(self, mul: Mul, add: Add) -> Option<Self::Output> {
+        self.and_then(|inner_self| inner_self.opt_mul_add(mul, add))
+    }
+}
+
+/// Trait for values and `Option`s checked fused multiply-add.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionCheckedMulAdd<Mul, Add>` for `Option<T>`.
+///
+/// Implementations are expected to accept `Mul` and `Add` as either
+/// `T` or `Option<T>`, which is the case of the provided implementations
+/// for integers.
+///
+/// Note that since the `std` library doesn't define any `CheckedMulAdd`
+/// trait, users must provide the base implementation for the inner type.
+pub trait OptionCheckedMulAdd<Mul = Self, Add = Mul> {
+    /// The resulting inner type after applying the fused multiply-add.
+    type Output;
+
+    /// Computes the checked `(self * mul) + add`.
+    ///
+    /// - Returns `Ok(Some(result))` if `result` could be computed.
+    /// - Returns `Ok(None)` if at least one argument is `None`.
+    /// - Returns `Err(Error::Overflow)` if an overflow occured, either
+    ///   in the multiplication or in the addition.
+    fn opt_checked_mul_add(self, mul: Mul, add: Add) -> Result<Option<Self::Output>, Error>;
+}
+
+impl<T, Mul, Add> OptionCheckedMulAdd<Mul, Add> for Option<T>
+where
+    T: OptionOperations + OptionCheckedMulAdd<Mul, Add>,
+{
+    type Output = <T as OptionCheckedMulAdd<Mul, Add>>::Output;
+
+    #[inline]
+    fn opt_checked_mul_add(self, mul: Mul, add: Add) -> Result<Option<Self::Output>, Error> {
+        if let Some(inner_self) = self {
+            inner_self.opt_checked_mul_add(mul, add)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+macro_rules! impl_mul_add_for_ints {
+    ($($typ_:ty),+ $(,)?) => {
+        $(
+            impl<Mul, Add> OptionMulAdd<Mul, Add> for $typ_
+            where
+                Mul: Into<Option<$typ_>>,
+                Add: Into<Option<$typ_>>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn opt_mul_add(self, mul: Mul, add: Add) -> Option<Self::Output> {
+                    Some(self * mul.into()? + add.into()?)
+                }
+            }
+
+            impl<Mul, Add> OptionCheckedMulAdd<Mul, Add> for $typ_
+            where
+                Mul: Into<Option<$typ_>>,
+                Add: Into<Option<$typ_>>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn opt_checked_mul_add(
+                    self,
+                    mul: Mul,
+                    add: Add,
+                ) -> Result<Option<Self::Output>, Error> {
+                    match (mul.into(), add.into()) {
+                        (Some(mul), Some(add)) => self
+                            .checked_mul(mul)
+                            .and_then(|res| res.checked_add(add))
+                            .ok_or(Error::Overflow)
+                            .map(Some),
+                        _ => Ok(None),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_mul_add_for_ints!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_mul_add_for_floats {
+    ($($typ_:ty => $libm_fma:ident),+ $(,)?) => {
+        $(
+            impl<Mul, Add> OptionMulAdd<Mul, Add> for $typ_
+            where
+                Mul: Into<Option<$typ_>>,
+                Add: Into<Option<$typ_>>,
+            {
+                type Output = Self;
+
+                #[inline]
+                fn opt_mul_add(self, mul: Mul, add: Add) -> Option<Self::Output> {
+                    #[cfg(feature = "std")]
+                    let res = self.mul_add(mul.into()?, add.into()?);
+                    #[cfg(not(feature = "std"))]
+                    let res = libm::$libm_fma(self, mul.into()?, add.into()?);
+
+                    Some(res)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_mul_add_for_floats!(f32 => fmaf, f64 => fma);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl<Mul, Add> OptionMulAdd<Mul, Add> for MyInt
+    where
+        Mul: Into<Option<MyInt>>,
+        Add: Into<Option<MyInt>>,
+    {
+        type Output = MyInt;
+        fn opt_mul_add(self, mul: Mul, add: Add) -> Option<Self::Output> {
+            self.0.opt_mul_add(mul.into()?.0, add.into()?.0).map(MyInt)
+        }
+    }
+
+    impl<Mul, Add> OptionCheckedMulAdd<Mul, Add> for MyInt
+    where
+        Mul: Into<Option<MyInt>>,
+        Add: Into<Option<MyInt>>,
+    {
+        type Output = MyInt;
+        fn opt_checked_mul_add(self, mul: Mul, add: Add) -> Result<Option<Self::Output>, Error> {
+            match (mul.into(), add.into()) {
+                (Some(mul), Some(add)) => self
+                    .0
+                    .opt_checked_mul_add(mul.0, add.0)
+                    .map(|ok| ok.map(MyInt)),
+                _ => Ok(None),
+            }
+        }
+    }
+
+    const MY_2: MyInt = MyInt(2);
+    const MY_3: MyInt = MyInt(3);
+    const MY_5: MyInt = MyInt(5);
+    const MY_MAX: MyInt = MyInt(i64::MAX);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const SOME_5: Option<MyInt> = Some(MY_5);
+    const SOME_11: Option<MyInt> = Some(MyInt(11));
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn mul_add_my() {
+        assert_eq!(MY_2.opt_mul_add(MY_3, MY_5), SOME_11);
+        assert_eq!(MY_2.opt_mul_add(SOME_3, MY_5), SOME_11);
+        assert_eq!(MY_2.opt_mul_add(MY_3, SOME_5), SOME_11);
+        assert_eq!(SOME_2.opt_mul_add(MY_3, MY_5), SOME_11);
+        assert_eq!(SOME_2.opt_mul_add(SOME_3, SOME_5), SOME_11);
+
+        assert_eq!(NONE.opt_mul_add(SOME_3, SOME_5), None);
+        assert_eq!(SOME_2.opt_mul_add(NONE, SOME_5), None);
+        assert_eq!(SOME_2.opt_mul_add(SOME_3, NONE), None);
+        assert_eq!(MY_2.opt_mul_add(NONE, NONE), None);
+    }
+
+    #[test]
+    fn mul_add_ints() {
+        assert_eq!(Some(3u8).opt_mul_add(Some(4), Some(5)), Some(17));
+        assert_eq!(Some(-3i32).opt_mul_add(4, Some(5)), Some(-7));
+        assert_eq!(7u64.opt_mul_add(Option::<u64>::None, 1), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn mul_add_floats() {
+        let (lhs, mul, add) = (0.1f64, 10.0f64, -1.0f64);
+        // The fused result differs from the separately rounded operations.
+        assert_ne!(lhs * mul + add, lhs.mul_add(mul, add));
+        assert_eq!(
+            Some(lhs).opt_mul_add(Some(mul), Some(add)),
+            Some(lhs.mul_add(mul, add))
+        );
+        assert_eq!(lhs.opt_mul_add(mul, Some(add)), Some(lhs.mul_add(mul, add)));
+
+        assert_eq!(
+            Some(1.5f32).opt_mul_add(2.0, 0.5),
+            Some(1.5f32.mul_add(2.0, 0.5))
+        );
+        assert_eq!(Some(1.5f32).opt_mul_add(Option::<f32>::None, 0.5), None);
+        assert_eq!(Option::<f64>::None.opt_mul_add(mul, add), None);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn mul_add_floats_libm() {
+        let (lhs, mul, add) = (0.1f64, 10.0f64, -1.0f64);
+        assert_ne!(lhs * mul + add, libm::fma(lhs, mul, add));
+        assert_eq!(
+            Some(lhs).opt_mul_add(Some(mul), Some(add)),
+            Some(libm::fma(lhs, mul, add))
+        );
+
+        assert_eq!(
+            Some(1.5f32).opt_mul_add(2.0, Some(0.5)),
+            Some(libm::fmaf(1.5, 2.0, 0.5))
+        );
+        assert_eq!(Option::<f32>::None.opt_mul_add(2.0, 0.5), None);
+    }
+
+    #[test]
+    fn checked_mul_add() {
+        assert_eq!(MY_2.opt_checked_mul_add(MY_3, MY_5), Ok(SOME_11));
+        assert_eq!(MY_2.opt_checked_mul_add(SOME_3, MY_5), Ok(SOME_11));
+        assert_eq!(SOME_2.opt_checked_mul_add(SOME_3, SOME_5), Ok(SOME_11));
+        assert_eq!(MY_MAX.opt_checked_mul_add(MY_2, MY_5), Err(Error::Overflow));
+        assert_eq!(
+            MY_MAX.opt_checked_mul_add(MyInt(1), MY_5),
+            Err(Error::Overflow)
+        );
+
+        assert_eq!(NONE.opt_checked_mul_add(MY_2, MY_5), Ok(None));
+        assert_eq!(SOME_2.opt_checked_mul_add(NONE, MY_5), Ok(None));
+        assert_eq!(MY_MAX.opt_checked_mul_add(MY_2, NONE), Ok(None));
+
+        assert_eq!(
+            Some(15u8).opt_checked_mul_add(Some(17), Some(0)),
+            Ok(Some(255))
+        );
+        assert_eq!(Some(15u8).opt_checked_mul_add(17, 1), Err(Error::Overflow));
+        assert_eq!(Some(16u8).opt_checked_mul_add(16, 0), Err(Error::Overflow));
+        assert_eq!(Some(-64i8).opt_checked_mul_add(2, 0), Ok(Some(-128)));
+        assert_eq!(Some(-64i8).opt_checked_mul_add(2, -1), Err(Error::Overflow));
+    }
+}
@@ -0,0 +1,440 @@
+//! Traits for the negation [`OptionOperations`].
+
+use core::ops::Neg;
+
+use crate::{Error, OptionOperations};
+
+/// Trait for values and `Option`s negation.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionNeg` for `Option<T>`.
+///
+/// This trait is auto-implemented for [`OptionOperations`] types implementing
+/// `Neg`.
+pub trait OptionNeg {
+    /// The resulting inner type after applying the negation.
+    type Output;
+
+    /// Computes the negation.
+    ///
+    /// Returns `None` if `self` is `None`.
+    ///
+    /// # Panics
+    ///
+    /// Most signed integer implementations will panic in debug mode
+    /// if `self` is the minimum value of its type.
+    #[must_use]
+    fn opt_neg(self) -> Option<Self::Output>;
+}
+
+impl<T> OptionNeg for T
+where
+    T: OptionOperations + Neg,
+{
+    type Output = <T as Neg>::Output;
+
+    #[inline]
+    fn opt_neg(self) -> Option<Self::Output> {
+        Some(self.neg())
+    }
+}
+
+impl<T> OptionNeg for Option<T>
+where
+    T: OptionOperations + OptionNeg,
+{
+    type Output = <T as OptionNeg>::Output;
+
+    #[inline]
+    fn opt_neg(self) -> Option<Self::Output> {
+        self.and_then(|inner_self| inner_self.opt_neg())
+    }
+}
+
+/// Trait for values and `Option`s negation assignment.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionNegAssign` for `Option<T>`.
+///
+/// This trait is auto-implemented for `Copy` [`OptionOperations`] types
+/// implementing `Neg<Output = Self>`.
+pub trait OptionNegAssign {
+    /// Performs the negation assignment.
+    ///
+    /// `self` is unchanged if it is `None`.
+    ///
+    /// # Panics
+    ///
+    /// Most signed integer implementations will panic in debug mode
+    /// if `self` is the minimum value of its type.
+    fn opt_neg_assign(&mut self);
+}
+
+impl<T> OptionNegAssign for T
+where
+    T: OptionOperations + Neg<Output = T> + Copy,
+{
+    #[inline]
+    fn opt_neg_assign(&mut self) {
+        *self = self.neg();
+    }
+}
+
+impl<T> OptionNegAssign for Option<T>
+where
+    T: OptionOperations + OptionNegAssign,
+{
+    #[inline]
+    fn opt_neg_assign(&mut self) {
+        if let Some(inner_self) = self {
+            inner_self.opt_neg_assign();
+        }
+    }
+}
+
+option_unary_op_checked!(
+    Neg,
+    neg,
+    "negation",
+    "- Returns `Err(Error::Overflow)` if `self` is the minimum value of a signed
+integer type.",
+);
+
+impl_for_signed_ints!(OptionCheckedNeg, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_neg(self) -> Result<Option<Self::Output>, Error> {
+        self.checked_neg().ok_or(Error::Overflow).map(Some)
+    }
+});
+
+impl_for_floats!(OptionCheckedNeg, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_neg(self) -> Result<Option<Self::Output>, Error> {
+        Ok(Some(-self))
+    }
+});
+
+/// Trait for values and `Option`s checked negation assignment.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionCheckedNegAssign` for `Option<T>`.
+///
+/// Note that since the `std` library doesn't define any `CheckedNegAssign`
+/// trait, users must provide the base implementation for the inner type.
+pub trait OptionCheckedNegAssign {
+    /// Performs the checked negation assignment.
+    ///
+    /// - Returns `Ok(())` if the negation could be computed or if `self`
+    ///   is `None`, in which case `self` is unchanged.
+    /// - Returns `Err(Error::Overflow)` if an overflow occured, in which case
+    ///   `self` is unchanged.
+    fn opt_checked_neg_assign(&mut self) -> Result<(), Error>;
+}
+
+impl<T> OptionCheckedNegAssign for Option<T>
+where
+    T: OptionOperations + OptionCheckedNegAssign,
+{
+    #[inline]
+    fn opt_checked_neg_assign(&mut self) -> Result<(), Error> {
+        if let Some(inner_self) = self {
+            inner_self.opt_checked_neg_assign()
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl_for_signed_ints!(OptionCheckedNegAssign, {
+    #[inline]
+    fn opt_checked_neg_assign(&mut self) -> Result<(), Error> {
+        *self = self.checked_neg().ok_or(Error::Overflow)?;
+        Ok(())
+    }
+});
+
+/// Trait for values and `Option`s overflowing negation.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionOverflowingNeg` for `Option<T>`.
+///
+/// Note that since the `std` library doesn't define any `OverflowingNeg`
+/// trait, users must provide the base implementation for the inner type.
+pub trait OptionOverflowingNeg {
+    /// The resulting inner type after applying the overflowing negation.
+    type Output;
+
+    /// Computes the negation, returning a tuple of the result
+    /// and a boolean indicating whether an arithmetic overflow occured.
+    ///
+    /// If an overflow occured, i.e. if `self` is the minimum value
+    /// of a signed integer type, then `self` is returned.
+    ///
+    /// Returns `None` if `self` is `None`.
+    #[must_use]
+    fn opt_overflowing_neg(self) -> Option<(Self::Output, bool)>;
+}
+
+impl<T> OptionOverflowingNeg for Option<T>
+where
+    T: OptionOperations + OptionOverflowingNeg,
+{
+    type Output = <T as OptionOverflowingNeg>::Output;
+
+    #[inline]
+    fn opt_overflowing_neg(self) -> Option<(Self::Output, bool)> {
+        self.and_then(|inner_self| inner_self.opt_overflowing_neg())
+    }
+}
+
+impl_for_signed_ints!(OptionOverflowingNeg, {
+    type Output = Self;
+    #[inline]
+    fn opt_overflowing_neg(self) -> Option<(Self::Output, bool)> {
+        Some(self.overflowing_neg())
+    }
+});
+
+option_unary_op_custom!(
+    SaturatingNeg,
+    saturating_neg,
+    "saturating negation",
+    "
+The negation of the minimum value of a signed integer type saturates
+to the maximum value of the type.",
+);
+
+impl_for_signed_ints!(OptionSaturatingNeg, {
+    type Output = Self;
+    #[inline]
+    fn opt_saturating_neg(self) -> Option<Self::Output> {
+        Some(self.saturating_neg())
+    }
+});
+
+option_unary_op_custom!(
+    WrappingNeg,
+    wrapping_neg,
+    "wrapping negation",
+    "
+The negation of the minimum value of a signed integer type wraps around
+to the minimum value. For unsigned integers, the result is the two's
+complement of `self`, i.e. `0 - self` wrapping around the type's boundary.",
+);
+
+impl_for_ints!(OptionWrappingNeg, {
+    type Output = Self;
+    #[inline]
+    fn opt_wrapping_neg(self) -> Option<Self::Output> {
+        Some(self.wrapping_neg())
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+    use core::ops::Neg;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl Neg for MyInt {
+        type Output = MyInt;
+
+        fn neg(self) -> MyInt {
+            MyInt(self.0.neg())
+        }
+    }
+
+    impl OptionCheckedNeg for MyInt {
+        type Output = MyInt;
+        fn opt_checked_neg(self) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_neg().map(|ok| ok.map(MyInt))
+        }
+    }
+
+    impl OptionCheckedNegAssign for MyInt {
+        fn opt_checked_neg_assign(&mut self) -> Result<(), Error> {
+            self.0.opt_checked_neg_assign()
+        }
+    }
+
+    impl OptionOverflowingNeg for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_neg(self) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_neg()
+                .map(|(val, flag)| (MyInt(val), flag))
+        }
+    }
+
+    impl OptionSaturatingNeg for MyInt {
+        type Output = MyInt;
+        fn opt_saturating_neg(self) -> Option<Self::Output> {
+            self.0.opt_saturating_neg().map(MyInt)
+        }
+    }
+
+    impl OptionWrappingNeg for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_neg(self) -> Option<Self::Output> {
+            self.0.opt_wrapping_neg().map(MyInt)
+        }
+    }
+
+    const MY_MINUS_2: MyInt = MyInt(-2);
+    const MY_0: MyInt = MyInt(0);
+    const MY_2: MyInt = MyInt(2);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const MY_MAX: MyInt = MyInt(i64::MAX);
+    const SOME_MINUS_2: Option<MyInt> = Some(MY_MINUS_2);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_MIN: Option<MyInt> = Some(MY_MIN);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn neg() {
+        assert_eq!(MY_2.opt_neg(), SOME_MINUS_2);
+        assert_eq!(MY_MINUS_2.opt_neg(), SOME_2);
+        assert_eq!(MY_0.opt_neg(), SOME_0);
+        assert_eq!(SOME_2.opt_neg(), SOME_MINUS_2);
+        assert_eq!(NONE.opt_neg(), NONE);
+
+        assert_eq!(Some(5i8).opt_neg(), Some(-5));
+        assert_eq!(Some(1.5f64).opt_neg(), Some(-1.5));
+        assert_eq!(Option::<f32>::None.opt_neg(), None);
+    }
+
+    #[test]
+    fn neg_assign() {
+        let mut my = MY_2;
+        my.opt_neg_assign();
+        assert_eq!(my, MY_MINUS_2);
+
+        let mut some = SOME_MINUS_2;
+        some.opt_neg_assign();
+        assert_eq!(some, SOME_2);
+
+        let mut none = NONE;
+        none.opt_neg_assign();
+        assert_eq!(none, NONE);
+
+        let mut some = Some(1.5f64);
+        some.opt_neg_assign();
+        assert_eq!(some, Some(-1.5));
+    }
+
+    #[test]
+    fn checked_neg() {
+        assert_eq!(MY_2.opt_checked_neg(), Ok(SOME_MINUS_2));
+        assert_eq!(MY_MAX.opt_checked_neg(), Ok(Some(MyInt(-i64::MAX))));
+        assert_eq!(MY_MIN.opt_checked_neg(), Err(Error::Overflow));
+        assert_eq!(SOME_MINUS_2.opt_checked_neg(), Ok(SOME_2));
+        assert_eq!(SOME_MIN.opt_checked_neg(), Err(Error::Overflow));
+        assert_eq!(NONE.opt_checked_neg(), Ok(None));
+
+        assert_eq!(Some(i8::MIN).opt_checked_neg(), Err(Error::Overflow));
+        assert_eq!(Some(1.5f64).opt_checked_neg(), Ok(Some(-1.5)));
+        assert_eq!(Option::<f32>::None.opt_checked_neg(), Ok(None));
+    }
+
+    #[test]
+    fn checked_neg_assign() {
+        let mut my = MY_2;
+        assert_eq!(my.opt_checked_neg_assign(), Ok(()));
+        assert_eq!(my, MY_MINUS_2);
+
+        let mut my = MY_MAX;
+        assert_eq!(my.opt_checked_neg_assign(), Ok(()));
+        assert_eq!(my, MyInt(-i64::MAX));
+
+        let mut some = SOME_MINUS_2;
+        assert_eq!(some.opt_checked_neg_assign(), Ok(()));
+        assert_eq!(some, SOME_2);
+
+        let mut my = MY_MIN;
+        assert_eq!(my.opt_checked_neg_assign(), Err(Error::Overflow));
+        assert_eq!(my, MY_MIN);
+
+        let mut some = SOME_MIN;
+        assert_eq!(some.opt_checked_neg_assign(), Err(Error::Overflow));
+        assert_eq!(some, SOME_MIN);
+
+        let mut none = NONE;
+        assert_eq!(none.opt_checked_neg_assign(), Ok(()));
+        assert_eq!(none, NONE);
+
+        let mut some = Some(i8::MIN);
+        assert_eq!(some.opt_checked_neg_assign(), Err(Error::Overflow));
+        assert_eq!(some, Some(i8::MIN));
+    }
+
+    #[test]
+    fn overflowing_neg() {
+        assert_eq!(MY_2.opt_overflowing_neg(), Some((MY_MINUS_2, false)));
+        assert_eq!(MY_MIN.opt_overflowing_neg(), Some((MY_MIN, true)));
+        assert_eq!(SOME_MINUS_2.opt_overflowing_neg(), Some((MY_2, false)));
+        assert_eq!(SOME_MIN.opt_overflowing_neg(), Some((MY_MIN, true)));
+        assert_eq!(NONE.opt_overflowing_neg(), None);
+
+        assert_eq!(Some(i8::MIN).opt_overflowing_neg(), Some((i8::MIN, true)));
+        assert_eq!(Option::<i32>::None.opt_overflowing_neg(), None);
+    }
+
+    #[test]
+    fn saturating_neg() {
+        assert_eq!(MY_2.opt_saturating_neg(), SOME_MINUS_2);
+        assert_eq!(MY_MIN.opt_saturating_neg(), Some(MY_MAX));
+        assert_eq!(SOME_MINUS_2.opt_saturating_neg(), SOME_2);
+        assert_eq!(SOME_MIN.opt_saturating_neg(), Some(MY_MAX));
+        assert_eq!(NONE.opt_saturating_neg(), NONE);
+
+        assert_eq!(Some(i64::MIN).opt_saturating_neg(), Some(i64::MAX));
+        assert_eq!(Some(i8::MAX).opt_saturating_neg(), Some(-i8::MAX));
+        assert_eq!(Option::<i32>::None.opt_saturating_neg(), None);
+    }
+
+    #[test]
+    fn wrapping_neg() {
+        assert_eq!(MY_2.opt_wrapping_neg(), SOME_MINUS_2);
+        assert_eq!(MY_MIN.opt_wrapping_neg(), SOME_MIN);
+        assert_eq!(SOME_MINUS_2.opt_wrapping_neg(), SOME_2);
+        assert_eq!(NONE.opt_wrapping_neg(), NONE);
+
+        assert_eq!(Some(i8::MIN).opt_wrapping_neg(), Some(i8::MIN));
+        assert_eq!(Some(1u8).opt_wrapping_neg(), Some(u8::MAX));
+        assert_eq!(Some(0u32).opt_wrapping_neg(), Some(0));
+        assert_eq!(Option::<u64>::None.opt_wrapping_neg(), None);
+    }
+
+    #[test]
+    fn min_under_each_variant() {
+        let min = Some(i64::MIN);
+
+        assert_eq!(min.opt_checked_neg(), Err(Error::Overflow));
+        assert_eq!(min.opt_overflowing_neg(), Some((i64::MIN, true)));
+        assert_eq!(min.opt_wrapping_neg(), Some(i64::MIN));
+        assert_eq!(min.opt_saturating_neg(), Some(i64::MAX));
+
+        let mut some = min;
+        assert_eq!(some.opt_checked_neg_assign(), Err(Error::Overflow));
+        assert_eq!(some, min);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn neg_min_panics() {
+        let _ = Some(i64::MIN).opt_neg();
+    }
+}
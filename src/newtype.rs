@@ -0,0 +1,429 @@
+//! Macros for downstream integer-like types.
+
+/// Implements [`OptionOperations`](crate::OptionOperations) for newtypes
+/// over a primitive integer and forwards the base implementations
+/// of the checked, overflowing, wrapping and saturating arithmetic
+/// operations to the inner type.
+///
+/// The newtype is expected to be a tuple struct with a single field of
+/// a primitive integer type, e.g. `struct MyInt(i64);`, and to be `Copy`.
+///
+/// The following base implementations are generated, both with the
+/// newtype and with the inner type as `Rhs`:
+///
+/// - `OptionChecked{Add, Sub, Mul, Div, Rem}`.
+/// - `OptionOverflowing{Add, Sub, Mul, Div, Rem}`.
+/// - `OptionWrapping{Add, Sub, Mul, Div, Rem}`.
+/// - `OptionSaturating{Add, Sub, Mul, Div}`.
+///
+/// They are also generated for references to the newtype, so that
+/// `Option<&MyInt>` items, e.g. from iterating over a `&[Option<MyInt>]`,
+/// can be used without calling `.copied()` first.
+///
+/// ```
+/// # use option_operations::{
+/// #     impl_option_operations, Error, OptionCheckedAdd, OptionCheckedDiv, OptionWrappingAdd,
+/// # };
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// struct MyInt(i64);
+///
+/// impl_option_operations!(MyInt(i64));
+///
+/// assert_eq!(Some(MyInt(10)).opt_checked_div(MyInt(2)), Ok(Some(MyInt(5))));
+/// assert_eq!(Some(MyInt(10)).opt_checked_div(Some(0)), Err(Error::DivisionByZero));
+/// assert_eq!(MyInt(i64::MAX).opt_wrapping_add(1), Some(MyInt(i64::MIN)));
+///
+/// let values = [Some(MyInt(1)), None];
+/// assert_eq!(values[0].as_ref().opt_checked_add(MyInt(1)), Ok(Some(MyInt(2))));
+/// assert_eq!(values[1].as_ref().opt_checked_add(MyInt(1)), Ok(None));
+/// ```
+#[macro_export]
+macro_rules! impl_option_operations {
+    ($($typ_:ident($inner:ty)),+ $(,)?) => {
+        $(
+            impl $crate::OptionOperations for $typ_ {}
+
+            $crate::__impl_option_operations_checked!(
+                $typ_, $inner,
+                OptionCheckedAdd, opt_checked_add,
+                OptionCheckedSub, opt_checked_sub,
+                OptionCheckedMul, opt_checked_mul,
+                OptionCheckedDiv, opt_checked_div,
+                OptionCheckedRem, opt_checked_rem,
+            );
+
+            $crate::__impl_option_operations_overflowing!(
+                $typ_, $inner,
+                OptionOverflowingAdd, opt_overflowing_add,
+                OptionOverflowingSub, opt_overflowing_sub,
+                OptionOverflowingMul, opt_overflowing_mul,
+                OptionOverflowingDiv, opt_overflowing_div,
+                OptionOverflowingRem, opt_overflowing_rem,
+            );
+
+            $crate::__impl_option_operations_plain!(
+                $typ_, $inner,
+                OptionWrappingAdd, opt_wrapping_add,
+                OptionWrappingSub, opt_wrapping_sub,
+                OptionWrappingMul, opt_wrapping_mul,
+                OptionWrappingDiv, opt_wrapping_div,
+                OptionWrappingRem, opt_wrapping_rem,
+                OptionSaturatingAdd, opt_saturating_add,
+                OptionSaturatingSub, opt_saturating_sub,
+                OptionSaturatingMul, opt_saturating_mul,
+                OptionSaturatingDiv, opt_saturating_div,
+            );
+        )+
+    };
+}
+
+/// Implements an `Option*` trait for an integer-like type.
+///
+/// This is the public counterpart of the macro used internally to
+/// implement the base operations for the primitive integers.
+/// It is useful to downstream crates defining their own integer-like
+/// types, e.g. big integers or SIMD lanes, which need to implement the
+/// base of the checked, overflowing, wrapping or saturating families.
+///
+/// The type must also implement [`OptionOperations`](crate::OptionOperations)
+/// so that the `Option` variants are auto-implemented.
+///
+/// ```
+/// # use option_operations::{impl_opt_for_int_like, Error, OptionCheckedDiv, OptionOperations};
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// struct BigInt(u128);
+///
+/// impl OptionOperations for BigInt {}
+///
+/// impl_opt_for_int_like!(OptionCheckedDiv, BigInt, {
+///     type Output = Self;
+///     fn opt_checked_div(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+///         if rhs.0 == 0 {
+///             return Err(Error::DivisionByZero);
+///         }
+///         self.0.checked_div(rhs.0).ok_or(Error::Overflow).map(|q| Some(BigInt(q)))
+///     }
+/// });
+///
+/// assert_eq!(Some(BigInt(10)).opt_checked_div(Some(BigInt(2))), Ok(Some(BigInt(5))));
+/// assert_eq!(BigInt(10).opt_checked_div(BigInt(0)), Err(Error::DivisionByZero));
+/// assert_eq!(BigInt(10).opt_checked_div(None), Ok(None));
+/// ```
+#[macro_export]
+macro_rules! impl_opt_for_int_like {
+    ($trait:path, $typ_:ty, $block:tt) => {
+        impl $trait for $typ_ $block
+    };
+}
+
+/// Implements [`OptionOperations`](crate::OptionOperations) for each
+/// of the listed types.
+///
+/// This only generates the marker implementations. The `Option*` traits
+/// which are auto-implemented for types implementing the `std` operator
+/// traits, e.g. [`OptionAdd`](crate::OptionAdd) for `Add`, are then
+/// available for the listed types:
+///
+/// ```
+/// # use core::ops::Add;
+/// # use option_operations::{impl_option_operations_for, OptionAdd, OptionOrd};
+/// #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+/// struct Meters(f64);
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+/// struct Seconds(u64);
+///
+/// impl_option_operations_for!(Meters, Seconds);
+///
+/// impl Add for Meters {
+///     type Output = Meters;
+///     fn add(self, rhs: Meters) -> Meters {
+///         Meters(self.0 + rhs.0)
+///     }
+/// }
+///
+/// assert_eq!(Some(Meters(1.0)).opt_add(Meters(0.5)), Some(Meters(1.5)));
+/// assert_eq!(Some(Seconds(1)).opt_lt(None), None);
+/// ```
+///
+/// See [`impl_option_operations`] to also generate the arithmetic base
+/// implementations of newtypes over a primitive integer.
+#[macro_export]
+macro_rules! impl_option_operations_for {
+    ($($typ_:ty),+ $(,)?) => {
+        $(
+            impl $crate::OptionOperations for $typ_ {}
+        )+
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_option_operations_checked {
+    ($typ_:ident, $inner:ty, $($trait:ident, $op:ident),+ $(,)?) => {
+        $(
+            impl $crate::$trait for $typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $typ_) -> Result<Option<Self::Output>, $crate::Error> {
+                    <$inner as $crate::$trait>::$op(self.0, rhs.0).map(|ok| ok.map($typ_))
+                }
+            }
+
+            impl $crate::$trait<$inner> for $typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $inner) -> Result<Option<Self::Output>, $crate::Error> {
+                    <$inner as $crate::$trait>::$op(self.0, rhs).map(|ok| ok.map($typ_))
+                }
+            }
+
+            impl $crate::$trait for &$typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: &$typ_) -> Result<Option<Self::Output>, $crate::Error> {
+                    <$typ_ as $crate::$trait>::$op(*self, *rhs)
+                }
+            }
+
+            impl $crate::$trait<$typ_> for &$typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $typ_) -> Result<Option<Self::Output>, $crate::Error> {
+                    <$typ_ as $crate::$trait>::$op(*self, rhs)
+                }
+            }
+
+            impl $crate::$trait<$inner> for &$typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $inner) -> Result<Option<Self::Output>, $crate::Error> {
+                    <$typ_ as $crate::$trait<$inner>>::$op(*self, rhs)
+                }
+            }
+        )+
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_option_operations_overflowing {
+    ($typ_:ident, $inner:ty, $($trait:ident, $op:ident),+ $(,)?) => {
+        $(
+            impl $crate::$trait for $typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $typ_) -> Option<(Self::Output, bool)> {
+                    <$inner as $crate::$trait>::$op(self.0, rhs.0)
+                        .map(|(val, flag)| ($typ_(val), flag))
+                }
+            }
+
+            impl $crate::$trait<$inner> for $typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $inner) -> Option<(Self::Output, bool)> {
+                    <$inner as $crate::$trait>::$op(self.0, rhs)
+                        .map(|(val, flag)| ($typ_(val), flag))
+                }
+            }
+
+            impl $crate::$trait for &$typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: &$typ_) -> Option<(Self::Output, bool)> {
+                    <$typ_ as $crate::$trait>::$op(*self, *rhs)
+                }
+            }
+
+            impl $crate::$trait<$typ_> for &$typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $typ_) -> Option<(Self::Output, bool)> {
+                    <$typ_ as $crate::$trait>::$op(*self, rhs)
+                }
+            }
+
+            impl $crate::$trait<$inner> for &$typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $inner) -> Option<(Self::Output, bool)> {
+                    <$typ_ as $crate::$trait<$inner>>::$op(*self, rhs)
+                }
+            }
+        )+
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_option_operations_plain {
+    ($typ_:ident, $inner:ty, $($trait:ident, $op:ident),+ $(,)?) => {
+        $(
+            impl $crate::$trait for $typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $typ_) -> Option<Self::Output> {
+                    <$inner as $crate::$trait>::$op(self.0, rhs.0).map($typ_)
+                }
+            }
+
+            impl $crate::$trait<$inner> for $typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $inner) -> Option<Self::Output> {
+                    <$inner as $crate::$trait>::$op(self.0, rhs).map($typ_)
+                }
+            }
+
+            impl $crate::$trait for &$typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: &$typ_) -> Option<Self::Output> {
+                    <$typ_ as $crate::$trait>::$op(*self, *rhs)
+                }
+            }
+
+            impl $crate::$trait<$typ_> for &$typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $typ_) -> Option<Self::Output> {
+                    <$typ_ as $crate::$trait>::$op(*self, rhs)
+                }
+            }
+
+            impl $crate::$trait<$inner> for &$typ_ {
+                type Output = $typ_;
+                #[inline]
+                fn $op(self, rhs: $inner) -> Option<Self::Output> {
+                    <$typ_ as $crate::$trait<$inner>>::$op(*self, rhs)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        Error, OptionCheckedAdd, OptionCheckedDiv, OptionCheckedRem, OptionCheckedSub,
+        OptionOperations, OptionOverflowingAdd, OptionOverflowingMul, OptionSaturatingSub,
+        OptionWrappingAdd, OptionWrappingDiv,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyUInt(u8);
+
+    impl_option_operations!(MyInt(i64), MyUInt(u8));
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const MY_MAX: MyInt = MyInt(i64::MAX);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn checked() {
+        assert_eq!(MY_1.opt_checked_add(MY_1), Ok(SOME_2));
+        assert_eq!(SOME_1.opt_checked_add(1), Ok(SOME_2));
+        assert_eq!(MY_MAX.opt_checked_add(SOME_1), Err(Error::Overflow));
+        assert_eq!(MY_MIN.opt_checked_sub(&Some(1)), Err(Error::Overflow));
+        assert_eq!(SOME_2.opt_checked_div(MY_0), Err(Error::DivisionByZero));
+        assert_eq!(SOME_2.opt_checked_rem(Some(0)), Err(Error::DivisionByZero));
+        assert_eq!(NONE.opt_checked_div(MY_0), Ok(None));
+
+        assert_eq!(MyUInt(255).opt_checked_add(MyUInt(1)), Err(Error::Overflow));
+    }
+
+    #[test]
+    fn overflowing_wrapping_saturating() {
+        assert_eq!(MY_MAX.opt_overflowing_mul(2), Some((MyInt(-2), true)));
+        assert_eq!(MY_MAX.opt_wrapping_add(SOME_1), Some(MY_MIN));
+        assert_eq!(MY_MIN.opt_saturating_sub(MY_1), Some(MY_MIN));
+        assert_eq!(MyUInt(0).opt_saturating_sub(1), Some(MyUInt(0)));
+        assert_eq!(NONE.opt_wrapping_add(MY_1), None);
+    }
+
+    #[test]
+    fn ref_receivers() {
+        let values = [SOME_1, NONE, Some(MY_MAX)];
+        let mut iter = values.iter().map(Option::as_ref);
+
+        let some_1 = iter.next().unwrap();
+        assert_eq!(some_1.opt_checked_add(MY_1), Ok(SOME_2));
+        assert_eq!(some_1.opt_checked_add(Some(1)), Ok(SOME_2));
+        assert_eq!(some_1.opt_wrapping_add(&SOME_1), Some(MY_2));
+        assert_eq!(some_1.opt_overflowing_add(1), Some((MY_2, false)));
+        assert_eq!(some_1.opt_saturating_sub(MY_2), Some(MyInt(-1)));
+        assert_eq!((&MY_1).opt_checked_add(&MY_1), Ok(SOME_2));
+
+        let none = iter.next().unwrap();
+        assert_eq!(none.opt_checked_add(MY_1), Ok(None));
+        assert_eq!(none.opt_wrapping_add(1), None);
+
+        let some_max = iter.next().unwrap();
+        assert_eq!(some_max.opt_checked_add(MY_1), Err(Error::Overflow));
+        assert_eq!(some_max.opt_wrapping_add(MY_1), Some(MY_MIN));
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyLane(u16);
+
+    impl OptionOperations for MyLane {}
+
+    impl_opt_for_int_like!(OptionOverflowingAdd, MyLane, {
+        type Output = Self;
+        fn opt_overflowing_add(self, rhs: Self) -> Option<(Self::Output, bool)> {
+            let (val, flag) = self.0.overflowing_add(rhs.0);
+            Some((MyLane(val), flag))
+        }
+    });
+
+    impl_opt_for_int_like!(crate::OptionWrappingDiv<u16>, MyLane, {
+        type Output = Self;
+        fn opt_wrapping_div(self, rhs: u16) -> Option<Self::Output> {
+            Some(MyLane(self.0.wrapping_div(rhs)))
+        }
+    });
+
+    #[test]
+    fn int_like() {
+        assert_eq!(
+            MyLane(u16::MAX).opt_overflowing_add(Some(MyLane(1))),
+            Some((MyLane(0), true))
+        );
+        assert_eq!(
+            Some(MyLane(6)).opt_wrapping_div(Some(3u16)),
+            Some(MyLane(2))
+        );
+        assert_eq!(Option::<MyLane>::None.opt_wrapping_div(3u16), None);
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct Meters(u32);
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct Label(char);
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct Ratio(f32);
+
+    impl_option_operations_for!(Meters, Label, Ratio,);
+
+    #[test]
+    fn marker_for_list() {
+        use crate::{OptionMinMax, OptionOrd};
+
+        assert_eq!(Some(Meters(1)).opt_lt(Meters(2)), Some(true));
+        assert_eq!(Some(Meters(1)).opt_lt(None), None);
+        assert_eq!(Label('a').opt_max(Some(Label('b'))), Some(Label('b')));
+        assert_eq!(Some(Ratio(0.5)).opt_min(Ratio(0.25)), Some(Ratio(0.25)));
+        assert_eq!(Option::<Ratio>::None.opt_min(Ratio(0.25)), None);
+    }
+}
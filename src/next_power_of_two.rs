@@ -0,0 +1,116 @@
+//! Traits for the next power of two [`OptionOperations`].
+
+use crate::{Error, OptionOperations};
+
+option_unary_op_custom!(
+    NextPowerOfTwo,
+    next_power_of_two,
+    "smallest power of two greater than or equal to `self`",
+    "
+# Panics
+
+Most implementations will panic in debug mode if the next power of two
+is greater than the type's maximum value.
+See [`OptionCheckedNextPowerOfTwo`] for a non-panicking alternative.",
+);
+
+impl_for_unsigned_ints!(OptionNextPowerOfTwo, {
+    type Output = Self;
+    #[inline]
+    fn opt_next_power_of_two(self) -> Option<Self::Output> {
+        Some(self.next_power_of_two())
+    }
+});
+
+option_unary_op_checked!(
+    NextPowerOfTwo,
+    next_power_of_two,
+    "smallest power of two greater than or equal to `self`",
+    "- Returns `Err(Error::Overflow)` if the next power of two is greater than
+the type's maximum value.",
+);
+
+impl_for_unsigned_ints!(OptionCheckedNextPowerOfTwo, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_next_power_of_two(self) -> Result<Option<Self::Output>, Error> {
+        self.checked_next_power_of_two()
+            .ok_or(Error::Overflow)
+            .map(Some)
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u32);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionNextPowerOfTwo for MyInt {
+        type Output = MyInt;
+        fn opt_next_power_of_two(self) -> Option<Self::Output> {
+            self.0.opt_next_power_of_two().map(MyInt)
+        }
+    }
+
+    impl OptionCheckedNextPowerOfTwo for MyInt {
+        type Output = MyInt;
+        fn opt_checked_next_power_of_two(self) -> Result<Option<Self::Output>, Error> {
+            self.0
+                .opt_checked_next_power_of_two()
+                .map(|ok| ok.map(MyInt))
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_33: MyInt = MyInt(33);
+    const MY_64: MyInt = MyInt(64);
+    const MY_MAX: MyInt = MyInt(u32::MAX);
+    const SOME_1: Option<MyInt> = Some(MyInt(1));
+    const SOME_33: Option<MyInt> = Some(MY_33);
+    const SOME_64: Option<MyInt> = Some(MY_64);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn next_power_of_two() {
+        assert_eq!(MY_33.opt_next_power_of_two(), SOME_64);
+        assert_eq!(MY_64.opt_next_power_of_two(), SOME_64);
+        assert_eq!(MY_0.opt_next_power_of_two(), SOME_1);
+        assert_eq!(SOME_33.opt_next_power_of_two(), SOME_64);
+        assert_eq!(NONE.opt_next_power_of_two(), None);
+
+        assert_eq!(Some(33u32).opt_next_power_of_two(), Some(64));
+        assert_eq!(Some(1u32 << 31).opt_next_power_of_two(), Some(1 << 31));
+    }
+
+    #[test]
+    fn checked_next_power_of_two() {
+        assert_eq!(MY_33.opt_checked_next_power_of_two(), Ok(SOME_64));
+        assert_eq!(MY_0.opt_checked_next_power_of_two(), Ok(SOME_1));
+        assert_eq!(SOME_64.opt_checked_next_power_of_two(), Ok(SOME_64));
+        assert_eq!(MY_MAX.opt_checked_next_power_of_two(), Err(Error::Overflow));
+        assert_eq!(NONE.opt_checked_next_power_of_two(), Ok(None));
+
+        assert_eq!(
+            Some(1u32 << 31).opt_checked_next_power_of_two(),
+            Ok(Some(1 << 31))
+        );
+        assert_eq!(
+            Some((1u32 << 31) + 1).opt_checked_next_power_of_two(),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            Some(u32::MAX).opt_checked_next_power_of_two(),
+            Err(Error::Overflow)
+        );
+        assert_eq!(Some(128u8).opt_checked_next_power_of_two(), Ok(Some(128)));
+        assert_eq!(
+            Some(129u8).opt_checked_next_power_of_two(),
+            Err(Error::Overflow)
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! Traits for the bitwise NOT [`OptionOperations`].
+
+use core::ops::Not;
+
+use crate::OptionOperations;
+
+/// Trait for values and `Option`s bitwise NOT.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionBitNot` for `Option<T>`.
+///
+/// This trait is auto-implemented for [`OptionOperations`] types implementing
+/// `Not`.
+pub trait OptionBitNot {
+    /// The resulting inner type after applying the bitwise NOT.
+    type Output;
+
+    /// Computes the bitwise NOT, i.e. the complement of `self`.
+    ///
+    /// Returns `None` if `self` is `None`.
+    #[must_use]
+    fn opt_not(self) -> Option<Self::Output>;
+}
+
+impl<T> OptionBitNot for T
+where
+    T: OptionOperations + Not,
+{
+    type Output = <T as Not>::Output;
+
+    #[inline]
+    fn opt_not(self) -> Option<Self::Output> {
+        Some(self.not())
+    }
+}
+
+impl<T> OptionBitNot for Option<T>
+where
+    T: OptionOperations + OptionBitNot,
+{
+    type Output = <T as OptionBitNot>::Output;
+
+    #[inline]
+    fn opt_not(self) -> Option<Self::Output> {
+        self.and_then(|inner_self| inner_self.opt_not())
+    }
+}
+
+/// Trait for values and `Option`s bitwise NOT assignment.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionBitNotAssign` for `Option<T>`.
+///
+/// This trait is auto-implemented for `Copy` [`OptionOperations`] types
+/// implementing `Not<Output = Self>`.
+pub trait OptionBitNotAssign {
+    /// Performs the bitwise NOT assignment.
+    ///
+    /// `self` is unchanged if it is `None`.
+    fn opt_not_assign(&mut self);
+}
+
+impl<T> OptionBitNotAssign for T
+where
+    T: OptionOperations + Not<Output = T> + Copy,
+{
+    #[inline]
+    fn opt_not_assign(&mut self) {
+        *self = self.not();
+    }
+}
+
+impl<T> OptionBitNotAssign for Option<T>
+where
+    T: OptionOperations + OptionBitNotAssign,
+{
+    #[inline]
+    fn opt_not_assign(&mut self) {
+        if let Some(inner_self) = self {
+            inner_self.opt_not_assign();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+    use core::ops::Not;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl Not for MyInt {
+        type Output = MyInt;
+
+        fn not(self) -> MyInt {
+            MyInt(self.0.not())
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_MAX: MyInt = MyInt(u64::MAX);
+    const MY_HALF: MyInt = MyInt(0xffff_ffff);
+    const MY_OTHER_HALF: MyInt = MyInt(0xffff_ffff_0000_0000);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_MAX: Option<MyInt> = Some(MY_MAX);
+    const SOME_HALF: Option<MyInt> = Some(MY_HALF);
+    const SOME_OTHER_HALF: Option<MyInt> = Some(MY_OTHER_HALF);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn not() {
+        assert_eq!(MY_0.opt_not(), SOME_MAX);
+        assert_eq!(MY_HALF.opt_not(), SOME_OTHER_HALF);
+        assert_eq!(SOME_MAX.opt_not(), SOME_0);
+        assert_eq!(SOME_OTHER_HALF.opt_not(), SOME_HALF);
+        assert_eq!(NONE.opt_not(), NONE);
+
+        assert_eq!(Some(0u8).opt_not(), Some(255));
+        assert_eq!(Some(0i32).opt_not(), Some(-1));
+        assert_eq!(Option::<u16>::None.opt_not(), None);
+    }
+
+    #[test]
+    fn not_assign() {
+        let mut my = MY_0;
+        my.opt_not_assign();
+        assert_eq!(my, MY_MAX);
+
+        let mut some = SOME_HALF;
+        some.opt_not_assign();
+        assert_eq!(some, SOME_OTHER_HALF);
+
+        let mut none = NONE;
+        none.opt_not_assign();
+        assert_eq!(none, NONE);
+
+        let mut some = Some(0x0fu8);
+        some.opt_not_assign();
+        assert_eq!(some, Some(0xf0));
+    }
+}
@@ -0,0 +1,210 @@
+//! Bridge between `num-traits` checked operations and [`OptionOperations`].
+//!
+//! This module is available with the `num-traits` feature.
+
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, Zero};
+
+use crate::{
+    Error, OptionCheckedAdd, OptionCheckedDiv, OptionCheckedMul, OptionCheckedRem,
+    OptionCheckedSub, OptionOperations,
+};
+
+/// Marker trait for types participating in `option-operations` through
+/// their `num-traits` checked operations.
+///
+/// Implementing this trait for a type which implements the `num-traits`
+/// checked operations leads to the following auto-implementations:
+///
+/// - `OptionCheckedAdd` from `num_traits::CheckedAdd`.
+/// - `OptionCheckedSub` from `num_traits::CheckedSub`.
+/// - `OptionCheckedMul` from `num_traits::CheckedMul`.
+/// - `OptionCheckedDiv` from `num_traits::CheckedDiv` & `num_traits::Zero`.
+/// - `OptionCheckedRem` from `num_traits::CheckedRem` & `num_traits::Zero`.
+///
+/// Since the `num-traits` checked operations return `None` on failure,
+/// the `Error` is determined as follows:
+///
+/// - For the division and the remainder, `Error::DivisionByZero` if `rhs`
+///   is zero, `Error::Overflow` otherwise.
+/// - For the other operations, `Error::Overflow`.
+///
+/// ```
+/// # use option_operations::{Error, NumOperations, OptionCheckedAdd, OptionOperations};
+/// # use core::ops::Add;
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// struct MyNum(u8);
+///
+/// impl Add for MyNum {
+///     type Output = MyNum;
+///     fn add(self, rhs: MyNum) -> MyNum {
+///         MyNum(self.0 + rhs.0)
+///     }
+/// }
+///
+/// impl num_traits::CheckedAdd for MyNum {
+///     fn checked_add(&self, rhs: &MyNum) -> Option<MyNum> {
+///         self.0.checked_add(rhs.0).map(MyNum)
+///     }
+/// }
+///
+/// impl OptionOperations for MyNum {}
+/// impl NumOperations for MyNum {}
+///
+/// assert_eq!(Some(MyNum(1)).opt_checked_add(MyNum(2)), Ok(Some(MyNum(3))));
+/// assert_eq!(MyNum(255).opt_checked_add(Some(MyNum(1))), Err(Error::Overflow));
+/// ```
+pub trait NumOperations: OptionOperations {}
+
+impl<T> OptionCheckedAdd for T
+where
+    T: NumOperations + CheckedAdd,
+{
+    type Output = T;
+    #[inline]
+    fn opt_checked_add(self, rhs: T) -> Result<Option<Self::Output>, Error> {
+        self.checked_add(&rhs).ok_or(Error::Overflow).map(Some)
+    }
+}
+
+impl<T> OptionCheckedSub for T
+where
+    T: NumOperations + CheckedSub,
+{
+    type Output = T;
+    #[inline]
+    fn opt_checked_sub(self, rhs: T) -> Result<Option<Self::Output>, Error> {
+        self.checked_sub(&rhs).ok_or(Error::Overflow).map(Some)
+    }
+}
+
+impl<T> OptionCheckedMul for T
+where
+    T: NumOperations + CheckedMul,
+{
+    type Output = T;
+    #[inline]
+    fn opt_checked_mul(self, rhs: T) -> Result<Option<Self::Output>, Error> {
+        self.checked_mul(&rhs).ok_or(Error::Overflow).map(Some)
+    }
+}
+
+impl<T> OptionCheckedDiv for T
+where
+    T: NumOperations + CheckedDiv + Zero,
+{
+    type Output = T;
+    #[inline]
+    fn opt_checked_div(self, rhs: T) -> Result<Option<Self::Output>, Error> {
+        if rhs.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        self.checked_div(&rhs).ok_or(Error::Overflow).map(Some)
+    }
+}
+
+impl<T> OptionCheckedRem for T
+where
+    T: NumOperations + CheckedRem + Zero,
+{
+    type Output = T;
+    #[inline]
+    fn opt_checked_rem(self, rhs: T) -> Result<Option<Self::Output>, Error> {
+        if rhs.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        self.checked_rem(&rhs).ok_or(Error::Overflow).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::ops::{Add, Div, Mul, Rem, Sub};
+
+    use super::NumOperations;
+    use crate::{
+        Error, OptionCheckedAdd, OptionCheckedDiv, OptionCheckedMul, OptionCheckedRem,
+        OptionCheckedSub, OptionOperations,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i8);
+
+    impl OptionOperations for MyInt {}
+    impl NumOperations for MyInt {}
+
+    macro_rules! impl_op {
+        ($trait:ident, $op:ident, $checked_trait:ident, $checked_op:ident) => {
+            impl $trait for MyInt {
+                type Output = MyInt;
+                fn $op(self, rhs: MyInt) -> MyInt {
+                    MyInt(self.0.$op(rhs.0))
+                }
+            }
+
+            impl num_traits::$checked_trait for MyInt {
+                fn $checked_op(&self, rhs: &MyInt) -> Option<MyInt> {
+                    self.0.$checked_op(rhs.0).map(MyInt)
+                }
+            }
+        };
+    }
+
+    impl_op!(Add, add, CheckedAdd, checked_add);
+    impl_op!(Sub, sub, CheckedSub, checked_sub);
+    impl_op!(Mul, mul, CheckedMul, checked_mul);
+    impl_op!(Div, div, CheckedDiv, checked_div);
+    impl_op!(Rem, rem, CheckedRem, checked_rem);
+
+    impl num_traits::Zero for MyInt {
+        fn zero() -> Self {
+            MY_0
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    const MY_MINUS_1: MyInt = MyInt(-1);
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const MY_3: MyInt = MyInt(3);
+    const MY_MIN: MyInt = MyInt(i8::MIN);
+    const MY_MAX: MyInt = MyInt(i8::MAX);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const SOME_MAX: Option<MyInt> = Some(MY_MAX);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn checked_add_sub_mul() {
+        assert_eq!(MY_1.opt_checked_add(MY_2), Ok(SOME_3));
+        assert_eq!(SOME_1.opt_checked_add(SOME_2), Ok(SOME_3));
+        assert_eq!(MY_MAX.opt_checked_add(SOME_1), Err(Error::Overflow));
+        assert_eq!(NONE.opt_checked_add(MY_MAX), Ok(None));
+
+        assert_eq!(MY_3.opt_checked_sub(&SOME_2), Ok(SOME_1));
+        assert_eq!(MY_MIN.opt_checked_sub(MY_1), Err(Error::Overflow));
+        assert_eq!(MY_MIN.opt_checked_sub(NONE), Ok(None));
+
+        assert_eq!(SOME_3.opt_checked_mul(MY_MINUS_1), Ok(Some(MyInt(-3))));
+        assert_eq!(SOME_MAX.opt_checked_mul(MY_2), Err(Error::Overflow));
+        assert_eq!(SOME_MAX.opt_checked_mul(NONE), Ok(None));
+    }
+
+    #[test]
+    fn checked_div_rem() {
+        assert_eq!(MY_3.opt_checked_div(MY_2), Ok(SOME_1));
+        assert_eq!(SOME_3.opt_checked_div(SOME_0), Err(Error::DivisionByZero));
+        assert_eq!(MY_MIN.opt_checked_div(MY_MINUS_1), Err(Error::Overflow));
+        assert_eq!(NONE.opt_checked_div(MY_0), Ok(None));
+
+        assert_eq!(MY_3.opt_checked_rem(SOME_2), Ok(SOME_1));
+        assert_eq!(SOME_3.opt_checked_rem(&SOME_0), Err(Error::DivisionByZero));
+        assert_eq!(MY_MIN.opt_checked_rem(MY_MINUS_1), Err(Error::Overflow));
+        assert_eq!(MY_3.opt_checked_rem(NONE), Ok(None));
+    }
+}
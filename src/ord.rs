@@ -4,6 +4,27 @@ use core::cmp::Ordering;
 
 use crate::OptionOperations;
 
+/// Where `None` sorts relative to `Some(_)` in [`OptionOrd::opt_cmp_with`].
+///
+/// Two `None`s are always considered equal.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NoneOrdering {
+    /// `None` is less than any `Some(_)`.
+    First,
+    /// `None` is greater than any `Some(_)`.
+    Last,
+}
+
+impl NoneOrdering {
+    /// Returns the ordering of a `None` compared to a `Some(_)`.
+    pub(crate) fn none_cmp_some(self) -> Ordering {
+        match self {
+            NoneOrdering::First => Ordering::Less,
+            NoneOrdering::Last => Ordering::Greater,
+        }
+    }
+}
+
 /// Trait for values and `Option`s that can be compared for a sort-order.
 ///
 /// This implementation is mainly intended at working around the `PartialOrd`
@@ -53,6 +74,25 @@ pub trait OptionOrd<Rhs, InnerRhs = Rhs> {
     #[must_use]
     fn opt_cmp(&self, rhs: Rhs) -> Option<Ordering>;
 
+    /// Returns an ordering between `self` and `rhs` values if one exists,
+    /// placing `None` according to `none_ordering`.
+    ///
+    /// Contrary to [`opt_cmp`](Self::opt_cmp), a `None` can be compared
+    /// with a `Some(_)`. Returns `None` if the inner values can't be compared.
+    ///
+    /// ```
+    /// # use option_operations::{NoneOrdering, OptionOrd};
+    /// let mut values = vec![Some(2), None, Some(1)];
+    ///
+    /// values.sort_by(|a, b| a.opt_cmp_with(b, NoneOrdering::First).unwrap());
+    /// assert_eq!(values, [None, Some(1), Some(2)]);
+    ///
+    /// values.sort_by(|a, b| a.opt_cmp_with(b, NoneOrdering::Last).unwrap());
+    /// assert_eq!(values, [Some(1), Some(2), None]);
+    /// ```
+    #[must_use]
+    fn opt_cmp_with(&self, rhs: Rhs, none_ordering: NoneOrdering) -> Option<Ordering>;
+
     /// Tests whether `self` is less than `rhs`.
     ///
     /// Returns `None` if they can't be compared, e.g. if
@@ -97,60 +137,104 @@ impl<T, Rhs> OptionOrd<&Rhs, Rhs> for T
 where
     T: OptionOperations + PartialOrd<Rhs>,
 {
+    #[inline]
     fn opt_cmp(&self, rhs: &Rhs) -> Option<Ordering> {
         self.partial_cmp(rhs)
     }
+    #[inline]
+    fn opt_cmp_with(&self, rhs: &Rhs, _none_ordering: NoneOrdering) -> Option<Ordering> {
+        self.partial_cmp(rhs)
+    }
 }
 
 impl<T, Rhs> OptionOrd<Rhs> for T
 where
     T: OptionOperations + for<'a> OptionOrd<&'a Rhs, Rhs>,
 {
+    #[inline]
     fn opt_cmp(&self, rhs: Rhs) -> Option<Ordering> {
         self.opt_cmp(&rhs)
     }
+    #[inline]
+    fn opt_cmp_with(&self, rhs: Rhs, none_ordering: NoneOrdering) -> Option<Ordering> {
+        self.opt_cmp_with(&rhs, none_ordering)
+    }
 }
 
 impl<T, InnerRhs> OptionOrd<&Option<InnerRhs>, InnerRhs> for T
 where
     T: OptionOperations + for<'a> OptionOrd<&'a InnerRhs, InnerRhs>,
 {
+    #[inline]
     fn opt_cmp(&self, rhs: &Option<InnerRhs>) -> Option<Ordering> {
         rhs.as_ref().and_then(|inner_rhs| self.opt_cmp(inner_rhs))
     }
+    #[inline]
+    fn opt_cmp_with(
+        &self,
+        rhs: &Option<InnerRhs>,
+        none_ordering: NoneOrdering,
+    ) -> Option<Ordering> {
+        match rhs.as_ref() {
+            Some(inner_rhs) => self.opt_cmp(inner_rhs),
+            None => Some(none_ordering.none_cmp_some().reverse()),
+        }
+    }
 }
 
 impl<T, InnerRhs> OptionOrd<Option<InnerRhs>, InnerRhs> for T
 where
     T: OptionOperations + for<'a> OptionOrd<&'a InnerRhs, InnerRhs>,
 {
+    #[inline]
     fn opt_cmp(&self, rhs: Option<InnerRhs>) -> Option<Ordering> {
         rhs.as_ref().and_then(|inner_rhs| self.opt_cmp(inner_rhs))
     }
+    #[inline]
+    fn opt_cmp_with(&self, rhs: Option<InnerRhs>, none_ordering: NoneOrdering) -> Option<Ordering> {
+        match rhs.as_ref() {
+            Some(inner_rhs) => self.opt_cmp(inner_rhs),
+            None => Some(none_ordering.none_cmp_some().reverse()),
+        }
+    }
 }
 
 impl<T, Rhs> OptionOrd<&Rhs, Rhs> for Option<T>
 where
     T: OptionOperations + for<'a> OptionOrd<&'a Rhs, Rhs>,
 {
+    #[inline]
     fn opt_cmp(&self, rhs: &Rhs) -> Option<Ordering> {
         self.as_ref().and_then(|inner_self| inner_self.opt_cmp(rhs))
     }
+    #[inline]
+    fn opt_cmp_with(&self, rhs: &Rhs, none_ordering: NoneOrdering) -> Option<Ordering> {
+        match self {
+            Some(inner_self) => inner_self.opt_cmp(rhs),
+            None => Some(none_ordering.none_cmp_some()),
+        }
+    }
 }
 
 impl<T, Rhs> OptionOrd<Rhs> for Option<T>
 where
     T: OptionOperations + for<'a> OptionOrd<&'a Rhs, Rhs>,
 {
+    #[inline]
     fn opt_cmp(&self, rhs: Rhs) -> Option<Ordering> {
         self.opt_cmp(&rhs)
     }
+    #[inline]
+    fn opt_cmp_with(&self, rhs: Rhs, none_ordering: NoneOrdering) -> Option<Ordering> {
+        self.opt_cmp_with(&rhs, none_ordering)
+    }
 }
 
 impl<T, InnerRhs> OptionOrd<&Option<InnerRhs>, InnerRhs> for Option<T>
 where
     T: OptionOperations + for<'a> OptionOrd<&'a InnerRhs, InnerRhs>,
 {
+    #[inline]
     fn opt_cmp(&self, rhs: &Option<InnerRhs>) -> Option<Ordering> {
         match (self, rhs) {
             (Some(inner_self), Some(inner_rhs)) => inner_self.opt_cmp(inner_rhs),
@@ -158,12 +242,26 @@ where
             _ => None,
         }
     }
+    #[inline]
+    fn opt_cmp_with(
+        &self,
+        rhs: &Option<InnerRhs>,
+        none_ordering: NoneOrdering,
+    ) -> Option<Ordering> {
+        match (self, rhs) {
+            (Some(inner_self), Some(inner_rhs)) => inner_self.opt_cmp(inner_rhs),
+            (None, None) => Some(Ordering::Equal),
+            (None, Some(_)) => Some(none_ordering.none_cmp_some()),
+            (Some(_), None) => Some(none_ordering.none_cmp_some().reverse()),
+        }
+    }
 }
 
 impl<T, InnerRhs> OptionOrd<Option<InnerRhs>, InnerRhs> for Option<T>
 where
     T: OptionOperations + for<'a> OptionOrd<&'a InnerRhs, InnerRhs>,
 {
+    #[inline]
     fn opt_cmp(&self, rhs: Option<InnerRhs>) -> Option<Ordering> {
         match (self, rhs.as_ref()) {
             (Some(inner_self), Some(inner_rhs)) => inner_self.opt_cmp(inner_rhs),
@@ -171,13 +269,22 @@ where
             _ => None,
         }
     }
+    #[inline]
+    fn opt_cmp_with(&self, rhs: Option<InnerRhs>, none_ordering: NoneOrdering) -> Option<Ordering> {
+        match (self, rhs.as_ref()) {
+            (Some(inner_self), Some(inner_rhs)) => inner_self.opt_cmp(inner_rhs),
+            (None, None) => Some(Ordering::Equal),
+            (None, Some(_)) => Some(none_ordering.none_cmp_some()),
+            (Some(_), None) => Some(none_ordering.none_cmp_some().reverse()),
+        }
+    }
 }
 
 #[cfg(test)]
 mod test {
     use core::cmp::Ordering;
 
-    use super::OptionOrd;
+    use super::{NoneOrdering, OptionOrd};
     use crate::OptionOperations;
 
     #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
@@ -236,4 +343,47 @@ mod test {
         assert_eq!(MY_2.opt_cmp(MY_1), Some(Ordering::Greater));
         assert_eq!(MY_2.opt_cmp(&MY_1), Some(Ordering::Greater));
     }
+
+    #[test]
+    fn opt_cmp_with() {
+        use NoneOrdering::{First, Last};
+
+        assert_eq!(NONE.opt_cmp_with(NONE, First), Some(Ordering::Equal));
+        assert_eq!(NONE.opt_cmp_with(&NONE, Last), Some(Ordering::Equal));
+        assert_eq!(NONE.opt_cmp_with(SOME_1, First), Some(Ordering::Less));
+        assert_eq!(NONE.opt_cmp_with(&SOME_1, Last), Some(Ordering::Greater));
+        assert_eq!(SOME_1.opt_cmp_with(NONE, First), Some(Ordering::Greater));
+        assert_eq!(SOME_1.opt_cmp_with(&NONE, Last), Some(Ordering::Less));
+        assert_eq!(SOME_1.opt_cmp_with(SOME_2, First), Some(Ordering::Less));
+        assert_eq!(SOME_2.opt_cmp_with(&SOME_1, Last), Some(Ordering::Greater));
+
+        assert_eq!(NONE.opt_cmp_with(MY_1, First), Some(Ordering::Less));
+        assert_eq!(NONE.opt_cmp_with(&MY_1, Last), Some(Ordering::Greater));
+        assert_eq!(SOME_1.opt_cmp_with(MY_2, Last), Some(Ordering::Less));
+
+        assert_eq!(MY_1.opt_cmp_with(NONE, First), Some(Ordering::Greater));
+        assert_eq!(MY_1.opt_cmp_with(&NONE, Last), Some(Ordering::Less));
+        assert_eq!(MY_1.opt_cmp_with(SOME_1, Last), Some(Ordering::Equal));
+        assert_eq!(MY_2.opt_cmp_with(MY_1, First), Some(Ordering::Greater));
+
+        assert_eq!(Some(f64::NAN).opt_cmp_with(Some(1.0), First), None);
+        assert_eq!(
+            Some(f64::NAN).opt_cmp_with(None, First),
+            Some(Ordering::Greater)
+        );
+    }
+
+    #[test]
+    fn sort_with_none_ordering() {
+        let mut values = [SOME_2, NONE, SOME_1, NONE, SOME_2];
+
+        values.sort_unstable_by(|a, b| a.opt_cmp_with(b, NoneOrdering::First).unwrap());
+        assert_eq!(values, [NONE, NONE, SOME_1, SOME_2, SOME_2]);
+
+        values.sort_unstable_by(|a, b| a.opt_cmp_with(b, NoneOrdering::Last).unwrap());
+        assert_eq!(values, [SOME_1, SOME_2, SOME_2, NONE, NONE]);
+
+        values.sort_unstable_by(|a, b| b.opt_cmp_with(a, NoneOrdering::First).unwrap());
+        assert_eq!(values, [SOME_2, SOME_2, SOME_1, NONE, NONE]);
+    }
 }
@@ -0,0 +1,196 @@
+//! Trait for the partial order [`OptionOperations`].
+
+use core::cmp::Ordering;
+
+use crate::OptionOperations;
+
+/// Trait for values and `Option`s that can be compared using a partial order.
+///
+/// Contrary to [`OptionOrd`](crate::OptionOrd), which returns `None` both
+/// when an argument is `None` and when the inner values can't be compared,
+/// this trait keeps the two cases apart:
+///
+/// - the outer `Option` is `None` if at least one argument is `None`,
+/// - the inner `Option` is the result of `PartialOrd::partial_cmp`
+///   for the inner values, e.g. `None` when comparing a `NaN`.
+///
+/// ```
+/// # use core::cmp::Ordering;
+/// # use option_operations::OptionPartialCmp;
+/// assert_eq!(Some(1.0).opt_partial_cmp(2.0), Some(Some(Ordering::Less)));
+/// assert_eq!(Some(1.0).opt_partial_cmp(f64::NAN), Some(None));
+/// assert_eq!(Some(1.0).opt_partial_cmp(Option::<f64>::None), None);
+/// ```
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionPartialCmp<Option<InnerRhs>> for T`.
+/// - `OptionPartialCmp<Rhs> for Option<T>`.
+/// - `OptionPartialCmp<Option<InnerRhs>> for Option<T>`.
+/// - ... and some variants with references.
+///
+/// This trait is auto-implemented for [`OptionOperations`] types
+/// implementing `PartialOrd<Rhs>`.
+pub trait OptionPartialCmp<Rhs, InnerRhs = Rhs> {
+    /// Returns the partial ordering between `self` and `rhs`.
+    ///
+    /// - Returns `Some(Some(ordering))` if the inner values could be compared.
+    /// - Returns `Some(None)` if the inner values can't be compared.
+    /// - Returns `None` if at least one argument is `None`.
+    #[must_use]
+    fn opt_partial_cmp(&self, rhs: Rhs) -> Option<Option<Ordering>>;
+}
+
+impl<T, Rhs> OptionPartialCmp<&Rhs, Rhs> for T
+where
+    T: OptionOperations + PartialOrd<Rhs>,
+{
+    #[inline]
+    fn opt_partial_cmp(&self, rhs: &Rhs) -> Option<Option<Ordering>> {
+        Some(self.partial_cmp(rhs))
+    }
+}
+
+impl<T, Rhs> OptionPartialCmp<Rhs> for T
+where
+    T: OptionOperations + for<'a> OptionPartialCmp<&'a Rhs, Rhs>,
+{
+    #[inline]
+    fn opt_partial_cmp(&self, rhs: Rhs) -> Option<Option<Ordering>> {
+        self.opt_partial_cmp(&rhs)
+    }
+}
+
+impl<T, InnerRhs> OptionPartialCmp<&Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + for<'a> OptionPartialCmp<&'a InnerRhs, InnerRhs>,
+{
+    #[inline]
+    fn opt_partial_cmp(&self, rhs: &Option<InnerRhs>) -> Option<Option<Ordering>> {
+        rhs.as_ref()
+            .and_then(|inner_rhs| self.opt_partial_cmp(inner_rhs))
+    }
+}
+
+impl<T, InnerRhs> OptionPartialCmp<Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + for<'a> OptionPartialCmp<&'a InnerRhs, InnerRhs>,
+{
+    #[inline]
+    fn opt_partial_cmp(&self, rhs: Option<InnerRhs>) -> Option<Option<Ordering>> {
+        rhs.as_ref()
+            .and_then(|inner_rhs| self.opt_partial_cmp(inner_rhs))
+    }
+}
+
+impl<T, Rhs> OptionPartialCmp<&Rhs, Rhs> for Option<T>
+where
+    T: OptionOperations + for<'a> OptionPartialCmp<&'a Rhs, Rhs>,
+{
+    #[inline]
+    fn opt_partial_cmp(&self, rhs: &Rhs) -> Option<Option<Ordering>> {
+        self.as_ref()
+            .and_then(|inner_self| inner_self.opt_partial_cmp(rhs))
+    }
+}
+
+impl<T, Rhs> OptionPartialCmp<Rhs> for Option<T>
+where
+    T: OptionOperations + for<'a> OptionPartialCmp<&'a Rhs, Rhs>,
+{
+    #[inline]
+    fn opt_partial_cmp(&self, rhs: Rhs) -> Option<Option<Ordering>> {
+        self.opt_partial_cmp(&rhs)
+    }
+}
+
+impl<T, InnerRhs> OptionPartialCmp<&Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + for<'a> OptionPartialCmp<&'a InnerRhs, InnerRhs>,
+{
+    #[inline]
+    fn opt_partial_cmp(&self, rhs: &Option<InnerRhs>) -> Option<Option<Ordering>> {
+        match (self, rhs) {
+            (Some(inner_self), Some(inner_rhs)) => inner_self.opt_partial_cmp(inner_rhs),
+            _ => None,
+        }
+    }
+}
+
+impl<T, InnerRhs> OptionPartialCmp<Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + for<'a> OptionPartialCmp<&'a InnerRhs, InnerRhs>,
+{
+    #[inline]
+    fn opt_partial_cmp(&self, rhs: Option<InnerRhs>) -> Option<Option<Ordering>> {
+        match (self, rhs.as_ref()) {
+            (Some(inner_self), Some(inner_rhs)) => inner_self.opt_partial_cmp(inner_rhs),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cmp::Ordering;
+
+    use super::OptionPartialCmp;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn partial_cmp_my() {
+        assert_eq!(MY_1.opt_partial_cmp(MY_2), Some(Some(Ordering::Less)));
+        assert_eq!(MY_1.opt_partial_cmp(&MY_1), Some(Some(Ordering::Equal)));
+        assert_eq!(MY_2.opt_partial_cmp(SOME_1), Some(Some(Ordering::Greater)));
+        assert_eq!(MY_2.opt_partial_cmp(&SOME_2), Some(Some(Ordering::Equal)));
+        assert_eq!(SOME_1.opt_partial_cmp(MY_2), Some(Some(Ordering::Less)));
+        assert_eq!(SOME_2.opt_partial_cmp(&MY_1), Some(Some(Ordering::Greater)));
+        assert_eq!(SOME_1.opt_partial_cmp(SOME_1), Some(Some(Ordering::Equal)));
+        assert_eq!(
+            SOME_2.opt_partial_cmp(&SOME_1),
+            Some(Some(Ordering::Greater))
+        );
+
+        assert_eq!(MY_1.opt_partial_cmp(NONE), None);
+        assert_eq!(SOME_1.opt_partial_cmp(&NONE), None);
+        assert_eq!(NONE.opt_partial_cmp(MY_1), None);
+        assert_eq!(NONE.opt_partial_cmp(SOME_1), None);
+        assert_eq!(NONE.opt_partial_cmp(NONE), None);
+    }
+
+    #[test]
+    fn partial_cmp_nan() {
+        let none = Option::<f64>::None;
+
+        // The inner values can't be compared.
+        assert_eq!(1.0f64.opt_partial_cmp(f64::NAN), Some(None));
+        assert_eq!(Some(f64::NAN).opt_partial_cmp(1.0), Some(None));
+        assert_eq!(Some(f64::NAN).opt_partial_cmp(Some(f64::NAN)), Some(None));
+        assert_eq!(Some(1.0f64).opt_partial_cmp(&Some(f64::NAN)), Some(None));
+
+        // At least one operand is absent.
+        assert_eq!(Some(f64::NAN).opt_partial_cmp(none), None);
+        assert_eq!(none.opt_partial_cmp(f64::NAN), None);
+        assert_eq!(1.0f64.opt_partial_cmp(&none), None);
+
+        assert_eq!(
+            Some(1.0f64).opt_partial_cmp(2.0),
+            Some(Some(Ordering::Less))
+        );
+        assert_eq!(
+            Some(-0.0f64).opt_partial_cmp(Some(0.0)),
+            Some(Some(Ordering::Equal))
+        );
+    }
+}
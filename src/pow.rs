@@ -0,0 +1,206 @@
+//! Traits for the exponentiation [`OptionOperations`].
+
+use crate::{Error, OptionOperations};
+
+option_op_custom!(
+    Pow<u32>,
+    pow,
+    "exponentiation",
+    "
+# Panics
+
+Most implementations will panic on overflow in debug mode.
+",
+);
+
+impl_for_ints!(OptionPow<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_pow(self, rhs: u32) -> Option<Self::Output> {
+        Some(self.pow(rhs))
+    }
+});
+
+option_op_checked!(Pow<u32>, pow, "exponentiation");
+
+impl_for_ints!(OptionCheckedPow<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_pow(self, rhs: u32) -> Result<Option<Self::Output>, Error> {
+        self.checked_pow(rhs).ok_or(Error::Overflow).map(Some)
+    }
+});
+
+option_op_overflowing!(Pow<u32>, pow, "exponentiation");
+
+impl_for_ints!(OptionOverflowingPow<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_overflowing_pow(self, rhs: u32) -> Option<(Self::Output, bool)> {
+        Some(self.overflowing_pow(rhs))
+    }
+});
+
+option_op_saturating!(Pow<u32>, pow, "exponentiation");
+
+impl_for_ints!(OptionSaturatingPow<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_saturating_pow(self, rhs: u32) -> Option<Self::Output> {
+        Some(self.saturating_pow(rhs))
+    }
+});
+
+option_op_wrapping!(Pow<u32>, pow, "exponentiation");
+
+impl_for_ints!(OptionWrappingPow<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_wrapping_pow(self, rhs: u32) -> Option<Self::Output> {
+        Some(self.wrapping_pow(rhs))
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const MY_1024: MyInt = MyInt(1024);
+    const MY_HIGH_BIT: MyInt = MyInt(1 << 63);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_1024: Option<MyInt> = Some(MY_1024);
+    const SOME_HIGH_BIT: Option<MyInt> = Some(MY_HIGH_BIT);
+    const NONE: Option<MyInt> = None;
+
+    impl OptionPow<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_pow(self, rhs: u32) -> Option<Self::Output> {
+            self.0.opt_pow(rhs).map(MyInt)
+        }
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(MY_2.opt_pow(10), SOME_1024);
+        assert_eq!(MY_2.opt_pow(Some(10u32)), SOME_1024);
+        assert_eq!(MY_2.opt_pow(&Some(1u32)), SOME_2);
+        assert_eq!(MY_0.opt_pow(0), SOME_1);
+        assert_eq!(SOME_2.opt_pow(10), SOME_1024);
+        assert_eq!(SOME_2.opt_pow(Some(63u32)), SOME_HIGH_BIT);
+        assert_eq!(SOME_2.opt_pow(&Some(10u32)), SOME_1024);
+        assert_eq!(MY_2.opt_pow(Option::<u32>::None), NONE);
+        assert_eq!(NONE.opt_pow(10), NONE);
+        assert_eq!(NONE.opt_pow(Some(10u32)), NONE);
+
+        assert_eq!(Some(3i32).opt_pow(Some(3u32)), Some(27));
+        assert_eq!(Some(-2i8).opt_pow(7), Some(i8::MIN));
+    }
+
+    impl OptionCheckedPow<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_pow(self, rhs: u32) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_pow(rhs).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    #[test]
+    fn checked_pow() {
+        assert_eq!(MY_2.opt_checked_pow(10), Ok(SOME_1024));
+        assert_eq!(MY_2.opt_checked_pow(Some(10u32)), Ok(SOME_1024));
+        assert_eq!(MY_2.opt_checked_pow(&Some(1u32)), Ok(SOME_2));
+        assert_eq!(MY_2.opt_checked_pow(64), Err(Error::Overflow));
+
+        assert_eq!(SOME_2.opt_checked_pow(63), Ok(SOME_HIGH_BIT));
+        assert_eq!(SOME_2.opt_checked_pow(Some(10u32)), Ok(SOME_1024));
+        assert_eq!(SOME_2.opt_checked_pow(&Some(1u32)), Ok(SOME_2));
+
+        assert_eq!(SOME_2.opt_checked_pow(Some(64u32)), Err(Error::Overflow));
+        assert_eq!(SOME_2.opt_checked_pow(&Some(64u32)), Err(Error::Overflow));
+        assert_eq!(MY_2.opt_checked_pow(Option::<u32>::None), Ok(None));
+        assert_eq!(NONE.opt_checked_pow(Some(64u32)), Ok(None));
+
+        assert_eq!(Some(10u8).opt_checked_pow(Some(2u32)), Ok(Some(100)));
+        assert_eq!(Some(10u8).opt_checked_pow(Some(3u32)), Err(Error::Overflow));
+        assert_eq!(Some(-2i8).opt_checked_pow(Some(7u32)), Ok(Some(i8::MIN)));
+        assert_eq!(Some(2i8).opt_checked_pow(Some(7u32)), Err(Error::Overflow));
+    }
+
+    impl OptionOverflowingPow<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_pow(self, rhs: u32) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_pow(rhs)
+                .map(|(val, flag)| (MyInt(val), flag))
+        }
+    }
+
+    #[test]
+    fn overflowing_pow() {
+        assert_eq!(MY_2.opt_overflowing_pow(10), Some((MY_1024, false)));
+        assert_eq!(MY_2.opt_overflowing_pow(63), Some((MY_HIGH_BIT, false)));
+        assert_eq!(MY_2.opt_overflowing_pow(64), Some((MY_0, true)));
+        assert_eq!(SOME_2.opt_overflowing_pow(Some(64u32)), Some((MY_0, true)));
+        assert_eq!(
+            SOME_2.opt_overflowing_pow(&Some(10u32)),
+            Some((MY_1024, false))
+        );
+        assert_eq!(MY_2.opt_overflowing_pow(Option::<u32>::None), None);
+        assert_eq!(NONE.opt_overflowing_pow(64), None);
+    }
+
+    impl OptionSaturatingPow<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_saturating_pow(self, rhs: u32) -> Option<Self::Output> {
+            self.0.opt_saturating_pow(rhs).map(MyInt)
+        }
+    }
+
+    #[test]
+    fn saturating_pow() {
+        assert_eq!(MY_2.opt_saturating_pow(10), SOME_1024);
+        assert_eq!(MY_2.opt_saturating_pow(64), Some(MyInt(u64::MAX)));
+        assert_eq!(SOME_2.opt_saturating_pow(63), SOME_HIGH_BIT);
+        assert_eq!(
+            SOME_2.opt_saturating_pow(Some(64u32)),
+            Some(MyInt(u64::MAX))
+        );
+        assert_eq!(SOME_2.opt_saturating_pow(&Some(10u32)), SOME_1024);
+        assert_eq!(MY_2.opt_saturating_pow(Option::<u32>::None), None);
+        assert_eq!(NONE.opt_saturating_pow(64), None);
+
+        assert_eq!(Some(10u8).opt_saturating_pow(Some(3u32)), Some(u8::MAX));
+        assert_eq!(Some(-2i8).opt_saturating_pow(Some(7u32)), Some(i8::MIN));
+        assert_eq!(Some(-2i8).opt_saturating_pow(Some(9u32)), Some(i8::MIN));
+        assert_eq!(Some(-2i8).opt_saturating_pow(Some(8u32)), Some(i8::MAX));
+    }
+
+    impl OptionWrappingPow<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_pow(self, rhs: u32) -> Option<Self::Output> {
+            self.0.opt_wrapping_pow(rhs).map(MyInt)
+        }
+    }
+
+    #[test]
+    fn wrapping_pow() {
+        assert_eq!(MY_2.opt_wrapping_pow(10), SOME_1024);
+        assert_eq!(MY_2.opt_wrapping_pow(64), Some(MY_0));
+        assert_eq!(SOME_2.opt_wrapping_pow(63), SOME_HIGH_BIT);
+        assert_eq!(SOME_2.opt_wrapping_pow(Some(64u32)), Some(MY_0));
+        assert_eq!(SOME_2.opt_wrapping_pow(&Some(10u32)), SOME_1024);
+        assert_eq!(MY_2.opt_wrapping_pow(Option::<u32>::None), None);
+        assert_eq!(NONE.opt_wrapping_pow(64), None);
+
+        assert_eq!(Some(3u8).opt_wrapping_pow(Some(6u32)), Some(217));
+    }
+}
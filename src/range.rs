@@ -0,0 +1,279 @@
+//! Extension traits for ranges and `Option`s.
+
+use core::ops::{RangeBounds, RangeInclusive};
+
+use crate::OptionOperations;
+
+/// Extension trait to test whether an `Option` is contained in a range.
+///
+/// This trait is auto-implemented for all the ranges of
+/// [`OptionOperations`] types implementing `PartialOrd`.
+pub trait OptionRangeContains<T> {
+    /// Tests whether `value` is contained in the range.
+    ///
+    /// Returns `None` if `value` is `None`, which allows distinguishing
+    /// a missing value from a value out of the range:
+    ///
+    /// ```
+    /// # use option_operations::OptionRangeContains;
+    /// assert_eq!((0..=255).opt_contains(Some(42)), Some(true));
+    /// assert_eq!((0..=255).opt_contains(Some(300)), Some(false));
+    /// assert_eq!((0..=255).opt_contains(None), None);
+    /// assert_eq!((0.0..1.0).opt_contains(1.0), Some(false));
+    /// ```
+    #[must_use]
+    fn opt_contains(&self, value: impl Into<Option<T>>) -> Option<bool>;
+}
+
+impl<T, R> OptionRangeContains<T> for R
+where
+    T: OptionOperations + PartialOrd,
+    R: RangeBounds<T> + ?Sized,
+{
+    #[inline]
+    fn opt_contains(&self, value: impl Into<Option<T>>) -> Option<bool> {
+        value.into().map(|value| self.contains(&value))
+    }
+}
+
+/// Trait for the values which can be mapped from one range to another
+/// with [`OptionMapRange`].
+///
+/// The mapping is computed with `f64`s, so integers with more than 53
+/// significant bits lose precision.
+pub trait MapRangeValue: Sized {
+    /// Converts `self` to an `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Converts `value` back to `Self`.
+    ///
+    /// Integer implementations round to the nearest integer, with ties
+    /// rounded away from zero, and saturate at the bounds of the type.
+    fn from_f64(value: f64) -> Self;
+}
+
+impl_for_ints!(MapRangeValue, {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        // `as` truncates towards zero and saturates
+        if value < 0.0 {
+            (value - 0.5) as Self
+        } else {
+            (value + 0.5) as Self
+        }
+    }
+});
+
+impl MapRangeValue for f32 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.into()
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl MapRangeValue for f64 {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    #[inline]
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// Trait for values and `Option`s which can be linearly mapped from
+/// one range to another.
+///
+/// Implementing this type leads to the following auto-implementations:
+///
+/// - `OptionMapRange<T> for T`.
+/// - `OptionMapRange<T> for Option<T>`.
+///
+/// This trait is auto-implemented for [`OptionOperations`] types
+/// implementing [`MapRangeValue`].
+pub trait OptionMapRange<T> {
+    /// Linearly maps `self` from the range `from` to the range `to`.
+    ///
+    /// The start of `from` maps to the start of `to` and the end of `from`
+    /// maps to the end of `to`. The ranges can be decreasing, e.g.
+    /// `100.0..=0.0`, and values outside of `from` are extrapolated.
+    /// Use [`OptionClampToRange`](crate::OptionClampToRange) to restrict
+    /// the result to `to` if needed.
+    ///
+    /// If `from` is a single point, i.e. its start equals its end,
+    /// the start of `to` is returned.
+    ///
+    /// Returns `None` if `self` is `None`.
+    ///
+    /// ```
+    /// # use option_operations::OptionMapRange;
+    /// assert_eq!(Some(5).opt_map_range(0..=10, 0.0..=100.0), Some(50.0));
+    /// assert_eq!(Some(0.5).opt_map_range(0.0..=1.0, 0u8..=255), Some(128));
+    /// assert_eq!(Some(3).opt_map_range(3..=3, 0.0..=1.0), Some(0.0));
+    /// assert_eq!(Option::<i32>::None.opt_map_range(0..=10, 0.0..=1.0), None);
+    /// ```
+    #[must_use]
+    fn opt_map_range<U>(self, from: RangeInclusive<T>, to: RangeInclusive<U>) -> Option<U>
+    where
+        U: MapRangeValue;
+}
+
+fn map_range<T, U>(value: T, from: RangeInclusive<T>, to: RangeInclusive<U>) -> U
+where
+    T: MapRangeValue,
+    U: MapRangeValue,
+{
+    let (from_start, from_end) = from.into_inner();
+    let (from_start, from_end) = (from_start.to_f64(), from_end.to_f64());
+    let (to_start, to_end) = to.into_inner();
+
+    if from_start == from_end {
+        return to_start;
+    }
+
+    let (to_start, to_end) = (to_start.to_f64(), to_end.to_f64());
+    let ratio = (value.to_f64() - from_start) / (from_end - from_start);
+
+    U::from_f64(to_start + ratio * (to_end - to_start))
+}
+
+impl<T> OptionMapRange<T> for T
+where
+    T: OptionOperations + MapRangeValue,
+{
+    #[inline]
+    fn opt_map_range<U>(self, from: RangeInclusive<T>, to: RangeInclusive<U>) -> Option<U>
+    where
+        U: MapRangeValue,
+    {
+        Some(map_range(self, from, to))
+    }
+}
+
+impl<T> OptionMapRange<T> for Option<T>
+where
+    T: OptionOperations + MapRangeValue,
+{
+    #[inline]
+    fn opt_map_range<U>(self, from: RangeInclusive<T>, to: RangeInclusive<U>) -> Option<U>
+    where
+        U: MapRangeValue,
+    {
+        self.map(|inner_self| map_range(inner_self, from, to))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MapRangeValue, OptionMapRange, OptionRangeContains};
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn contains_my() {
+        assert_eq!((MY_0..=MY_1).opt_contains(SOME_1), Some(true));
+        assert_eq!((MY_0..=MY_1).opt_contains(MY_1), Some(true));
+        assert_eq!((MY_0..=MY_1).opt_contains(SOME_2), Some(false));
+        assert_eq!((MY_0..MY_1).opt_contains(SOME_1), Some(false));
+        assert_eq!((MY_0..MY_1).opt_contains(SOME_0), Some(true));
+        assert_eq!((MY_1..).opt_contains(SOME_0), Some(false));
+        assert_eq!((..=MY_1).opt_contains(SOME_0), Some(true));
+        assert_eq!((MY_0..=MY_1).opt_contains(NONE), None);
+    }
+
+    #[test]
+    fn contains_inclusive_and_half_open() {
+        let byte = 0..=255;
+        assert_eq!(byte.opt_contains(Some(0)), Some(true));
+        assert_eq!(byte.opt_contains(Some(255)), Some(true));
+        assert_eq!(byte.opt_contains(Some(256)), Some(false));
+        assert_eq!(byte.opt_contains(Some(-1)), Some(false));
+        assert_eq!(byte.opt_contains(None), None);
+
+        let half_open = 0..256;
+        assert_eq!(half_open.opt_contains(Some(255)), Some(true));
+        assert_eq!(half_open.opt_contains(Some(256)), Some(false));
+        assert_eq!(half_open.opt_contains(None), None);
+
+        assert_eq!((0.0..1.0).opt_contains(Some(0.5)), Some(true));
+        assert_eq!((0.0..1.0).opt_contains(Some(1.0)), Some(false));
+        assert_eq!((0.0..=1.0).opt_contains(Some(1.0)), Some(true));
+        assert_eq!((0.0..=1.0).opt_contains(Some(f64::NAN)), Some(false));
+    }
+
+    impl MapRangeValue for MyInt {
+        fn to_f64(self) -> f64 {
+            self.0.to_f64()
+        }
+
+        fn from_f64(value: f64) -> Self {
+            MyInt(u64::from_f64(value))
+        }
+    }
+
+    #[test]
+    fn map_range_my() {
+        assert_eq!(MY_1.opt_map_range(MY_0..=MY_2, 0.0..=1.0), Some(0.5));
+        assert_eq!(
+            SOME_2.opt_map_range(MY_0..=MY_2, MY_0..=MyInt(10)),
+            Some(MyInt(10))
+        );
+        assert_eq!(Some(0.25).opt_map_range(0.0..=1.0, MY_0..=MY_2), SOME_1);
+        assert_eq!(NONE.opt_map_range(MY_0..=MY_2, 0.0..=1.0), None);
+    }
+
+    #[test]
+    fn map_range() {
+        assert_eq!(Some(5).opt_map_range(0..=10, 0.0..=100.0), Some(50.0));
+        assert_eq!(Some(0).opt_map_range(0..=10, 0.0..=100.0), Some(0.0));
+        assert_eq!(Some(10).opt_map_range(0..=10, 0.0..=100.0), Some(100.0));
+        assert_eq!(Some(15).opt_map_range(0..=10, 0.0..=100.0), Some(150.0));
+        assert_eq!(Option::<i32>::None.opt_map_range(0..=10, 0.0..=100.0), None);
+
+        // Decreasing ranges
+        assert_eq!(Some(0.25).opt_map_range(0.0..=1.0, 100.0..=0.0), Some(75.0));
+        assert_eq!(Some(2.0f32).opt_map_range(4.0..=0.0, 0.0..=1.0), Some(0.5));
+
+        // Zero width
+        assert_eq!(Some(3).opt_map_range(3..=3, 0.0..=1.0), Some(0.0));
+        assert_eq!(Some(7).opt_map_range(3..=3, 10u8..=20), Some(10));
+    }
+
+    #[test]
+    fn map_range_int_rounding() {
+        assert_eq!(Some(1).opt_map_range(0..=3, 0u8..=255), Some(85));
+        assert_eq!(Some(2).opt_map_range(0..=3, 0u8..=255), Some(170));
+        assert_eq!(Some(0.45).opt_map_range(0.0..=1.0, 0..=10), Some(5));
+        assert_eq!(Some(0.44).opt_map_range(0.0..=1.0, 0..=10), Some(4));
+        assert_eq!(Some(-0.45).opt_map_range(0.0..=1.0, 0..=10), Some(-5));
+        assert_eq!(Some(-0.44).opt_map_range(0.0..=1.0, 0..=10), Some(-4));
+
+        // Saturation at the bounds of the output type
+        assert_eq!(Some(2.0).opt_map_range(0.0..=1.0, 0u8..=255), Some(255));
+        assert_eq!(Some(-1.0).opt_map_range(0.0..=1.0, 0u8..=255), Some(0));
+    }
+}
@@ -0,0 +1,67 @@
+//! Traits for the reciprocal [`OptionOperations`].
+
+use crate::{Error, OptionOperations};
+
+option_unary_op_custom!(
+    Reciprocal,
+    reciprocal,
+    "reciprocal",
+    "
+Float implementations return an infinite value if `self` is zero.
+",
+);
+
+impl_for_floats!(OptionReciprocal, {
+    type Output = Self;
+    #[inline]
+    fn opt_reciprocal(self) -> Option<Self::Output> {
+        Some(self.recip())
+    }
+});
+
+option_unary_op_checked!(
+    Reciprocal,
+    reciprocal,
+    "reciprocal",
+    "- Returns `Err(Error::DivisionByZero)` if `self` is zero.",
+);
+
+impl_for_floats!(OptionCheckedReciprocal, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_reciprocal(self) -> Result<Option<Self::Output>, Error> {
+        if self == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Some(self.recip()))
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const NONE: Option<f64> = None;
+
+    #[test]
+    fn reciprocal() {
+        assert_eq!(2.0f64.opt_reciprocal(), Some(0.5));
+        assert_eq!(Some(-4.0f32).opt_reciprocal(), Some(-0.25));
+        assert_eq!(Some(0.0f64).opt_reciprocal(), Some(f64::INFINITY));
+        assert_eq!(Some(-0.0f64).opt_reciprocal(), Some(f64::NEG_INFINITY));
+        assert_eq!(NONE.opt_reciprocal(), None);
+    }
+
+    #[test]
+    fn checked_reciprocal() {
+        assert_eq!(2.0f64.opt_checked_reciprocal(), Ok(Some(0.5)));
+        assert_eq!(Some(-4.0f32).opt_checked_reciprocal(), Ok(Some(-0.25)));
+        assert_eq!(Some(f64::INFINITY).opt_checked_reciprocal(), Ok(Some(0.0)));
+        assert_eq!(0.0f64.opt_checked_reciprocal(), Err(Error::DivisionByZero));
+        assert_eq!(
+            Some(-0.0f64).opt_checked_reciprocal(),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(NONE.opt_checked_reciprocal(), Ok(None));
+    }
+}
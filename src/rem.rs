@@ -17,6 +17,7 @@ Most implementations will panic if `rhs` is zero.
 
 impl_for_ints!(OptionOverflowingRem, {
     type Output = Self;
+    #[inline]
     fn opt_overflowing_rem(self, rhs: Self) -> Option<(Self::Output, bool)> {
         Some(self.overflowing_rem(rhs))
     }
@@ -24,6 +25,7 @@ impl_for_ints!(OptionOverflowingRem, {
 
 impl_for_ints!(OptionWrappingRem, {
     type Output = Self;
+    #[inline]
     fn opt_wrapping_rem(self, rhs: Self) -> Option<Self::Output> {
         Some(self.wrapping_rem(rhs))
     }
@@ -38,6 +40,7 @@ option_op_checked!(
 
 impl_for_ints!(OptionCheckedRem, {
     type Output = Self;
+    #[inline]
     fn opt_checked_rem(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
         if rhs == 0 {
             return Err(Error::DivisionByZero);
@@ -46,6 +49,83 @@ impl_for_ints!(OptionCheckedRem, {
     }
 });
 
+impl_for_floats!(OptionCheckedRem, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_rem(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        if rhs == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Some(self.rem(rhs)))
+    }
+});
+
+/// Builds a [`Duration`](core::time::Duration) from a number of nanoseconds.
+///
+/// `nanos` must fit in a `Duration`, which is the case for a remainder.
+#[cfg(feature = "duration")]
+fn duration_from_nanos(nanos: u128) -> core::time::Duration {
+    const NANOS_PER_SEC: u128 = 1_000_000_000;
+    core::time::Duration::new(
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
+
+#[cfg(feature = "duration")]
+impl OptionCheckedRem<u32> for core::time::Duration {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_rem(self, rhs: u32) -> Result<Option<Self::Output>, Error> {
+        if rhs == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Some(duration_from_nanos(self.as_nanos() % u128::from(rhs))))
+    }
+}
+
+#[cfg(feature = "duration")]
+impl OptionCheckedRem for core::time::Duration {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_rem(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        if rhs.is_zero() {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Some(duration_from_nanos(self.as_nanos() % rhs.as_nanos())))
+    }
+}
+
+option_op_checked_assign!(
+    Rem,
+    rem,
+    remainder,
+    "- Returns `Err(Error::Overflow)` if an overflow occured.",
+    "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero.",
+);
+
+impl_for_ints!(OptionCheckedRemAssign, {
+    #[inline]
+    fn opt_checked_rem_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        if rhs == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        *self = self.checked_rem(rhs).ok_or(Error::Overflow)?;
+        Ok(())
+    }
+});
+
+impl_for_floats!(OptionCheckedRemAssign, {
+    #[inline]
+    fn opt_checked_rem_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        if rhs == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        *self = self.rem(rhs);
+        Ok(())
+    }
+});
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -231,22 +311,22 @@ mod test {
         some.opt_rem_assign(Some(0));
     }
 
-    #[test]
-    fn checked_rem() {
-        impl OptionCheckedRem for MyInt {
-            type Output = MyInt;
-            fn opt_checked_rem(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
-                self.0.opt_checked_rem(rhs.0).map(|ok| ok.map(MyInt))
-            }
+    impl OptionCheckedRem for MyInt {
+        type Output = MyInt;
+        fn opt_checked_rem(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_rem(rhs.0).map(|ok| ok.map(MyInt))
         }
+    }
 
-        impl OptionCheckedRem<i64> for MyInt {
-            type Output = MyInt;
-            fn opt_checked_rem(self, rhs: i64) -> Result<Option<Self::Output>, Error> {
-                self.0.opt_checked_rem(rhs).map(|ok| ok.map(MyInt))
-            }
+    impl OptionCheckedRem<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_rem(self, rhs: i64) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_rem(rhs).map(|ok| ok.map(MyInt))
         }
+    }
 
+    #[test]
+    fn checked_rem() {
         assert_eq!(MY_2.opt_checked_rem(MY_1), Ok(SOME_0));
         assert_eq!(MY_5.opt_checked_rem(SOME_2), Ok(SOME_1));
         assert_eq!(MY_0.opt_checked_rem(&SOME_1), Ok(SOME_0));
@@ -274,26 +354,125 @@ mod test {
         assert_eq!(NONE.opt_checked_rem(SOME_MIN), Ok(None));
     }
 
+    impl OptionCheckedRemAssign for MyInt {
+        fn opt_checked_rem_assign(&mut self, rhs: MyInt) -> Result<(), Error> {
+            self.0.opt_checked_rem_assign(rhs.0)
+        }
+    }
+
+    impl OptionCheckedRemAssign<i64> for MyInt {
+        fn opt_checked_rem_assign(&mut self, rhs: i64) -> Result<(), Error> {
+            self.0.opt_checked_rem_assign(rhs)
+        }
+    }
+
     #[test]
-    fn overflowing_rem() {
-        impl OptionOverflowingRem for MyInt {
-            type Output = MyInt;
-            fn opt_overflowing_rem(self, rhs: MyInt) -> Option<(Self::Output, bool)> {
-                self.0
-                    .opt_overflowing_rem(rhs.0)
-                    .map(|(val, flag)| (MyInt(val), flag))
-            }
+    fn checked_rem_assign() {
+        let mut my = MY_5;
+        assert_eq!(my.opt_checked_rem_assign(MY_2), Ok(()));
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_10;
+        assert_eq!(my.opt_checked_rem_assign(&SOME_5), Ok(()));
+        assert_eq!(my, MY_0);
+
+        let mut my = MY_5;
+        assert_eq!(my.opt_checked_rem_assign(MY_0), Err(Error::DivisionByZero));
+        assert_eq!(my, MY_5);
+
+        let mut my = MY_MIN;
+        assert_eq!(my.opt_checked_rem_assign(-1), Err(Error::Overflow));
+        assert_eq!(my, MY_MIN);
+
+        let mut my = MY_5;
+        assert_eq!(my.opt_checked_rem_assign(NONE), Ok(()));
+        assert_eq!(my, MY_5);
+
+        let mut some = SOME_5;
+        assert_eq!(some.opt_checked_rem_assign(Some(2)), Ok(()));
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_5;
+        assert_eq!(
+            some.opt_checked_rem_assign(SOME_0),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(some, SOME_5);
+
+        let mut some = SOME_MAX;
+        assert_eq!(
+            some.opt_checked_rem_assign(&Some(0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(some, SOME_MAX);
+
+        let mut some = SOME_5;
+        assert_eq!(some.opt_checked_rem_assign(NONE), Ok(()));
+        assert_eq!(some, SOME_5);
+
+        let mut none = NONE;
+        assert_eq!(none.opt_checked_rem_assign(SOME_0), Ok(()));
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    fn checked_rem_assign_float() {
+        let mut some = Some(7.5f64);
+        assert_eq!(some.opt_checked_rem_assign(2.0), Ok(()));
+        assert_eq!(some, Some(1.5));
+
+        let mut some = Some(7.5f64);
+        assert_eq!(
+            some.opt_checked_rem_assign(Some(0.0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(some, Some(7.5));
+    }
+
+    #[test]
+    fn checked_rem_float() {
+        assert_eq!(Some(7.5f64).opt_checked_rem(Some(2.0)), Ok(Some(1.5)));
+        // Truncated remainder: the result has the sign of `self`.
+        assert_eq!((-7.5f64).opt_checked_rem(2.0), Ok(Some(-1.5)));
+        assert_eq!(Some(7.5f32).opt_checked_rem(&Some(-2.0)), Ok(Some(1.5)));
+        assert_eq!(Some(1.0f64).opt_rem(Some(0.0)).map(f64::is_nan), Some(true));
+
+        assert_eq!(
+            Some(1.0f64).opt_checked_rem(0.0),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            Some(1.0f64).opt_checked_rem(-0.0),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            Some(f32::INFINITY).opt_checked_rem(Some(0.0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(Some(1.0f64).opt_checked_rem(Option::<f64>::None), Ok(None));
+        assert_eq!(Option::<f64>::None.opt_checked_rem(0.0), Ok(None));
+    }
+
+    impl OptionOverflowingRem for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_rem(self, rhs: MyInt) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_rem(rhs.0)
+                .map(|(val, flag)| (MyInt(val), flag))
         }
+    }
 
-        impl OptionOverflowingRem<i64> for MyInt {
-            type Output = MyInt;
-            fn opt_overflowing_rem(self, rhs: i64) -> Option<(Self::Output, bool)> {
-                self.0
-                    .opt_overflowing_rem(rhs)
-                    .map(|(val, flag)| (MyInt(val), flag))
-            }
+    impl OptionOverflowingRem<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_rem(self, rhs: i64) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_rem(rhs)
+                .map(|(val, flag)| (MyInt(val), flag))
         }
+    }
 
+    #[test]
+    fn overflowing_rem() {
         assert_eq!(MY_2.opt_overflowing_rem(MY_1), Some((MY_0, false)));
         assert_eq!(MY_0.opt_overflowing_rem(MY_1), Some((MY_0, false)));
         assert_eq!(MY_MAX.opt_overflowing_rem(MY_2), Some((MY_1, false)));
@@ -311,22 +490,57 @@ mod test {
         assert_eq!(NONE.opt_overflowing_rem(MY_MIN), None);
     }
 
-    #[test]
-    fn wrapping_rem() {
-        impl OptionWrappingRem for MyInt {
-            type Output = MyInt;
-            fn opt_wrapping_rem(self, rhs: MyInt) -> Option<Self::Output> {
-                self.0.opt_wrapping_rem(rhs.0).map(MyInt)
-            }
+    impl OptionWrappingRem for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_rem(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_wrapping_rem(rhs.0).map(MyInt)
         }
+    }
 
-        impl OptionWrappingRem<i64> for MyInt {
-            type Output = MyInt;
-            fn opt_wrapping_rem(self, rhs: i64) -> Option<Self::Output> {
-                self.0.opt_wrapping_rem(rhs).map(MyInt)
-            }
+    impl OptionWrappingRem<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_rem(self, rhs: i64) -> Option<Self::Output> {
+            self.0.opt_wrapping_rem(rhs).map(MyInt)
         }
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn checked_rem_duration() {
+        use core::time::Duration;
+
+        let secs_7 = Duration::from_secs(7);
+        let secs_2 = Duration::from_secs(2);
 
+        assert_eq!(secs_7.opt_checked_rem(3), Ok(Some(Duration::from_nanos(1))));
+        assert_eq!(
+            Duration::from_millis(7_500).opt_checked_rem(Some(2u32)),
+            Ok(Some(Duration::ZERO))
+        );
+        assert_eq!(secs_7.opt_checked_rem(0), Err(Error::DivisionByZero));
+        assert_eq!(Some(secs_7).opt_checked_rem(Option::<u32>::None), Ok(None));
+
+        assert_eq!(
+            secs_7.opt_checked_rem(secs_2),
+            Ok(Some(Duration::from_secs(1)))
+        );
+        assert_eq!(
+            Some(Duration::from_millis(7_250)).opt_checked_rem(Some(secs_2)),
+            Ok(Some(Duration::from_millis(1_250)))
+        );
+        assert_eq!(
+            Duration::MAX.opt_checked_rem(Duration::from_secs(u64::MAX)),
+            Ok(Some(Duration::new(0, 999_999_999)))
+        );
+        assert_eq!(
+            secs_7.opt_checked_rem(&Some(Duration::ZERO)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(Option::<Duration>::None.opt_checked_rem(secs_2), Ok(None));
+    }
+
+    #[test]
+    fn wrapping_rem() {
         assert_eq!(MY_2.opt_wrapping_rem(MY_1), SOME_0);
         assert_eq!(MY_0.opt_wrapping_rem(MY_1), SOME_0);
         assert_eq!(MY_MAX.opt_wrapping_rem(MY_2), SOME_1);
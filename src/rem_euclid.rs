@@ -0,0 +1,374 @@
+//! Traits for the Euclidean remainder [`OptionOperations`].
+
+use crate::{Error, OptionOperations};
+
+option_op_custom!(
+    RemEuclid,
+    rem_euclid,
+    "Euclidean remainder",
+    "
+# Panics
+
+Most implementations will panic if `rhs` is zero.
+",
+);
+
+impl_for_ints!(OptionRemEuclid, {
+    type Output = Self;
+    #[inline]
+    fn opt_rem_euclid(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.rem_euclid(rhs))
+    }
+});
+
+// `rem_euclid` is not available for floats in `core`,
+// this is the same implementation as in `std`.
+macro_rules! float_rem_euclid {
+    ($lhs:expr, $rhs:expr) => {{
+        let res = $lhs % $rhs;
+        if res < 0.0 {
+            res + $rhs.abs()
+        } else {
+            res
+        }
+    }};
+}
+
+impl_for_floats!(OptionRemEuclid, {
+    type Output = Self;
+    #[inline]
+    fn opt_rem_euclid(self, rhs: Self) -> Option<Self::Output> {
+        Some(float_rem_euclid!(self, rhs))
+    }
+});
+
+option_op_assign_custom!(
+    RemEuclid,
+    rem_euclid,
+    "Euclidean remainder",
+    "
+# Panics
+
+Most implementations will panic if `rhs` is zero.
+",
+);
+
+impl_for_ints!(OptionRemEuclidAssign, {
+    #[inline]
+    fn opt_rem_euclid_assign(&mut self, rhs: Self) {
+        *self = self.rem_euclid(rhs);
+    }
+});
+
+impl_for_floats!(OptionRemEuclidAssign, {
+    #[inline]
+    fn opt_rem_euclid_assign(&mut self, rhs: Self) {
+        *self = float_rem_euclid!(*self, rhs);
+    }
+});
+
+option_op_checked!(
+    RemEuclid,
+    rem_euclid,
+    "Euclidean remainder",
+    "- Returns `Err(Error::DivisionByZero)` if `rhs` is zero.",
+);
+
+impl_for_ints!(OptionCheckedRemEuclid, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_rem_euclid(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        if rhs == 0 {
+            return Err(Error::DivisionByZero);
+        }
+        self.checked_rem_euclid(rhs)
+            .ok_or(Error::Overflow)
+            .map(Some)
+    }
+});
+
+impl_for_floats!(OptionCheckedRemEuclid, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_rem_euclid(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        if rhs == 0.0 {
+            return Err(Error::DivisionByZero);
+        }
+        Ok(Some(float_rem_euclid!(self, rhs)))
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionRemEuclid for MyInt {
+        type Output = MyInt;
+        fn opt_rem_euclid(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_rem_euclid(rhs.0).map(MyInt)
+        }
+    }
+
+    impl OptionRemEuclid<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_rem_euclid(self, rhs: i64) -> Option<Self::Output> {
+            self.0.opt_rem_euclid(rhs).map(MyInt)
+        }
+    }
+
+    impl OptionRemEuclidAssign for MyInt {
+        fn opt_rem_euclid_assign(&mut self, rhs: MyInt) {
+            self.0.opt_rem_euclid_assign(rhs.0)
+        }
+    }
+
+    impl OptionRemEuclidAssign<i64> for MyInt {
+        fn opt_rem_euclid_assign(&mut self, rhs: i64) {
+            self.0.opt_rem_euclid_assign(rhs)
+        }
+    }
+
+    impl OptionCheckedRemEuclid for MyInt {
+        type Output = MyInt;
+        fn opt_checked_rem_euclid(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_rem_euclid(rhs.0).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    impl OptionCheckedRemEuclid<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_rem_euclid(self, rhs: i64) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_rem_euclid(rhs).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    const MY_MINUS_7: MyInt = MyInt(-7);
+    const MY_MINUS_4: MyInt = MyInt(-4);
+    const MY_MINUS_1: MyInt = MyInt(-1);
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_3: MyInt = MyInt(3);
+    const MY_4: MyInt = MyInt(4);
+    const MY_7: MyInt = MyInt(7);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const SOME_MINUS_7: Option<MyInt> = Some(MY_MINUS_7);
+    const SOME_MINUS_4: Option<MyInt> = Some(MY_MINUS_4);
+    const SOME_MINUS_1: Option<MyInt> = Some(MY_MINUS_1);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const SOME_4: Option<MyInt> = Some(MY_4);
+    const SOME_7: Option<MyInt> = Some(MY_7);
+    const SOME_MIN: Option<MyInt> = Some(MY_MIN);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn rem_euclid_my() {
+        assert_eq!(MY_7.opt_rem_euclid(MY_4), SOME_3);
+        assert_eq!(MY_MINUS_7.opt_rem_euclid(MY_4), SOME_1);
+        assert_eq!(MY_7.opt_rem_euclid(MY_MINUS_4), SOME_3);
+        assert_eq!(MY_MINUS_7.opt_rem_euclid(MY_MINUS_4), SOME_1);
+        assert_eq!(MY_MINUS_7.opt_rem_euclid(SOME_4), SOME_1);
+        assert_eq!(MY_MINUS_7.opt_rem_euclid(&SOME_4), SOME_1);
+        assert_eq!(SOME_MINUS_7.opt_rem_euclid(MY_4), SOME_1);
+        assert_eq!(SOME_MINUS_7.opt_rem_euclid(SOME_4), SOME_1);
+        assert_eq!(SOME_MINUS_7.opt_rem_euclid(&SOME_4), SOME_1);
+        assert_eq!(MY_7.opt_rem_euclid(NONE), NONE);
+        assert_eq!(NONE.opt_rem_euclid(MY_4), NONE);
+        assert_eq!(NONE.opt_rem_euclid(SOME_4), NONE);
+    }
+
+    #[test]
+    fn rem_euclid_i64() {
+        assert_eq!(MY_MINUS_7.opt_rem_euclid(4), SOME_1);
+        assert_eq!(MY_MINUS_7.opt_rem_euclid(Some(4)), SOME_1);
+        assert_eq!(SOME_7.opt_rem_euclid(-4), SOME_3);
+        assert_eq!(SOME_MINUS_7.opt_rem_euclid(&Some(-4)), SOME_1);
+        assert_eq!(MY_7.opt_rem_euclid(Option::<i64>::None), NONE);
+        assert_eq!(NONE.opt_rem_euclid(4), NONE);
+
+        assert_eq!(Some(-7i32).opt_rem_euclid(Some(4)), Some(1));
+        assert_eq!(Some(7u8).opt_rem_euclid(Some(4)), Some(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rem_euclid_by_zero_my() {
+        let _ = SOME_7.opt_rem_euclid(SOME_0);
+    }
+
+    #[test]
+    fn rem_euclid_assign_my() {
+        let mut my = MY_MINUS_7;
+        my.opt_rem_euclid_assign(MY_4);
+        assert_eq!(my, MY_1);
+
+        let mut some = SOME_7;
+        some.opt_rem_euclid_assign(MY_MINUS_4);
+        assert_eq!(some, SOME_3);
+
+        let mut my = MY_MINUS_7;
+        my.opt_rem_euclid_assign(SOME_4);
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_MINUS_7;
+        my.opt_rem_euclid_assign(&SOME_MINUS_4);
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_7;
+        my.opt_rem_euclid_assign(NONE);
+        assert_eq!(my, MY_7);
+
+        let mut some = SOME_MINUS_7;
+        some.opt_rem_euclid_assign(SOME_4);
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_MINUS_7;
+        some.opt_rem_euclid_assign(&SOME_MINUS_4);
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_7;
+        some.opt_rem_euclid_assign(NONE);
+        assert_eq!(some, SOME_7);
+
+        let mut none = NONE;
+        none.opt_rem_euclid_assign(SOME_4);
+        assert_eq!(none, NONE);
+
+        let mut none = NONE;
+        none.opt_rem_euclid_assign(NONE);
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rem_euclid_assign_by_zero_my() {
+        let mut some = SOME_7;
+        some.opt_rem_euclid_assign(SOME_0);
+    }
+
+    #[test]
+    fn rem_euclid_assign_i64() {
+        let mut my = MY_MINUS_7;
+        my.opt_rem_euclid_assign(4);
+        assert_eq!(my, MY_1);
+
+        let mut some = SOME_7;
+        some.opt_rem_euclid_assign(-4);
+        assert_eq!(some, SOME_3);
+
+        let mut my = MY_MINUS_7;
+        my.opt_rem_euclid_assign(Some(4));
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_MINUS_7;
+        my.opt_rem_euclid_assign(&Some(-4));
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_7;
+        my.opt_rem_euclid_assign(Option::<i64>::None);
+        assert_eq!(my, MY_7);
+
+        let mut some = SOME_MINUS_7;
+        some.opt_rem_euclid_assign(Some(4));
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_7;
+        some.opt_rem_euclid_assign(Option::<i64>::None);
+        assert_eq!(some, SOME_7);
+
+        let mut none = NONE;
+        none.opt_rem_euclid_assign(4);
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rem_euclid_assign_by_zero_i64() {
+        let mut some = SOME_7;
+        some.opt_rem_euclid_assign(Some(0));
+    }
+
+    #[test]
+    fn checked_rem_euclid() {
+        assert_eq!(MY_MINUS_7.opt_checked_rem_euclid(MY_4), Ok(SOME_1));
+        assert_eq!(MY_MINUS_7.opt_checked_rem_euclid(SOME_4), Ok(SOME_1));
+        assert_eq!(MY_MINUS_7.opt_checked_rem_euclid(&SOME_4), Ok(SOME_1));
+        assert_eq!(SOME_MINUS_7.opt_checked_rem_euclid(-4), Ok(SOME_1));
+        assert_eq!(SOME_7.opt_checked_rem_euclid(Some(4)), Ok(SOME_3));
+        assert_eq!(SOME_MINUS_7.opt_checked_rem_euclid(&Some(-4)), Ok(SOME_1));
+
+        assert_eq!(
+            MY_7.opt_checked_rem_euclid(MY_0),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            SOME_7.opt_checked_rem_euclid(Some(0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            MY_MIN.opt_checked_rem_euclid(MY_MINUS_1),
+            Err(Error::Overflow)
+        );
+        assert_eq!(
+            SOME_MIN.opt_checked_rem_euclid(SOME_MINUS_1),
+            Err(Error::Overflow)
+        );
+
+        assert_eq!(MY_7.opt_checked_rem_euclid(NONE), Ok(None));
+        assert_eq!(NONE.opt_checked_rem_euclid(SOME_0), Ok(None));
+    }
+
+    #[test]
+    fn rem_euclid_float() {
+        const ANGLES: [f64; 7] = [-725.5, -360.0, -90.25, -0.0, 45.0, 360.0, 1_000.75];
+
+        for angle in ANGLES {
+            let expected = angle.rem_euclid(360.0);
+            assert_eq!(angle.opt_rem_euclid(360.0), Some(expected));
+            assert_eq!(Some(angle).opt_rem_euclid(Some(360.0)), Some(expected));
+            assert_eq!(
+                Some(angle).opt_checked_rem_euclid(360.0),
+                Ok(Some(expected))
+            );
+
+            let expected = angle.rem_euclid(-360.0);
+            assert_eq!(Some(angle).opt_rem_euclid(&Some(-360.0)), Some(expected));
+
+            let mut some = Some(angle);
+            some.opt_rem_euclid_assign(360.0);
+            assert_eq!(some, Some(angle.rem_euclid(360.0)));
+        }
+
+        assert_eq!(Some(-7.5f32).opt_rem_euclid(2.0), Some(0.5));
+        assert_eq!(Some(-7.5f32).opt_rem_euclid(Option::<f32>::None), None);
+        assert_eq!(Option::<f64>::None.opt_rem_euclid(360.0), None);
+    }
+
+    #[test]
+    fn checked_rem_euclid_float() {
+        assert_eq!(
+            Some(-7.5f64).opt_checked_rem_euclid(2.0),
+            Ok(Some((-7.5f64).rem_euclid(2.0)))
+        );
+        assert_eq!(
+            Some(-7.5f64).opt_checked_rem_euclid(0.0),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(
+            Some(7.5f32).opt_checked_rem_euclid(&Some(-0.0)),
+            Err(Error::DivisionByZero)
+        );
+        assert_eq!(Option::<f64>::None.opt_checked_rem_euclid(0.0), Ok(None));
+        assert_eq!(
+            Some(1.0f64).opt_checked_rem_euclid(Option::<f64>::None),
+            Ok(None)
+        );
+    }
+}
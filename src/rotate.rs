@@ -0,0 +1,108 @@
+//! Traits for the bit rotation [`OptionOperations`].
+//!
+//! Contrary to shifts, rotations never overflow: the bits shifted out
+//! at one end are reinserted at the other end.
+
+use crate::OptionOperations;
+
+option_op_custom!(RotateLeft<u32>, rotate_left, "left rotation");
+
+impl_for_ints!(OptionRotateLeft<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_rotate_left(self, rhs: u32) -> Option<Self::Output> {
+        Some(self.rotate_left(rhs))
+    }
+});
+
+option_op_custom!(RotateRight<u32>, rotate_right, "right rotation");
+
+impl_for_ints!(OptionRotateRight<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_rotate_right(self, rhs: u32) -> Option<Self::Output> {
+        Some(self.rotate_right(rhs))
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u8);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionRotateLeft<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_rotate_left(self, rhs: u32) -> Option<Self::Output> {
+            self.0.opt_rotate_left(rhs).map(MyInt)
+        }
+    }
+
+    impl OptionRotateRight<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_rotate_right(self, rhs: u32) -> Option<Self::Output> {
+            self.0.opt_rotate_right(rhs).map(MyInt)
+        }
+    }
+
+    const MY_1: MyInt = MyInt(0b0000_0001);
+    const MY_2: MyInt = MyInt(0b0000_0010);
+    const MY_HIGH_BIT: MyInt = MyInt(0b1000_0000);
+    const MY_MIXED: MyInt = MyInt(0b1000_0011);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_HIGH_BIT: Option<MyInt> = Some(MY_HIGH_BIT);
+    const SOME_MIXED: Option<MyInt> = Some(MY_MIXED);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn rotate_left() {
+        assert_eq!(MY_1.opt_rotate_left(1), SOME_2);
+        assert_eq!(MY_HIGH_BIT.opt_rotate_left(1), SOME_1);
+        assert_eq!(
+            MY_MIXED.opt_rotate_left(Some(1u32)),
+            Some(MyInt(0b0000_0111))
+        );
+        assert_eq!(MY_1.opt_rotate_left(&Some(7u32)), SOME_HIGH_BIT);
+        assert_eq!(SOME_HIGH_BIT.opt_rotate_left(2), SOME_2);
+        assert_eq!(SOME_1.opt_rotate_left(Some(8u32)), SOME_1);
+        assert_eq!(SOME_1.opt_rotate_left(&Some(9u32)), SOME_2);
+
+        assert_eq!(MY_1.opt_rotate_left(Option::<u32>::None), None);
+        assert_eq!(SOME_1.opt_rotate_left(Option::<u32>::None), None);
+        assert_eq!(NONE.opt_rotate_left(1), None);
+        assert_eq!(NONE.opt_rotate_left(Some(1u32)), None);
+
+        let shift_opt = Some(4u32);
+        assert_eq!(Some(0x1234u16).opt_rotate_left(shift_opt), Some(0x2341));
+        assert_eq!(Some(-1i32).opt_rotate_left(shift_opt), Some(-1));
+        assert_eq!(Some(u64::MAX - 1).opt_rotate_left(64), Some(u64::MAX - 1));
+    }
+
+    #[test]
+    fn rotate_right() {
+        assert_eq!(MY_2.opt_rotate_right(1), SOME_1);
+        assert_eq!(MY_1.opt_rotate_right(1), SOME_HIGH_BIT);
+        assert_eq!(
+            MY_MIXED.opt_rotate_right(Some(1u32)),
+            Some(MyInt(0b1100_0001))
+        );
+        assert_eq!(MY_HIGH_BIT.opt_rotate_right(&Some(7u32)), SOME_1);
+        assert_eq!(SOME_2.opt_rotate_right(2), SOME_HIGH_BIT);
+        assert_eq!(SOME_MIXED.opt_rotate_right(Some(8u32)), SOME_MIXED);
+        assert_eq!(SOME_2.opt_rotate_right(&Some(9u32)), SOME_1);
+
+        assert_eq!(MY_1.opt_rotate_right(Option::<u32>::None), None);
+        assert_eq!(SOME_1.opt_rotate_right(&Option::<u32>::None), None);
+        assert_eq!(NONE.opt_rotate_right(1), None);
+
+        let shift_opt = Some(4u32);
+        assert_eq!(Some(0x1234u16).opt_rotate_right(shift_opt), Some(0x4123));
+        assert_eq!(Some(1i8).opt_rotate_right(shift_opt), Some(0x10));
+        assert_eq!(Some(1u128).opt_rotate_right(1), Some(1 << 127));
+    }
+}
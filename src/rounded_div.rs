@@ -0,0 +1,317 @@
+//! Traits for the rounded division [`OptionOperations`].
+
+use crate::OptionOperations;
+
+/// Rounding mode for [`OptionRoundedDiv`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RoundingMode {
+    /// Rounds toward zero, like the integer `/` operator.
+    TowardZero,
+    /// Rounds away from zero.
+    AwayFromZero,
+    /// Rounds to the nearest integer, ties away from zero.
+    Nearest,
+    /// Rounds to the nearest integer, ties to the nearest even integer.
+    NearestEven,
+    /// Rounds toward negative infinity.
+    Floor,
+    /// Rounds toward positive infinity.
+    Ceil,
+}
+
+impl RoundingMode {
+    /// Returns whether the truncated quotient must be moved one step
+    /// away from zero.
+    ///
+    /// - `is_negative`: whether the exact quotient is negative.
+    /// - `rem` & `rhs`: magnitudes of the non-zero remainder and divisor.
+    /// - `is_odd`: whether the truncated quotient is odd.
+    #[inline]
+    fn is_away<U: PartialOrd + core::ops::Sub<Output = U> + Copy>(
+        self,
+        is_negative: bool,
+        rem: U,
+        rhs: U,
+        is_odd: bool,
+    ) -> bool {
+        use RoundingMode::*;
+
+        match self {
+            TowardZero => false,
+            AwayFromZero => true,
+            Floor => is_negative,
+            Ceil => !is_negative,
+            Nearest => rem >= rhs - rem,
+            NearestEven => {
+                let other = rhs - rem;
+                rem > other || (rem == other && is_odd)
+            }
+        }
+    }
+}
+
+/// Trait for values and `Option`s rounded division.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionRoundedDiv<Option<InnerRhs>>` for `T`.
+/// - `OptionRoundedDiv<Rhs>` for `Option<T>`.
+/// - `OptionRoundedDiv<Option<InnerRhs>>` for `Option<T>`.
+/// - ... and some variants with references.
+///
+/// Note that since the `std` library doesn't define any `RoundedDiv` trait,
+/// users must provide the base implementation for the inner type.
+pub trait OptionRoundedDiv<Rhs = Self, InnerRhs = Rhs> {
+    /// The resulting inner type after applying the rounded division.
+    type Output;
+
+    /// Computes the division, rounding the quotient according to `mode`.
+    ///
+    /// Returns `None` if at least one argument is `None`.
+    ///
+    /// ```
+    /// # use option_operations::{OptionRoundedDiv, RoundingMode};
+    /// assert_eq!(Some(7).opt_rounded_div(Some(2), RoundingMode::TowardZero), Some(3));
+    /// assert_eq!(Some(7).opt_rounded_div(Some(2), RoundingMode::NearestEven), Some(4));
+    /// assert_eq!(Some(-7).opt_rounded_div(2, RoundingMode::Floor), Some(-4));
+    /// assert_eq!(Some(-7).opt_rounded_div(None, RoundingMode::Floor), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Most implementations will panic if `rhs` is zero.
+    #[must_use]
+    fn opt_rounded_div(self, rhs: Rhs, mode: RoundingMode) -> Option<Self::Output>;
+}
+
+impl<T, InnerRhs> OptionRoundedDiv<Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + OptionRoundedDiv<InnerRhs>,
+{
+    type Output = <T as OptionRoundedDiv<InnerRhs>>::Output;
+
+    #[inline]
+    fn opt_rounded_div(self, rhs: Option<InnerRhs>, mode: RoundingMode) -> Option<Self::Output> {
+        rhs.and_then(|inner_rhs| self.opt_rounded_div(inner_rhs, mode))
+    }
+}
+
+impl<T, InnerRhs> OptionRoundedDiv<&Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + OptionRoundedDiv<InnerRhs>,
+    InnerRhs: Copy,
+{
+    type Output = <T as OptionRoundedDiv<InnerRhs>>::Output;
+
+    #[inline]
+    fn opt_rounded_div(self, rhs: &Option<InnerRhs>, mode: RoundingMode) -> Option<Self::Output> {
+        rhs.as_ref()
+            .and_then(|inner_rhs| self.opt_rounded_div(*inner_rhs, mode))
+    }
+}
+
+impl<T, Rhs> OptionRoundedDiv<Rhs> for Option<T>
+where
+    T: OptionOperations + OptionRoundedDiv<Rhs>,
+{
+    type Output = <T as OptionRoundedDiv<Rhs>>::Output;
+
+    #[inline]
+    fn opt_rounded_div(self, rhs: Rhs, mode: RoundingMode) -> Option<Self::Output> {
+        self.and_then(|inner_self| inner_self.opt_rounded_div(rhs, mode))
+    }
+}
+
+impl<T, InnerRhs> OptionRoundedDiv<Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + OptionRoundedDiv<InnerRhs>,
+{
+    type Output = <T as OptionRoundedDiv<InnerRhs>>::Output;
+
+    #[inline]
+    fn opt_rounded_div(self, rhs: Option<InnerRhs>, mode: RoundingMode) -> Option<Self::Output> {
+        self.zip(rhs)
+            .and_then(|(inner_self, inner_rhs)| inner_self.opt_rounded_div(inner_rhs, mode))
+    }
+}
+
+impl<T, InnerRhs> OptionRoundedDiv<&Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + OptionRoundedDiv<InnerRhs>,
+    InnerRhs: Copy,
+{
+    type Output = <T as OptionRoundedDiv<InnerRhs>>::Output;
+
+    #[inline]
+    fn opt_rounded_div(self, rhs: &Option<InnerRhs>, mode: RoundingMode) -> Option<Self::Output> {
+        self.zip(rhs.as_ref())
+            .and_then(|(inner_self, inner_rhs)| inner_self.opt_rounded_div(*inner_rhs, mode))
+    }
+}
+
+impl_for_signed_ints!(OptionRoundedDiv, {
+    type Output = Self;
+    #[inline]
+    fn opt_rounded_div(self, rhs: Self, mode: RoundingMode) -> Option<Self::Output> {
+        let quotient = self / rhs;
+        let rem = self % rhs;
+        if rem == 0 {
+            return Some(quotient);
+        }
+
+        let is_negative = (self < 0) != (rhs < 0);
+        if mode.is_away(
+            is_negative,
+            rem.unsigned_abs(),
+            rhs.unsigned_abs(),
+            quotient & 1 != 0,
+        ) {
+            Some(if is_negative {
+                quotient - 1
+            } else {
+                quotient + 1
+            })
+        } else {
+            Some(quotient)
+        }
+    }
+});
+
+impl_for_unsigned_ints!(OptionRoundedDiv, {
+    type Output = Self;
+    #[inline]
+    fn opt_rounded_div(self, rhs: Self, mode: RoundingMode) -> Option<Self::Output> {
+        let quotient = self / rhs;
+        let rem = self % rhs;
+        if rem == 0 {
+            return Some(quotient);
+        }
+
+        if mode.is_away(false, rem, rhs, quotient & 1 != 0) {
+            Some(quotient + 1)
+        } else {
+            Some(quotient)
+        }
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::{OptionRoundedDiv, RoundingMode};
+    use crate::OptionOperations;
+
+    use RoundingMode::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionRoundedDiv for MyInt {
+        type Output = MyInt;
+        fn opt_rounded_div(self, rhs: MyInt, mode: RoundingMode) -> Option<Self::Output> {
+            self.0.opt_rounded_div(rhs.0, mode).map(MyInt)
+        }
+    }
+
+    impl OptionRoundedDiv<i64> for MyInt {
+        type Output = MyInt;
+        fn opt_rounded_div(self, rhs: i64, mode: RoundingMode) -> Option<Self::Output> {
+            self.0.opt_rounded_div(rhs, mode).map(MyInt)
+        }
+    }
+
+    const MY_MINUS_7: MyInt = MyInt(-7);
+    const MY_2: MyInt = MyInt(2);
+    const MY_7: MyInt = MyInt(7);
+    const SOME_MINUS_7: Option<MyInt> = Some(MY_MINUS_7);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_7: Option<MyInt> = Some(MY_7);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn rounded_div_my() {
+        assert_eq!(MY_7.opt_rounded_div(MY_2, TowardZero), Some(MyInt(3)));
+        assert_eq!(MY_7.opt_rounded_div(SOME_2, AwayFromZero), Some(MyInt(4)));
+        assert_eq!(MY_MINUS_7.opt_rounded_div(&SOME_2, Floor), Some(MyInt(-4)));
+        assert_eq!(SOME_MINUS_7.opt_rounded_div(MY_2, Ceil), Some(MyInt(-3)));
+        assert_eq!(SOME_7.opt_rounded_div(SOME_2, Nearest), Some(MyInt(4)));
+        assert_eq!(SOME_7.opt_rounded_div(&SOME_2, NearestEven), Some(MyInt(4)));
+        assert_eq!(SOME_7.opt_rounded_div(2, Floor), Some(MyInt(3)));
+        assert_eq!(SOME_7.opt_rounded_div(Some(-2), Floor), Some(MyInt(-4)));
+
+        assert_eq!(MY_7.opt_rounded_div(NONE, Nearest), NONE);
+        assert_eq!(NONE.opt_rounded_div(MY_2, Nearest), NONE);
+        assert_eq!(NONE.opt_rounded_div(SOME_2, Nearest), NONE);
+        assert_eq!(SOME_7.opt_rounded_div(Option::<i64>::None, Nearest), NONE);
+    }
+
+    #[test]
+    fn rounded_div_negative() {
+        // -7 / 2 = -3.5
+        assert_eq!((-7i32).opt_rounded_div(2, TowardZero), Some(-3));
+        assert_eq!((-7i32).opt_rounded_div(2, AwayFromZero), Some(-4));
+        assert_eq!((-7i32).opt_rounded_div(2, Nearest), Some(-4));
+        assert_eq!((-7i32).opt_rounded_div(2, NearestEven), Some(-4));
+        assert_eq!((-7i32).opt_rounded_div(2, Floor), Some(-4));
+        assert_eq!((-7i32).opt_rounded_div(2, Ceil), Some(-3));
+
+        // 7 / -2 = -3.5
+        assert_eq!(7i32.opt_rounded_div(-2, TowardZero), Some(-3));
+        assert_eq!(7i32.opt_rounded_div(-2, AwayFromZero), Some(-4));
+        assert_eq!(7i32.opt_rounded_div(-2, Nearest), Some(-4));
+        assert_eq!(7i32.opt_rounded_div(-2, NearestEven), Some(-4));
+        assert_eq!(7i32.opt_rounded_div(-2, Floor), Some(-4));
+        assert_eq!(7i32.opt_rounded_div(-2, Ceil), Some(-3));
+
+        // -7 / -2 = 3.5
+        assert_eq!((-7i32).opt_rounded_div(-2, TowardZero), Some(3));
+        assert_eq!((-7i32).opt_rounded_div(-2, AwayFromZero), Some(4));
+        assert_eq!((-7i32).opt_rounded_div(-2, Nearest), Some(4));
+        assert_eq!((-7i32).opt_rounded_div(-2, NearestEven), Some(4));
+        assert_eq!((-7i32).opt_rounded_div(-2, Floor), Some(3));
+        assert_eq!((-7i32).opt_rounded_div(-2, Ceil), Some(4));
+
+        // -5 / 2 = -2.5
+        assert_eq!((-5i32).opt_rounded_div(2, Nearest), Some(-3));
+        assert_eq!((-5i32).opt_rounded_div(2, NearestEven), Some(-2));
+
+        // -7 / 3 = -2.33
+        assert_eq!((-7i32).opt_rounded_div(3, TowardZero), Some(-2));
+        assert_eq!((-7i32).opt_rounded_div(3, AwayFromZero), Some(-3));
+        assert_eq!((-7i32).opt_rounded_div(3, Nearest), Some(-2));
+        assert_eq!((-7i32).opt_rounded_div(3, NearestEven), Some(-2));
+        assert_eq!((-7i32).opt_rounded_div(3, Floor), Some(-3));
+        assert_eq!((-7i32).opt_rounded_div(3, Ceil), Some(-2));
+
+        // -8 / 3 = -2.67
+        assert_eq!((-8i32).opt_rounded_div(3, Nearest), Some(-3));
+        assert_eq!((-8i32).opt_rounded_div(3, NearestEven), Some(-3));
+
+        // Exact division
+        assert_eq!((-8i32).opt_rounded_div(-4, AwayFromZero), Some(2));
+        assert_eq!((-8i32).opt_rounded_div(4, Ceil), Some(-2));
+
+        assert_eq!(i8::MIN.opt_rounded_div(i8::MIN, Nearest), Some(1));
+        assert_eq!(i8::MIN.opt_rounded_div(3, Nearest), Some(-43));
+        assert_eq!(i8::MAX.opt_rounded_div(i8::MIN, Nearest), Some(-1));
+    }
+
+    #[test]
+    fn rounded_div_unsigned() {
+        assert_eq!(Some(5u8).opt_rounded_div(Some(2), TowardZero), Some(2));
+        assert_eq!(Some(5u8).opt_rounded_div(Some(2), AwayFromZero), Some(3));
+        assert_eq!(Some(5u8).opt_rounded_div(Some(2), Nearest), Some(3));
+        assert_eq!(Some(5u8).opt_rounded_div(Some(2), NearestEven), Some(2));
+        assert_eq!(Some(5u8).opt_rounded_div(Some(2), Floor), Some(2));
+        assert_eq!(Some(5u8).opt_rounded_div(Some(2), Ceil), Some(3));
+        assert_eq!(Some(u8::MAX).opt_rounded_div(Some(254), Nearest), Some(1));
+        assert_eq!(Some(254u8).opt_rounded_div(Some(u8::MAX), Nearest), Some(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rounded_div_by_zero() {
+        let _ = SOME_7.opt_rounded_div(Some(0), Nearest);
+    }
+}
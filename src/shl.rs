@@ -0,0 +1,407 @@
+//! Traits for the left shift [`OptionOperations`].
+
+use core::ops::{Shl, ShlAssign};
+
+use crate::{Error, OptionOperations};
+
+common_option_op!(
+    Shl,
+    shl,
+    "left shift",
+    "
+# Panics
+
+Most implementations will panic if `rhs` is larger than or equal to
+the number of bits in `self`.
+",
+);
+
+impl_for_ints!(OptionOverflowingShl<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_overflowing_shl(self, rhs: u32) -> Option<(Self::Output, bool)> {
+        Some(self.overflowing_shl(rhs))
+    }
+});
+
+impl_for_ints!(OptionWrappingShl<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_wrapping_shl(self, rhs: u32) -> Option<Self::Output> {
+        Some(self.wrapping_shl(rhs))
+    }
+});
+
+option_op_assign_custom!(
+    WrappingShl<u32>,
+    wrapping_shl,
+    "wrapping left shift",
+    "
+`rhs` is masked to the number of bits in `self`: shifting by a count
+larger than or equal to the number of bits wraps the count around
+instead of panicking.",
+);
+
+impl_for_ints!(OptionWrappingShlAssign<u32>, {
+    #[inline]
+    fn opt_wrapping_shl_assign(&mut self, rhs: u32) {
+        *self = self.wrapping_shl(rhs);
+    }
+});
+
+option_op_checked!(
+    Shl,
+    shl,
+    "left shift",
+    "- Returns `Err(Error::ShiftOverflow)`, not `Error::Overflow`, if `rhs`
+is larger than or equal to the number of bits in `self`.
+
+Only the shift amount is checked: the bits shifted out of `self` are
+discarded without error. To scale a value by a power of two while
+detecting overflows, multiply it with [`OptionCheckedMul`](crate::OptionCheckedMul)
+instead:
+
+```
+# use option_operations::{Error, OptionCheckedMul, OptionCheckedShl};
+let frames = Some(1u64 << 62);
+
+assert_eq!(frames.opt_checked_shl(1), Ok(Some(1 << 63)));
+assert_eq!(frames.opt_checked_shl(2), Ok(Some(0)));
+assert_eq!(frames.opt_checked_shl(64), Err(Error::ShiftOverflow));
+
+assert_eq!(frames.opt_checked_mul(1u64 << 1), Ok(Some(1 << 63)));
+assert_eq!(frames.opt_checked_mul(1u64 << 2), Err(Error::Overflow));
+```",
+);
+
+impl_for_ints!(OptionCheckedShl<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_shl(self, rhs: u32) -> Result<Option<Self::Output>, Error> {
+        self.checked_shl(rhs).ok_or(Error::ShiftOverflow).map(Some)
+    }
+});
+
+option_op_checked_assign!(
+    Shl,
+    shl,
+    "left shift",
+    "- Returns `Err(Error::ShiftOverflow)` if `rhs` is larger than or equal to
+the number of bits in `self`.",
+);
+
+impl_for_ints!(OptionCheckedShlAssign<u32>, {
+    #[inline]
+    fn opt_checked_shl_assign(&mut self, rhs: u32) -> Result<(), Error> {
+        *self = self.checked_shl(rhs).ok_or(Error::ShiftOverflow)?;
+        Ok(())
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+    use core::ops::{Shl, ShlAssign};
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl Shl<u32> for MyInt {
+        type Output = MyInt;
+
+        fn shl(self, rhs: u32) -> MyInt {
+            MyInt(self.0.shl(rhs))
+        }
+    }
+
+    impl ShlAssign<u32> for MyInt {
+        fn shl_assign(&mut self, rhs: u32) {
+            self.0.shl_assign(rhs)
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_2: MyInt = MyInt(2);
+    const MY_16: MyInt = MyInt(16);
+    const MY_HIGH_BIT: MyInt = MyInt(1 << 63);
+    const MY_MAX: MyInt = MyInt(u64::MAX);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_2: Option<MyInt> = Some(MY_2);
+    const SOME_16: Option<MyInt> = Some(MY_16);
+    const SOME_HIGH_BIT: Option<MyInt> = Some(MY_HIGH_BIT);
+    const SOME_MAX: Option<MyInt> = Some(MY_MAX);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn shl_u32() {
+        assert_eq!(MY_1.opt_shl(4), SOME_16);
+        assert_eq!(MY_1.opt_shl(Some(4u32)), SOME_16);
+        assert_eq!(MY_1.opt_shl(&Some(1u32)), SOME_2);
+        assert_eq!(SOME_1.opt_shl(4), SOME_16);
+        assert_eq!(SOME_1.opt_shl(Some(63u32)), SOME_HIGH_BIT);
+        assert_eq!(SOME_1.opt_shl(&Some(1u32)), SOME_2);
+        assert_eq!(MY_1.opt_shl(Option::<u32>::None), NONE);
+        assert_eq!(NONE.opt_shl(4), NONE);
+        assert_eq!(NONE.opt_shl(Some(4u32)), NONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn shl_overflow() {
+        let _ = SOME_1.opt_shl(Some(64u32));
+    }
+
+    #[test]
+    fn shl_assign_u32() {
+        let mut my = MY_1;
+        my.opt_shl_assign(4);
+        assert_eq!(my, MY_16);
+
+        let mut some = SOME_1;
+        some.opt_shl_assign(1);
+        assert_eq!(some, SOME_2);
+
+        let mut my = MY_1;
+        my.opt_shl_assign(Some(4u32));
+        assert_eq!(my, MY_16);
+
+        let mut my = MY_1;
+        my.opt_shl_assign(&Some(1u32));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_1;
+        my.opt_shl_assign(Option::<u32>::None);
+        assert_eq!(my, MY_1);
+
+        let mut some = SOME_1;
+        some.opt_shl_assign(Some(63u32));
+        assert_eq!(some, SOME_HIGH_BIT);
+
+        let mut some = SOME_1;
+        some.opt_shl_assign(&Some(4u32));
+        assert_eq!(some, SOME_16);
+
+        let mut some = SOME_1;
+        some.opt_shl_assign(Option::<u32>::None);
+        assert_eq!(some, SOME_1);
+
+        let mut none = NONE;
+        none.opt_shl_assign(Some(1u32));
+        assert_eq!(none, NONE);
+    }
+
+    impl OptionCheckedShl<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_shl(self, rhs: u32) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_shl(rhs).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    #[test]
+    fn checked_shl() {
+        assert_eq!(MY_1.opt_checked_shl(4), Ok(SOME_16));
+        assert_eq!(MY_1.opt_checked_shl(Some(4u32)), Ok(SOME_16));
+        assert_eq!(MY_1.opt_checked_shl(&Some(1u32)), Ok(SOME_2));
+        assert_eq!(MY_1.opt_checked_shl(64), Err(Error::ShiftOverflow));
+
+        assert_eq!(SOME_1.opt_checked_shl(63), Ok(SOME_HIGH_BIT));
+        assert_eq!(SOME_1.opt_checked_shl(Some(4u32)), Ok(SOME_16));
+        assert_eq!(SOME_1.opt_checked_shl(&Some(1u32)), Ok(SOME_2));
+
+        assert_eq!(
+            SOME_1.opt_checked_shl(Some(64u32)),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(
+            SOME_1.opt_checked_shl(&Some(64u32)),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(MY_1.opt_checked_shl(Option::<u32>::None), Ok(None));
+        assert_eq!(NONE.opt_checked_shl(Some(64u32)), Ok(None));
+
+        assert_eq!(Some(1u64).opt_checked_shl(Some(4u32)), Ok(Some(16)));
+        assert_eq!(
+            Some(1u64).opt_checked_shl(Some(64u32)),
+            Err(Error::ShiftOverflow)
+        );
+    }
+
+    #[test]
+    fn checked_shl_shift_overflow() {
+        let err = Some(1u32).opt_checked_shl(Some(64u32)).unwrap_err();
+        assert_eq!(err, Error::ShiftOverflow);
+        assert!(err.is_shift_overflow());
+        assert!(!err.is_overflow());
+
+        assert_eq!(1u32.opt_checked_shl(32), Err(Error::ShiftOverflow));
+        assert_eq!(1u32.opt_checked_shl(31).map(|res| res.is_some()), Ok(true));
+    }
+
+    #[test]
+    fn checked_shl_u64_boundary() {
+        let high_bit = 1u64 << 63;
+
+        assert_eq!(Some(1u64).opt_checked_shl(63), Ok(Some(high_bit)));
+        assert_eq!(Some(1u64).opt_checked_shl(64), Err(Error::ShiftOverflow));
+        assert_eq!(Some(1u64).opt_checked_shl(65), Err(Error::ShiftOverflow));
+
+        // Near saturation, the shift amount is checked, not the value
+        assert_eq!(Some(u64::MAX).opt_checked_shl(63), Ok(Some(high_bit)));
+        assert_eq!(
+            Some(u64::MAX).opt_checked_shl(64),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(
+            Some(u64::MAX).opt_checked_shl(65),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(Some(high_bit).opt_checked_shl(1), Ok(Some(0)));
+
+        let mut count = Some(1u64);
+        assert_eq!(count.opt_checked_shl_assign(63), Ok(()));
+        assert_eq!(count, Some(high_bit));
+        assert_eq!(count.opt_checked_shl_assign(64), Err(Error::ShiftOverflow));
+        assert_eq!(count.opt_checked_shl_assign(65), Err(Error::ShiftOverflow));
+        assert_eq!(count, Some(high_bit));
+    }
+
+    impl OptionCheckedShlAssign<u32> for MyInt {
+        fn opt_checked_shl_assign(&mut self, rhs: u32) -> Result<(), Error> {
+            self.0.opt_checked_shl_assign(rhs)
+        }
+    }
+
+    #[test]
+    fn checked_shl_assign() {
+        let mut my = MY_1;
+        assert_eq!(my.opt_checked_shl_assign(4), Ok(()));
+        assert_eq!(my, MY_16);
+
+        let mut my = MY_1;
+        assert_eq!(my.opt_checked_shl_assign(Some(63u32)), Ok(()));
+        assert_eq!(my, MY_HIGH_BIT);
+
+        let mut my = MY_1;
+        assert_eq!(my.opt_checked_shl_assign(&Some(1u32)), Ok(()));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_2;
+        assert_eq!(my.opt_checked_shl_assign(64), Err(Error::ShiftOverflow));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_2;
+        assert_eq!(my.opt_checked_shl_assign(Option::<u32>::None), Ok(()));
+        assert_eq!(my, MY_2);
+
+        let mut some = SOME_1;
+        assert_eq!(some.opt_checked_shl_assign(4), Ok(()));
+        assert_eq!(some, SOME_16);
+
+        let mut some = SOME_2;
+        assert_eq!(
+            some.opt_checked_shl_assign(Some(64u32)),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(some, SOME_2);
+
+        let mut some = SOME_2;
+        assert_eq!(
+            some.opt_checked_shl_assign(&Some(65u32)),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(some, SOME_2);
+
+        let mut none = NONE;
+        assert_eq!(none.opt_checked_shl_assign(Some(64u32)), Ok(()));
+        assert_eq!(none, NONE);
+
+        let mut some = Some(1u8);
+        assert_eq!(some.opt_checked_shl_assign(8), Err(Error::ShiftOverflow));
+        assert_eq!(some, Some(1));
+    }
+
+    impl OptionOverflowingShl<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_shl(self, rhs: u32) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_shl(rhs)
+                .map(|(val, flag)| (MyInt(val), flag))
+        }
+    }
+
+    #[test]
+    fn overflowing_shl() {
+        assert_eq!(MY_1.opt_overflowing_shl(4), Some((MY_16, false)));
+        assert_eq!(MY_1.opt_overflowing_shl(63), Some((MY_HIGH_BIT, false)));
+        assert_eq!(MY_1.opt_overflowing_shl(64), Some((MY_1, true)));
+        assert_eq!(SOME_1.opt_overflowing_shl(65), Some((MY_2, true)));
+        assert_eq!(SOME_1.opt_overflowing_shl(Some(68u32)), Some((MY_16, true)));
+        assert_eq!(SOME_1.opt_overflowing_shl(&Some(64u32)), Some((MY_1, true)));
+        assert_eq!(MY_1.opt_overflowing_shl(Option::<u32>::None), None);
+        assert_eq!(NONE.opt_overflowing_shl(64), None);
+    }
+
+    impl OptionWrappingShl<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_shl(self, rhs: u32) -> Option<Self::Output> {
+            self.0.opt_wrapping_shl(rhs).map(MyInt)
+        }
+    }
+
+    #[test]
+    fn wrapping_shl() {
+        assert_eq!(MY_1.opt_wrapping_shl(4), SOME_16);
+        assert_eq!(MY_1.opt_wrapping_shl(64), SOME_1);
+        assert_eq!(MY_MAX.opt_wrapping_shl(64), SOME_MAX);
+        assert_eq!(SOME_1.opt_wrapping_shl(65), SOME_2);
+        assert_eq!(SOME_1.opt_wrapping_shl(Some(68u32)), SOME_16);
+        assert_eq!(SOME_1.opt_wrapping_shl(&Some(127u32)), SOME_HIGH_BIT);
+        assert_eq!(MY_0.opt_wrapping_shl(Option::<u32>::None), None);
+        assert_eq!(NONE.opt_wrapping_shl(64), None);
+    }
+
+    impl OptionWrappingShlAssign<u32> for MyInt {
+        fn opt_wrapping_shl_assign(&mut self, rhs: u32) {
+            self.0.opt_wrapping_shl_assign(rhs)
+        }
+    }
+
+    #[test]
+    fn wrapping_shl_assign() {
+        let mut my = MY_1;
+        my.opt_wrapping_shl_assign(4);
+        assert_eq!(my, MY_16);
+
+        let mut my = MY_1;
+        my.opt_wrapping_shl_assign(64);
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_1;
+        my.opt_wrapping_shl_assign(Some(65u32));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_2;
+        my.opt_wrapping_shl_assign(Option::<u32>::None);
+        assert_eq!(my, MY_2);
+
+        let mut some = SOME_1;
+        some.opt_wrapping_shl_assign(&Some(127u32));
+        assert_eq!(some, SOME_HIGH_BIT);
+
+        let mut some = SOME_2;
+        some.opt_wrapping_shl_assign(Option::<u32>::None);
+        assert_eq!(some, SOME_2);
+
+        let mut none = NONE;
+        none.opt_wrapping_shl_assign(Some(4u32));
+        assert_eq!(none, NONE);
+
+        let mut some = Some(1u8);
+        some.opt_wrapping_shl_assign(9);
+        assert_eq!(some, Some(2));
+    }
+}
@@ -0,0 +1,371 @@
+//! Traits for the right shift [`OptionOperations`].
+
+use core::ops::{Shr, ShrAssign};
+
+use crate::{Error, OptionOperations};
+
+common_option_op!(
+    Shr,
+    shr,
+    "right shift",
+    "
+# Panics
+
+Most implementations will panic if `rhs` is larger than or equal to
+the number of bits in `self`.
+",
+);
+
+impl_for_ints!(OptionOverflowingShr<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_overflowing_shr(self, rhs: u32) -> Option<(Self::Output, bool)> {
+        Some(self.overflowing_shr(rhs))
+    }
+});
+
+impl_for_ints!(OptionWrappingShr<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_wrapping_shr(self, rhs: u32) -> Option<Self::Output> {
+        Some(self.wrapping_shr(rhs))
+    }
+});
+
+option_op_assign_custom!(
+    WrappingShr<u32>,
+    wrapping_shr,
+    "wrapping right shift",
+    "
+`rhs` is masked to the number of bits in `self`: shifting by a count
+larger than or equal to the number of bits wraps the count around
+instead of panicking.",
+);
+
+impl_for_ints!(OptionWrappingShrAssign<u32>, {
+    #[inline]
+    fn opt_wrapping_shr_assign(&mut self, rhs: u32) {
+        *self = self.wrapping_shr(rhs);
+    }
+});
+
+option_op_checked!(
+    Shr,
+    shr,
+    "right shift",
+    "- Returns `Err(Error::ShiftOverflow)`, not `Error::Overflow`, if `rhs`
+is larger than or equal to the number of bits in `self`.",
+);
+
+impl_for_ints!(OptionCheckedShr<u32>, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_shr(self, rhs: u32) -> Result<Option<Self::Output>, Error> {
+        self.checked_shr(rhs).ok_or(Error::ShiftOverflow).map(Some)
+    }
+});
+
+option_op_checked_assign!(
+    Shr,
+    shr,
+    "right shift",
+    "- Returns `Err(Error::ShiftOverflow)` if `rhs` is larger than or equal to
+the number of bits in `self`.",
+);
+
+impl_for_ints!(OptionCheckedShrAssign<u32>, {
+    #[inline]
+    fn opt_checked_shr_assign(&mut self, rhs: u32) -> Result<(), Error> {
+        *self = self.checked_shr(rhs).ok_or(Error::ShiftOverflow)?;
+        Ok(())
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+    use core::ops::{Shr, ShrAssign};
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    impl Shr<u32> for MyInt {
+        type Output = MyInt;
+
+        fn shr(self, rhs: u32) -> MyInt {
+            MyInt(self.0.shr(rhs))
+        }
+    }
+
+    impl ShrAssign<u32> for MyInt {
+        fn shr_assign(&mut self, rhs: u32) {
+            self.0.shr_assign(rhs)
+        }
+    }
+
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_8: MyInt = MyInt(8);
+    const MY_16: MyInt = MyInt(16);
+    const MY_HIGH_BIT: MyInt = MyInt(1 << 63);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_8: Option<MyInt> = Some(MY_8);
+    const SOME_16: Option<MyInt> = Some(MY_16);
+    const SOME_HIGH_BIT: Option<MyInt> = Some(MY_HIGH_BIT);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn shr_u32() {
+        assert_eq!(MY_16.opt_shr(4), SOME_1);
+        assert_eq!(MY_16.opt_shr(Some(4u32)), SOME_1);
+        assert_eq!(MY_16.opt_shr(&Some(1u32)), SOME_8);
+        assert_eq!(SOME_16.opt_shr(5), SOME_0);
+        assert_eq!(SOME_HIGH_BIT.opt_shr(Some(63u32)), SOME_1);
+        assert_eq!(SOME_16.opt_shr(&Some(1u32)), SOME_8);
+        assert_eq!(MY_16.opt_shr(Option::<u32>::None), NONE);
+        assert_eq!(NONE.opt_shr(4), NONE);
+        assert_eq!(NONE.opt_shr(Some(4u32)), NONE);
+    }
+
+    #[test]
+    #[should_panic]
+    fn shr_overflow() {
+        let _ = SOME_16.opt_shr(Some(64u32));
+    }
+
+    #[test]
+    fn shr_assign_u32() {
+        let mut my = MY_16;
+        my.opt_shr_assign(4);
+        assert_eq!(my, MY_1);
+
+        let mut some = SOME_16;
+        some.opt_shr_assign(1);
+        assert_eq!(some, SOME_8);
+
+        let mut my = MY_16;
+        my.opt_shr_assign(Some(4u32));
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_16;
+        my.opt_shr_assign(&Some(1u32));
+        assert_eq!(my, MY_8);
+
+        let mut my = MY_16;
+        my.opt_shr_assign(Option::<u32>::None);
+        assert_eq!(my, MY_16);
+
+        let mut some = SOME_HIGH_BIT;
+        some.opt_shr_assign(Some(63u32));
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_16;
+        some.opt_shr_assign(&Some(4u32));
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_16;
+        some.opt_shr_assign(Option::<u32>::None);
+        assert_eq!(some, SOME_16);
+
+        let mut none = NONE;
+        none.opt_shr_assign(Some(1u32));
+        assert_eq!(none, NONE);
+    }
+
+    impl OptionCheckedShr<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_shr(self, rhs: u32) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_shr(rhs).map(|ok| ok.map(MyInt))
+        }
+    }
+
+    #[test]
+    fn checked_shr() {
+        assert_eq!(MY_16.opt_checked_shr(4), Ok(SOME_1));
+        assert_eq!(MY_16.opt_checked_shr(Some(4u32)), Ok(SOME_1));
+        assert_eq!(MY_16.opt_checked_shr(&Some(1u32)), Ok(SOME_8));
+        assert_eq!(MY_16.opt_checked_shr(64), Err(Error::ShiftOverflow));
+
+        assert_eq!(SOME_HIGH_BIT.opt_checked_shr(63), Ok(SOME_1));
+        assert_eq!(SOME_16.opt_checked_shr(Some(4u32)), Ok(SOME_1));
+        assert_eq!(SOME_16.opt_checked_shr(&Some(1u32)), Ok(SOME_8));
+
+        assert_eq!(
+            SOME_16.opt_checked_shr(Some(64u32)),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(
+            SOME_16.opt_checked_shr(&Some(64u32)),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(MY_16.opt_checked_shr(Option::<u32>::None), Ok(None));
+        assert_eq!(NONE.opt_checked_shr(Some(64u32)), Ok(None));
+
+        assert_eq!(Some(16u64).opt_checked_shr(Some(4u32)), Ok(Some(1)));
+        assert_eq!(
+            Some(16u64).opt_checked_shr(Some(64u32)),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(Some(-16i8).opt_checked_shr(Some(2u32)), Ok(Some(-4)));
+        assert_eq!(
+            Some(-16i8).opt_checked_shr(Some(8u32)),
+            Err(Error::ShiftOverflow)
+        );
+    }
+
+    #[test]
+    fn checked_shr_shift_overflow() {
+        let err = Some(1u32).opt_checked_shr(Some(64u32)).unwrap_err();
+        assert_eq!(err, Error::ShiftOverflow);
+        assert!(err.is_shift_overflow());
+        assert!(!err.is_overflow());
+
+        assert_eq!(1u32.opt_checked_shr(32), Err(Error::ShiftOverflow));
+        assert_eq!(1u32.opt_checked_shr(31).map(|res| res.is_some()), Ok(true));
+    }
+
+    impl OptionCheckedShrAssign<u32> for MyInt {
+        fn opt_checked_shr_assign(&mut self, rhs: u32) -> Result<(), Error> {
+            self.0.opt_checked_shr_assign(rhs)
+        }
+    }
+
+    #[test]
+    fn checked_shr_assign() {
+        let mut my = MY_16;
+        assert_eq!(my.opt_checked_shr_assign(4), Ok(()));
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_HIGH_BIT;
+        assert_eq!(my.opt_checked_shr_assign(Some(63u32)), Ok(()));
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_16;
+        assert_eq!(my.opt_checked_shr_assign(&Some(1u32)), Ok(()));
+        assert_eq!(my, MY_8);
+
+        let mut my = MY_16;
+        assert_eq!(my.opt_checked_shr_assign(64), Err(Error::ShiftOverflow));
+        assert_eq!(my, MY_16);
+
+        let mut my = MY_16;
+        assert_eq!(my.opt_checked_shr_assign(Option::<u32>::None), Ok(()));
+        assert_eq!(my, MY_16);
+
+        let mut some = SOME_16;
+        assert_eq!(some.opt_checked_shr_assign(5), Ok(()));
+        assert_eq!(some, SOME_0);
+
+        let mut some = SOME_16;
+        assert_eq!(
+            some.opt_checked_shr_assign(Some(64u32)),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(some, SOME_16);
+
+        let mut some = SOME_16;
+        assert_eq!(
+            some.opt_checked_shr_assign(&Some(65u32)),
+            Err(Error::ShiftOverflow)
+        );
+        assert_eq!(some, SOME_16);
+
+        let mut none = NONE;
+        assert_eq!(none.opt_checked_shr_assign(Some(64u32)), Ok(()));
+        assert_eq!(none, NONE);
+
+        let mut some = Some(-16i8);
+        assert_eq!(some.opt_checked_shr_assign(8), Err(Error::ShiftOverflow));
+        assert_eq!(some, Some(-16));
+    }
+
+    impl OptionOverflowingShr<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_shr(self, rhs: u32) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_shr(rhs)
+                .map(|(val, flag)| (MyInt(val), flag))
+        }
+    }
+
+    #[test]
+    fn overflowing_shr() {
+        assert_eq!(MY_16.opt_overflowing_shr(4), Some((MY_1, false)));
+        assert_eq!(MY_HIGH_BIT.opt_overflowing_shr(63), Some((MY_1, false)));
+        assert_eq!(MY_16.opt_overflowing_shr(64), Some((MY_16, true)));
+        assert_eq!(SOME_16.opt_overflowing_shr(65), Some((MY_8, true)));
+        assert_eq!(SOME_16.opt_overflowing_shr(Some(68u32)), Some((MY_1, true)));
+        assert_eq!(
+            SOME_16.opt_overflowing_shr(&Some(64u32)),
+            Some((MY_16, true))
+        );
+        assert_eq!(MY_16.opt_overflowing_shr(Option::<u32>::None), None);
+        assert_eq!(NONE.opt_overflowing_shr(64), None);
+    }
+
+    impl OptionWrappingShr<u32> for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_shr(self, rhs: u32) -> Option<Self::Output> {
+            self.0.opt_wrapping_shr(rhs).map(MyInt)
+        }
+    }
+
+    #[test]
+    fn wrapping_shr() {
+        assert_eq!(MY_16.opt_wrapping_shr(4), SOME_1);
+        assert_eq!(MY_16.opt_wrapping_shr(64), SOME_16);
+        assert_eq!(SOME_16.opt_wrapping_shr(65), SOME_8);
+        assert_eq!(SOME_16.opt_wrapping_shr(Some(68u32)), SOME_1);
+        assert_eq!(SOME_HIGH_BIT.opt_wrapping_shr(&Some(127u32)), SOME_1);
+        assert_eq!(MY_0.opt_wrapping_shr(Option::<u32>::None), None);
+        assert_eq!(NONE.opt_wrapping_shr(64), None);
+
+        assert_eq!(Some(1u8).opt_wrapping_shr(Some(9u32)), Some(0));
+        assert_eq!(Some(2u8).opt_wrapping_shr(Some(9u32)), Some(1));
+    }
+
+    impl OptionWrappingShrAssign<u32> for MyInt {
+        fn opt_wrapping_shr_assign(&mut self, rhs: u32) {
+            self.0.opt_wrapping_shr_assign(rhs)
+        }
+    }
+
+    #[test]
+    fn wrapping_shr_assign() {
+        let mut my = MY_16;
+        my.opt_wrapping_shr_assign(4);
+        assert_eq!(my, MY_1);
+
+        let mut my = MY_16;
+        my.opt_wrapping_shr_assign(64);
+        assert_eq!(my, MY_16);
+
+        let mut my = MY_16;
+        my.opt_wrapping_shr_assign(Some(65u32));
+        assert_eq!(my, MY_8);
+
+        let mut my = MY_16;
+        my.opt_wrapping_shr_assign(Option::<u32>::None);
+        assert_eq!(my, MY_16);
+
+        let mut some = SOME_HIGH_BIT;
+        some.opt_wrapping_shr_assign(&Some(127u32));
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_16;
+        some.opt_wrapping_shr_assign(Option::<u32>::None);
+        assert_eq!(some, SOME_16);
+
+        let mut none = NONE;
+        none.opt_wrapping_shr_assign(Some(4u32));
+        assert_eq!(none, NONE);
+
+        let mut some = Some(2u8);
+        some.opt_wrapping_shr_assign(9);
+        assert_eq!(some, Some(1));
+    }
+}
@@ -0,0 +1,154 @@
+//! Traits for the sign predicates [`OptionOperations`].
+
+use crate::OptionOperations;
+
+/// Trait for values and `Option`s that can be tested for a positive sign.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionIsPositive` for `Option<T>`.
+///
+/// Note that since the `std` library doesn't define any `IsPositive` trait,
+/// users must provide the base implementation for the inner type.
+pub trait OptionIsPositive {
+    /// Tests whether `self` is strictly positive.
+    ///
+    /// Zero is neither positive nor negative.
+    ///
+    /// Returns `None` if `self` is `None`.
+    #[must_use]
+    fn opt_is_positive(self) -> Option<bool>;
+}
+
+impl<T> OptionIsPositive for Option<T>
+where
+    T: OptionOperations + OptionIsPositive,
+{
+    #[inline]
+    fn opt_is_positive(self) -> Option<bool> {
+        self.and_then(|inner_self| inner_self.opt_is_positive())
+    }
+}
+
+impl_for_signed_ints!(OptionIsPositive, {
+    #[inline]
+    fn opt_is_positive(self) -> Option<bool> {
+        Some(self.is_positive())
+    }
+});
+
+impl_for_floats!(OptionIsPositive, {
+    #[inline]
+    fn opt_is_positive(self) -> Option<bool> {
+        Some(self > 0.0)
+    }
+});
+
+/// Trait for values and `Option`s that can be tested for a negative sign.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionIsNegative` for `Option<T>`.
+///
+/// Note that since the `std` library doesn't define any `IsNegative` trait,
+/// users must provide the base implementation for the inner type.
+pub trait OptionIsNegative {
+    /// Tests whether `self` is strictly negative.
+    ///
+    /// Zero is neither positive nor negative.
+    ///
+    /// Returns `None` if `self` is `None`.
+    #[must_use]
+    fn opt_is_negative(self) -> Option<bool>;
+}
+
+impl<T> OptionIsNegative for Option<T>
+where
+    T: OptionOperations + OptionIsNegative,
+{
+    #[inline]
+    fn opt_is_negative(self) -> Option<bool> {
+        self.and_then(|inner_self| inner_self.opt_is_negative())
+    }
+}
+
+impl_for_signed_ints!(OptionIsNegative, {
+    #[inline]
+    fn opt_is_negative(self) -> Option<bool> {
+        Some(self.is_negative())
+    }
+});
+
+impl_for_floats!(OptionIsNegative, {
+    #[inline]
+    fn opt_is_negative(self) -> Option<bool> {
+        Some(self < 0.0)
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::{OptionIsNegative, OptionIsPositive};
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionIsPositive for MyInt {
+        fn opt_is_positive(self) -> Option<bool> {
+            self.0.opt_is_positive()
+        }
+    }
+
+    impl OptionIsNegative for MyInt {
+        fn opt_is_negative(self) -> Option<bool> {
+            self.0.opt_is_negative()
+        }
+    }
+
+    const MY_MINUS_1: MyInt = MyInt(-1);
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const SOME_MINUS_1: Option<MyInt> = Some(MY_MINUS_1);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn is_positive() {
+        assert_eq!(MY_1.opt_is_positive(), Some(true));
+        assert_eq!(MY_0.opt_is_positive(), Some(false));
+        assert_eq!(MY_MINUS_1.opt_is_positive(), Some(false));
+        assert_eq!(SOME_1.opt_is_positive(), Some(true));
+        assert_eq!(SOME_0.opt_is_positive(), Some(false));
+        assert_eq!(SOME_MINUS_1.opt_is_positive(), Some(false));
+        assert_eq!(NONE.opt_is_positive(), None);
+
+        assert_eq!(Some(i8::MAX).opt_is_positive(), Some(true));
+        assert_eq!(Some(0.5f64).opt_is_positive(), Some(true));
+        assert_eq!(Some(0.0f64).opt_is_positive(), Some(false));
+        assert_eq!(Some(-0.0f32).opt_is_positive(), Some(false));
+        assert_eq!(Some(f64::NAN).opt_is_positive(), Some(false));
+        assert_eq!(Option::<f64>::None.opt_is_positive(), None);
+    }
+
+    #[test]
+    fn is_negative() {
+        assert_eq!(MY_1.opt_is_negative(), Some(false));
+        assert_eq!(MY_0.opt_is_negative(), Some(false));
+        assert_eq!(MY_MINUS_1.opt_is_negative(), Some(true));
+        assert_eq!(SOME_1.opt_is_negative(), Some(false));
+        assert_eq!(SOME_0.opt_is_negative(), Some(false));
+        assert_eq!(SOME_MINUS_1.opt_is_negative(), Some(true));
+        assert_eq!(NONE.opt_is_negative(), None);
+
+        assert_eq!(Some(i128::MIN).opt_is_negative(), Some(true));
+        assert_eq!(Some(-0.5f64).opt_is_negative(), Some(true));
+        assert_eq!(Some(0.0f64).opt_is_negative(), Some(false));
+        assert_eq!(Some(-0.0f32).opt_is_negative(), Some(false));
+        assert_eq!(Some(f32::NAN).opt_is_negative(), Some(false));
+        assert_eq!(Option::<i32>::None.opt_is_negative(), None);
+    }
+}
@@ -0,0 +1,83 @@
+//! Traits for the sign extraction [`OptionOperations`].
+
+use crate::OptionOperations;
+
+option_unary_op_custom!(
+    Signum,
+    signum,
+    "sign",
+    "
+For signed integers, the result is `-1`, `0` or `1`. For floats,
+the result is `-1.0`, `1.0` or `NaN`, following the float `signum` method.
+",
+);
+
+impl_for_signed_ints!(OptionSignum, {
+    type Output = Self;
+    #[inline]
+    fn opt_signum(self) -> Option<Self::Output> {
+        Some(self.signum())
+    }
+});
+
+impl_for_floats!(OptionSignum, {
+    type Output = Self;
+    #[inline]
+    fn opt_signum(self) -> Option<Self::Output> {
+        Some(self.signum())
+    }
+});
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionSignum for MyInt {
+        type Output = MyInt;
+        fn opt_signum(self) -> Option<Self::Output> {
+            self.0.opt_signum().map(MyInt)
+        }
+    }
+
+    const MY_MINUS_1: MyInt = MyInt(-1);
+    const MY_0: MyInt = MyInt(0);
+    const MY_1: MyInt = MyInt(1);
+    const MY_MIN: MyInt = MyInt(i64::MIN);
+    const MY_MAX: MyInt = MyInt(i64::MAX);
+    const SOME_MINUS_1: Option<MyInt> = Some(MY_MINUS_1);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_1: Option<MyInt> = Some(MY_1);
+    const SOME_MIN: Option<MyInt> = Some(MY_MIN);
+    const SOME_MAX: Option<MyInt> = Some(MY_MAX);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn signum() {
+        assert_eq!(MY_MIN.opt_signum(), SOME_MINUS_1);
+        assert_eq!(MY_0.opt_signum(), SOME_0);
+        assert_eq!(MY_MAX.opt_signum(), SOME_1);
+        assert_eq!(SOME_MIN.opt_signum(), SOME_MINUS_1);
+        assert_eq!(SOME_0.opt_signum(), SOME_0);
+        assert_eq!(SOME_MAX.opt_signum(), SOME_1);
+        assert_eq!(NONE.opt_signum(), NONE);
+
+        assert_eq!(Some(-42i8).opt_signum(), Some(-1));
+        assert_eq!(Some(42i128).opt_signum(), Some(1));
+    }
+
+    #[test]
+    fn signum_float() {
+        assert_eq!(Some(-2.5f64).opt_signum(), Some(-1.0));
+        assert_eq!(Some(2.5f32).opt_signum(), Some(1.0));
+        assert_eq!(Some(0.0f64).opt_signum(), Some(1.0));
+        assert_eq!(Some(-0.0f64).opt_signum(), Some(-1.0));
+        assert!(Some(f64::NAN).opt_signum().unwrap().is_nan());
+        assert_eq!(Option::<f64>::None.opt_signum(), None);
+    }
+}
@@ -0,0 +1,168 @@
+//! Extension trait to sort slices of `Option`s.
+
+use core::cmp::Ordering;
+
+use crate::{NoneOrdering, OptionOperations, OptionOrd};
+
+/// Extension trait to sort slices of `Option`s with a configurable
+/// placement of the `None`s.
+///
+/// The sorts are stable: equal elements are not reordered.
+///
+/// This trait is auto-implemented for `[Option<T>]`, hence for
+/// `Vec<Option<T>>`. It is only available with the `alloc` feature.
+pub trait OptionSliceSortExt<T> {
+    /// Sorts the slice, placing the `None`s according to `none_ordering`.
+    ///
+    /// The `Some(_)`s are compared using [`OptionOrd`].
+    ///
+    /// ```
+    /// # use option_operations::{NoneOrdering, OptionSliceSortExt};
+    /// let mut values = vec![Some(2), None, Some(1)];
+    ///
+    /// values.opt_sort(NoneOrdering::First);
+    /// assert_eq!(values, [None, Some(1), Some(2)]);
+    ///
+    /// values.opt_sort(NoneOrdering::Last);
+    /// assert_eq!(values, [Some(1), Some(2), None]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if two inner values can't be compared, e.g. a `NaN`.
+    fn opt_sort(&mut self, none_ordering: NoneOrdering)
+    where
+        T: OptionOperations + for<'a> OptionOrd<&'a T, T>;
+
+    /// Sorts the slice with a key extraction function, placing the `None`s
+    /// according to `none_ordering`.
+    ///
+    /// `f` is only called on the inner value of the `Some(_)`s.
+    ///
+    /// ```
+    /// # use option_operations::{NoneOrdering, OptionSliceSortExt};
+    /// let mut values = vec![Some(-3i32), None, Some(1), Some(2)];
+    ///
+    /// values.opt_sort_by_key(NoneOrdering::Last, |value| value.abs());
+    /// assert_eq!(values, [Some(1), Some(2), Some(-3), None]);
+    /// ```
+    fn opt_sort_by_key<K, F>(&mut self, none_ordering: NoneOrdering, f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord;
+}
+
+fn cmp_with<T, F>(lhs: &Option<T>, rhs: &Option<T>, none_ordering: NoneOrdering, f: F) -> Ordering
+where
+    F: FnOnce(&T, &T) -> Ordering,
+{
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => f(lhs, rhs),
+        (None, Some(_)) => none_ordering.none_cmp_some(),
+        (Some(_), None) => none_ordering.none_cmp_some().reverse(),
+        (None, None) => Ordering::Equal,
+    }
+}
+
+impl<T> OptionSliceSortExt<T> for [Option<T>] {
+    #[inline]
+    fn opt_sort(&mut self, none_ordering: NoneOrdering)
+    where
+        T: OptionOperations + for<'a> OptionOrd<&'a T, T>,
+    {
+        self.sort_by(|lhs, rhs| {
+            lhs.opt_cmp_with(rhs, none_ordering)
+                .expect("inner values can't be compared")
+        });
+    }
+
+    #[inline]
+    fn opt_sort_by_key<K, F>(&mut self, none_ordering: NoneOrdering, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.sort_by(|lhs, rhs| cmp_with(lhs, rhs, none_ordering, |lhs, rhs| f(lhs).cmp(&f(rhs))));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use super::OptionSliceSortExt;
+    use crate::{NoneOrdering, OptionOperations};
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    const SOME_1: Option<MyInt> = Some(MyInt(1));
+    const SOME_2: Option<MyInt> = Some(MyInt(2));
+    const SOME_3: Option<MyInt> = Some(MyInt(3));
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn sort_none_placement() {
+        let mut values = vec![SOME_3, NONE, SOME_1, NONE, SOME_2];
+
+        values.opt_sort(NoneOrdering::First);
+        assert_eq!(values, [NONE, NONE, SOME_1, SOME_2, SOME_3]);
+
+        values.opt_sort(NoneOrdering::Last);
+        assert_eq!(values, [SOME_1, SOME_2, SOME_3, NONE, NONE]);
+
+        let mut values = [Some(2.5f64), None, Some(-1.0)];
+        values.opt_sort(NoneOrdering::First);
+        assert_eq!(values, [None, Some(-1.0), Some(2.5)]);
+
+        let mut empty: [Option<u8>; 0] = [];
+        empty.opt_sort(NoneOrdering::Last);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sort_incomparable() {
+        let mut values = [Some(1.0f64), Some(f64::NAN)];
+        values.opt_sort(NoneOrdering::First);
+    }
+
+    #[test]
+    fn sort_by_key_stable() {
+        let mut values = vec![
+            Some((2, 'a')),
+            None,
+            Some((1, 'b')),
+            Some((2, 'c')),
+            Some((1, 'd')),
+            None,
+        ];
+
+        values.opt_sort_by_key(NoneOrdering::Last, |(key, _)| *key);
+        assert_eq!(
+            values,
+            [
+                Some((1, 'b')),
+                Some((1, 'd')),
+                Some((2, 'a')),
+                Some((2, 'c')),
+                None,
+                None,
+            ]
+        );
+
+        values.opt_sort_by_key(NoneOrdering::First, |(key, _)| core::cmp::Reverse(*key));
+        assert_eq!(
+            values,
+            [
+                None,
+                None,
+                Some((2, 'a')),
+                Some((2, 'c')),
+                Some((1, 'b')),
+                Some((1, 'd')),
+            ]
+        );
+    }
+}
@@ -0,0 +1,192 @@
+//! Traits for the square root [`OptionOperations`].
+
+use crate::{Error, OptionOperations};
+
+option_unary_op_custom!(
+    Isqrt,
+    isqrt,
+    "integer square root, rounded down",
+    "
+# Panics
+
+Most signed integer implementations will panic if `self` is negative.
+See [`OptionCheckedIsqrt`] for a non-panicking alternative.",
+);
+
+impl_for_ints!(OptionIsqrt, {
+    type Output = Self;
+    #[inline]
+    fn opt_isqrt(self) -> Option<Self::Output> {
+        Some(self.isqrt())
+    }
+});
+
+option_unary_op_checked!(
+    Isqrt,
+    isqrt,
+    "integer square root, rounded down",
+    "- Returns `Err(Error::Domain)` if `self` is negative.",
+);
+
+impl_for_signed_ints!(OptionCheckedIsqrt, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_isqrt(self) -> Result<Option<Self::Output>, Error> {
+        self.checked_isqrt().ok_or(Error::Domain).map(Some)
+    }
+});
+
+impl_for_unsigned_ints!(OptionCheckedIsqrt, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_isqrt(self) -> Result<Option<Self::Output>, Error> {
+        Ok(Some(self.isqrt()))
+    }
+});
+
+option_unary_op_custom!(
+    Sqrt,
+    sqrt,
+    "square root",
+    "
+Float implementations return `NaN` if `self` is negative, except for `-0.0`.
+See [`OptionCheckedSqrt`] for an alternative rejecting negative values.
+
+Float implementations are only available with the `std` feature or,
+in `no_std` environments, with the `libm` feature.",
+);
+
+option_unary_op_checked!(
+    Sqrt,
+    sqrt,
+    "square root",
+    "- Returns `Err(Error::Domain)` if `self` is negative. `-0.0` is
+not considered negative.
+
+Float implementations are only available with the `std` feature or,
+in `no_std` environments, with the `libm` feature.",
+);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_sqrt_for_floats {
+    ($($typ_:ty => $libm_sqrt:ident),+ $(,)?) => {
+        $(
+            impl OptionSqrt for $typ_ {
+                type Output = Self;
+
+                #[inline]
+                fn opt_sqrt(self) -> Option<Self::Output> {
+                    #[cfg(feature = "std")]
+                    let res = self.sqrt();
+                    #[cfg(not(feature = "std"))]
+                    let res = libm::$libm_sqrt(self);
+
+                    Some(res)
+                }
+            }
+
+            impl OptionCheckedSqrt for $typ_ {
+                type Output = Self;
+
+                #[inline]
+                fn opt_checked_sqrt(self) -> Result<Option<Self::Output>, Error> {
+                    if self < 0.0 {
+                        return Err(Error::Domain);
+                    }
+                    Ok(self.opt_sqrt())
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_sqrt_for_floats!(f32 => sqrtf, f64 => sqrt);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::OptionOperations;
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(i64);
+
+    impl OptionOperations for MyInt {}
+
+    impl OptionIsqrt for MyInt {
+        type Output = MyInt;
+        fn opt_isqrt(self) -> Option<Self::Output> {
+            self.0.opt_isqrt().map(MyInt)
+        }
+    }
+
+    impl OptionCheckedIsqrt for MyInt {
+        type Output = MyInt;
+        fn opt_checked_isqrt(self) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_isqrt().map(|ok| ok.map(MyInt))
+        }
+    }
+
+    const MY_MINUS_1: MyInt = MyInt(-1);
+    const MY_0: MyInt = MyInt(0);
+    const MY_3: MyInt = MyInt(3);
+    const MY_9: MyInt = MyInt(9);
+    const MY_10: MyInt = MyInt(10);
+    const SOME_0: Option<MyInt> = Some(MY_0);
+    const SOME_3: Option<MyInt> = Some(MY_3);
+    const SOME_9: Option<MyInt> = Some(MY_9);
+    const NONE: Option<MyInt> = None;
+
+    #[test]
+    fn isqrt() {
+        assert_eq!(MY_9.opt_isqrt(), SOME_3);
+        assert_eq!(MY_10.opt_isqrt(), SOME_3);
+        assert_eq!(MY_0.opt_isqrt(), SOME_0);
+        assert_eq!(SOME_9.opt_isqrt(), SOME_3);
+        assert_eq!(NONE.opt_isqrt(), None);
+
+        assert_eq!(Some(144u8).opt_isqrt(), Some(12));
+        assert_eq!(Some(u64::MAX).opt_isqrt(), Some(u32::MAX as u64));
+        assert_eq!(Some(i32::MAX).opt_isqrt(), Some(46_340));
+    }
+
+    #[test]
+    #[should_panic]
+    fn isqrt_negative() {
+        let _ = Some(MY_MINUS_1).opt_isqrt();
+    }
+
+    #[test]
+    fn checked_isqrt() {
+        assert_eq!(MY_9.opt_checked_isqrt(), Ok(SOME_3));
+        assert_eq!(MY_10.opt_checked_isqrt(), Ok(SOME_3));
+        assert_eq!(SOME_0.opt_checked_isqrt(), Ok(SOME_0));
+        assert_eq!(MY_MINUS_1.opt_checked_isqrt(), Err(Error::Domain));
+        assert_eq!(
+            Some(MyInt(i64::MIN)).opt_checked_isqrt(),
+            Err(Error::Domain)
+        );
+        assert_eq!(NONE.opt_checked_isqrt(), Ok(None));
+
+        assert_eq!(Some(10_000u16).opt_checked_isqrt(), Ok(Some(100)));
+        assert_eq!(Some(-4i8).opt_checked_isqrt(), Err(Error::Domain));
+        assert_eq!(Option::<i8>::None.opt_checked_isqrt(), Ok(None));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn sqrt_float() {
+        assert_eq!(Some(9.0f64).opt_sqrt(), Some(3.0));
+        assert_eq!(2.25f32.opt_sqrt(), Some(1.5));
+        assert_eq!(Some(-0.0f64).opt_sqrt(), Some(-0.0));
+        assert!(Some(-1.0f64).opt_sqrt().unwrap().is_nan());
+        assert_eq!(Option::<f64>::None.opt_sqrt(), None);
+
+        assert_eq!(Some(16.0f64).opt_checked_sqrt(), Ok(Some(4.0)));
+        assert_eq!(Some(0.0f32).opt_checked_sqrt(), Ok(Some(0.0)));
+        assert_eq!(Some(-0.0f32).opt_checked_sqrt(), Ok(Some(-0.0)));
+        assert_eq!(Some(-1.0f64).opt_checked_sqrt(), Err(Error::Domain));
+        assert_eq!(f32::NEG_INFINITY.opt_checked_sqrt(), Err(Error::Domain));
+        assert_eq!(Option::<f64>::None.opt_checked_sqrt(), Ok(None));
+    }
+}
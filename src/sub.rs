@@ -8,6 +8,7 @@ common_option_op!(Sub, sub, substraction);
 
 impl_for_ints!(OptionOverflowingSub, {
     type Output = Self;
+    #[inline]
     fn opt_overflowing_sub(self, rhs: Self) -> Option<(Self::Output, bool)> {
         Some(self.overflowing_sub(rhs))
     }
@@ -15,41 +16,181 @@ impl_for_ints!(OptionOverflowingSub, {
 
 impl_for_ints!(OptionWrappingSub, {
     type Output = Self;
+    #[inline]
     fn opt_wrapping_sub(self, rhs: Self) -> Option<Self::Output> {
         Some(self.wrapping_sub(rhs))
     }
 });
 
-option_op_checked!(Sub, sub, substraction);
-
-impl_for_ints_and_duration!(OptionCheckedSub, {
+option_op_checked!(
+    Sub,
+    sub,
+    substraction,
+    "- Returns `Err(Error::Underflow)`, not `Error::Overflow`, if the result
+of an unsigned integer or `Duration` operation would be negative, or if `rhs` is later
+than `self` when subtracting two `Instant`s or two `SystemTime`s.
+- Returns `Err(Error::NotFinite)` if the result of a floating point operation
+is infinite or `NaN` while the operands are finite.",
+);
+
+impl_for_signed_ints!(OptionCheckedSub, {
     type Output = Self;
+    #[inline]
     fn opt_checked_sub(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
         self.checked_sub(rhs).ok_or(Error::Overflow).map(Some)
     }
 });
 
-#[cfg(feature = "std")]
+impl_for_unsigned_ints!(OptionCheckedSub, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_sub(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        self.checked_sub(rhs).ok_or(Error::Underflow).map(Some)
+    }
+});
+
+#[cfg(feature = "duration")]
+impl_for!(OptionCheckedSub, core::time::Duration, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_sub(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        self.checked_sub(rhs).ok_or(Error::Underflow).map(Some)
+    }
+});
+
+impl_for_floats!(OptionCheckedSub, {
+    type Output = Self;
+    #[inline]
+    fn opt_checked_sub(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        let res = self.sub(rhs);
+        if !res.is_finite() && self.is_finite() && rhs.is_finite() {
+            return Err(Error::NotFinite);
+        }
+        Ok(Some(res))
+    }
+});
+
+#[cfg(all(feature = "std", feature = "duration"))]
 impl OptionCheckedSub<std::time::Duration> for std::time::Instant {
     type Output = Self;
+    #[inline]
     fn opt_checked_sub(self, rhs: std::time::Duration) -> Result<Option<Self::Output>, Error> {
         self.checked_sub(rhs).ok_or(Error::Overflow).map(Some)
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(all(feature = "std", feature = "duration"))]
 impl OptionCheckedSub<std::time::Duration> for std::time::SystemTime {
     type Output = Self;
+    #[inline]
     fn opt_checked_sub(self, rhs: std::time::Duration) -> Result<Option<Self::Output>, Error> {
         self.checked_sub(rhs).ok_or(Error::Overflow).map(Some)
     }
 }
 
+#[cfg(all(feature = "std", feature = "duration"))]
+impl OptionCheckedSub for std::time::Instant {
+    type Output = std::time::Duration;
+    #[inline]
+    fn opt_checked_sub(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        self.checked_duration_since(rhs)
+            .ok_or(Error::Underflow)
+            .map(Some)
+    }
+}
+
+#[cfg(all(feature = "std", feature = "duration"))]
+impl OptionCheckedSub for std::time::SystemTime {
+    type Output = std::time::Duration;
+    #[inline]
+    fn opt_checked_sub(self, rhs: Self) -> Result<Option<Self::Output>, Error> {
+        self.duration_since(rhs)
+            .map_err(|_| Error::Underflow)
+            .map(Some)
+    }
+}
+
+option_op_checked_assign!(
+    Sub,
+    sub,
+    substraction,
+    "- Returns `Err(Error::Overflow)` if an overflow occured for a signed integer
+operation, or `Err(Error::Underflow)` if the result of an unsigned integer
+or `Duration` operation would be negative.",
+    "- Returns `Err(Error::NotFinite)` if the result of a floating point operation
+is infinite or `NaN` while the operands are finite.",
+);
+
+impl_for_signed_ints!(OptionCheckedSubAssign, {
+    #[inline]
+    fn opt_checked_sub_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        *self = self.checked_sub(rhs).ok_or(Error::Overflow)?;
+        Ok(())
+    }
+});
+
+impl_for_unsigned_ints!(OptionCheckedSubAssign, {
+    #[inline]
+    fn opt_checked_sub_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        *self = self.checked_sub(rhs).ok_or(Error::Underflow)?;
+        Ok(())
+    }
+});
+
+#[cfg(feature = "duration")]
+impl_for!(OptionCheckedSubAssign, core::time::Duration, {
+    #[inline]
+    fn opt_checked_sub_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        *self = self.checked_sub(rhs).ok_or(Error::Underflow)?;
+        Ok(())
+    }
+});
+
+impl_for_floats!(OptionCheckedSubAssign, {
+    #[inline]
+    fn opt_checked_sub_assign(&mut self, rhs: Self) -> Result<(), Error> {
+        if let Some(res) = (*self).opt_checked_sub(rhs)? {
+            *self = res;
+        }
+        Ok(())
+    }
+});
+
 option_op_saturating!(Sub, sub, substraction);
 
 impl_for_ints_and_duration!(OptionSaturatingSub, {
     type Output = Self;
+    #[inline]
+    fn opt_saturating_sub(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.saturating_sub(rhs))
+    }
+});
+
+impl<T> OptionSaturatingSub for core::num::Saturating<T>
+where
+    Self: OptionOperations + Sub<Output = Self>,
+{
+    type Output = Self;
+    #[inline]
     fn opt_saturating_sub(self, rhs: Self) -> Option<Self::Output> {
+        Some(self.sub(rhs))
+    }
+}
+
+option_op_custom!(
+    Monus,
+    monus,
+    "truncated subtraction",
+    "
+Contrary to the regular subtraction, the result is `0` if `rhs`
+is greater than `self`, e.g. `Some(3u32).opt_monus(Some(5))` is `Some(0)`.
+",
+);
+
+impl_for_unsigned_ints!(OptionMonus, {
+    type Output = Self;
+    #[inline]
+    fn opt_monus(self, rhs: Self) -> Option<Self::Output> {
         Some(self.saturating_sub(rhs))
     }
 });
@@ -200,55 +341,78 @@ mod test {
         assert_eq!(none, NONE);
     }
 
-    #[test]
-    fn checked_sub() {
-        impl OptionCheckedSub for MyInt {
-            type Output = MyInt;
-            fn opt_checked_sub(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
-                self.0.opt_checked_sub(rhs.0).map(|ok| ok.map(MyInt))
-            }
+    impl OptionCheckedSub for MyInt {
+        type Output = MyInt;
+        fn opt_checked_sub(self, rhs: MyInt) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_sub(rhs.0).map(|ok| ok.map(MyInt))
         }
+    }
 
-        impl OptionCheckedSub<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_checked_sub(self, rhs: u64) -> Result<Option<Self::Output>, Error> {
-                self.0.opt_checked_sub(rhs).map(|ok| ok.map(MyInt))
-            }
+    impl OptionCheckedSub<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_checked_sub(self, rhs: u64) -> Result<Option<Self::Output>, Error> {
+            self.0.opt_checked_sub(rhs).map(|ok| ok.map(MyInt))
         }
+    }
 
+    #[test]
+    fn checked_sub() {
         assert_eq!(MY_3.opt_checked_sub(MY_1), Ok(SOME_2));
         assert_eq!(MY_3.opt_checked_sub(SOME_1), Ok(SOME_2));
         assert_eq!(MY_3.opt_checked_sub(&SOME_1), Ok(SOME_2));
-        assert_eq!(MY_0.opt_checked_sub(MY_1), Err(Error::Overflow));
+        assert_eq!(MY_0.opt_checked_sub(MY_1), Err(Error::Underflow));
 
         assert_eq!(SOME_3.opt_checked_sub(MY_1), Ok(SOME_2));
         assert_eq!(SOME_3.opt_checked_sub(SOME_1), Ok(SOME_2));
         assert_eq!(SOME_3.opt_checked_sub(&SOME_1), Ok(SOME_2));
 
-        assert_eq!(SOME_0.opt_checked_sub(MY_1), Err(Error::Overflow));
-        assert_eq!(SOME_0.opt_checked_sub(1), Err(Error::Overflow));
-        assert_eq!(SOME_0.opt_checked_sub(Some(1)), Err(Error::Overflow));
-        assert_eq!(MY_0.opt_checked_sub(SOME_1), Err(Error::Overflow));
+        assert_eq!(SOME_0.opt_checked_sub(MY_1), Err(Error::Underflow));
+        assert_eq!(SOME_0.opt_checked_sub(1), Err(Error::Underflow));
+        assert_eq!(SOME_0.opt_checked_sub(Some(1)), Err(Error::Underflow));
+        assert_eq!(MY_0.opt_checked_sub(SOME_1), Err(Error::Underflow));
         assert_eq!(MY_0.opt_checked_sub(NONE), Ok(None));
         assert_eq!(NONE.opt_checked_sub(MY_0), Ok(None));
+
+        assert_eq!(Some(0u32).opt_checked_sub(Some(1)), Err(Error::Underflow));
+        assert!(Some(0u8).opt_checked_sub(1).unwrap_err().is_underflow());
+        assert_eq!(Some(i32::MIN).opt_checked_sub(1), Err(Error::Overflow));
+        assert_eq!(Some(i32::MAX).opt_checked_sub(-1), Err(Error::Overflow));
     }
 
     #[test]
-    fn saturating_sub() {
-        impl OptionSaturatingSub for MyInt {
-            type Output = MyInt;
-            fn opt_saturating_sub(self, rhs: MyInt) -> Option<Self::Output> {
-                self.0.opt_saturating_sub(rhs.0).map(MyInt)
-            }
+    fn checked_sub_float() {
+        assert_eq!(Some(1.5f64).opt_checked_sub(2.0), Ok(Some(-0.5)));
+        assert_eq!(1.5f32.opt_checked_sub(Some(-2.0)), Ok(Some(3.5)));
+        assert_eq!(f64::MIN.opt_checked_sub(f64::MAX), Err(Error::NotFinite));
+        assert_eq!(
+            Some(f32::MAX).opt_checked_sub(&Some(f32::MIN)),
+            Err(Error::NotFinite)
+        );
+        assert_eq!(
+            Some(f64::INFINITY)
+                .opt_checked_sub(f64::INFINITY)
+                .map(|res| res.map(f64::is_nan)),
+            Ok(Some(true))
+        );
+        assert_eq!(Option::<f64>::None.opt_checked_sub(f64::MAX), Ok(None));
+    }
+
+    impl OptionSaturatingSub for MyInt {
+        type Output = MyInt;
+        fn opt_saturating_sub(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_saturating_sub(rhs.0).map(MyInt)
         }
+    }
 
-        impl OptionSaturatingSub<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_saturating_sub(self, rhs: u64) -> Option<Self::Output> {
-                self.0.opt_saturating_sub(rhs).map(MyInt)
-            }
+    impl OptionSaturatingSub<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_saturating_sub(self, rhs: u64) -> Option<Self::Output> {
+            self.0.opt_saturating_sub(rhs).map(MyInt)
         }
+    }
 
+    #[test]
+    fn saturating_sub() {
         assert_eq!(MY_3.opt_saturating_sub(MY_1), SOME_2);
         assert_eq!(MY_1.opt_saturating_sub(MY_2), SOME_0);
         assert_eq!(SOME_1.opt_saturating_sub(MY_2), SOME_0);
@@ -261,26 +425,106 @@ mod test {
         assert_eq!(NONE.opt_saturating_sub(MY_1), NONE);
     }
 
+    #[cfg(feature = "duration")]
     #[test]
-    fn overflowing_sub() {
-        impl OptionOverflowingSub for MyInt {
-            type Output = MyInt;
-            fn opt_overflowing_sub(self, rhs: MyInt) -> Option<(Self::Output, bool)> {
-                self.0
-                    .opt_overflowing_sub(rhs.0)
-                    .map(|(val, flag)| (MyInt(val), flag))
-            }
+    fn saturating_sub_duration() {
+        use core::time::Duration;
+
+        let secs_1 = Duration::from_secs(1);
+        assert_eq!(
+            Duration::from_secs(3).opt_saturating_sub(secs_1),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            Duration::ZERO.opt_saturating_sub(secs_1),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(
+            Some(secs_1).opt_saturating_sub(Some(Duration::MAX)),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(
+            secs_1.opt_saturating_sub(&Some(Duration::from_nanos(1))),
+            Some(Duration::new(0, 999_999_999))
+        );
+        assert_eq!(
+            Some(Duration::ZERO).opt_saturating_sub(Option::<Duration>::None),
+            None
+        );
+        assert_eq!(Option::<Duration>::None.opt_saturating_sub(secs_1), None);
+    }
+
+    #[cfg(feature = "duration")]
+    #[test]
+    fn checked_sub_duration() {
+        use core::time::Duration;
+
+        let secs_1 = Duration::from_secs(1);
+        assert_eq!(
+            Duration::from_secs(3).opt_checked_sub(secs_1),
+            Ok(Some(Duration::from_secs(2)))
+        );
+        assert_eq!(
+            Duration::ZERO.opt_checked_sub(secs_1),
+            Err(Error::Underflow)
+        );
+        assert_eq!(
+            Some(secs_1).opt_checked_sub(&Some(Duration::MAX)),
+            Err(Error::Underflow)
+        );
+        assert_eq!(
+            Option::<Duration>::None.opt_checked_sub(Duration::MAX),
+            Ok(None)
+        );
+
+        let mut some = Some(secs_1);
+        assert_eq!(
+            some.opt_checked_sub_assign(Duration::from_millis(500)),
+            Ok(())
+        );
+        assert_eq!(some, Some(Duration::from_millis(500)));
+        assert_eq!(some.opt_checked_sub_assign(secs_1), Err(Error::Underflow));
+        assert_eq!(some, Some(Duration::from_millis(500)));
+
+        let mut zero = Duration::ZERO;
+        assert_eq!(
+            zero.opt_checked_sub_assign(Some(Duration::from_nanos(1))),
+            Err(Error::Underflow)
+        );
+        assert_eq!(zero, Duration::ZERO);
+    }
+
+    #[test]
+    fn monus() {
+        assert_eq!(Some(3u32).opt_monus(Some(5)), Some(0));
+        assert_eq!(Some(5u32).opt_monus(Some(3)), Some(2));
+        assert_eq!(5u8.opt_monus(5), Some(0));
+        assert_eq!(Some(u64::MAX).opt_monus(&Some(1)), Some(u64::MAX - 1));
+        assert_eq!(0u128.opt_monus(Some(u128::MAX)), Some(0));
+        assert_eq!(Some(3u16).opt_monus(Option::<u16>::None), None);
+        assert_eq!(Option::<u16>::None.opt_monus(3), None);
+    }
+
+    impl OptionOverflowingSub for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_sub(self, rhs: MyInt) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_sub(rhs.0)
+                .map(|(val, flag)| (MyInt(val), flag))
         }
+    }
 
-        impl OptionOverflowingSub<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_overflowing_sub(self, rhs: u64) -> Option<(Self::Output, bool)> {
-                self.0
-                    .opt_overflowing_sub(rhs)
-                    .map(|(val, flag)| (MyInt(val), flag))
-            }
+    impl OptionOverflowingSub<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_overflowing_sub(self, rhs: u64) -> Option<(Self::Output, bool)> {
+            self.0
+                .opt_overflowing_sub(rhs)
+                .map(|(val, flag)| (MyInt(val), flag))
         }
+    }
 
+    #[test]
+    fn overflowing_sub() {
         assert_eq!(MY_3.opt_overflowing_sub(MY_1), Some((MY_2, false)));
         assert_eq!(MY_1.opt_overflowing_sub(MY_2), Some((MY_MAX, true)));
         assert_eq!(SOME_1.opt_overflowing_sub(MY_2), Some((MY_MAX, true)));
@@ -293,22 +537,22 @@ mod test {
         assert_eq!(NONE.opt_overflowing_sub(MY_1), None);
     }
 
-    #[test]
-    fn wrapping_sub() {
-        impl OptionWrappingSub for MyInt {
-            type Output = MyInt;
-            fn opt_wrapping_sub(self, rhs: MyInt) -> Option<Self::Output> {
-                self.0.opt_wrapping_sub(rhs.0).map(MyInt)
-            }
+    impl OptionWrappingSub for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_sub(self, rhs: MyInt) -> Option<Self::Output> {
+            self.0.opt_wrapping_sub(rhs.0).map(MyInt)
         }
+    }
 
-        impl OptionWrappingSub<u64> for MyInt {
-            type Output = MyInt;
-            fn opt_wrapping_sub(self, rhs: u64) -> Option<Self::Output> {
-                self.0.opt_wrapping_sub(rhs).map(MyInt)
-            }
+    impl OptionWrappingSub<u64> for MyInt {
+        type Output = MyInt;
+        fn opt_wrapping_sub(self, rhs: u64) -> Option<Self::Output> {
+            self.0.opt_wrapping_sub(rhs).map(MyInt)
         }
+    }
 
+    #[test]
+    fn wrapping_sub() {
         assert_eq!(MY_3.opt_wrapping_sub(MY_1), SOME_2);
         assert_eq!(MY_1.opt_wrapping_sub(MY_2), SOME_MAX);
         assert_eq!(SOME_1.opt_wrapping_sub(MY_2), SOME_MAX);
@@ -320,4 +564,160 @@ mod test {
         assert_eq!(MY_1.opt_wrapping_sub(NONE), None);
         assert_eq!(NONE.opt_wrapping_sub(MY_1), None);
     }
+
+    #[test]
+    fn sub_wrapping_type() {
+        use core::num::Wrapping;
+
+        assert_eq!(
+            Some(Wrapping(0u8)).opt_sub(Wrapping(1u8)),
+            Some(Wrapping(255))
+        );
+        assert_eq!(
+            Wrapping(i64::MIN).opt_sub(Some(Wrapping(1))),
+            Some(Wrapping(i64::MAX))
+        );
+        assert_eq!(Option::<Wrapping<u8>>::None.opt_sub(Wrapping(1)), None);
+
+        let mut some = Some(Wrapping(1u16));
+        some.opt_sub_assign(Some(Wrapping(2)));
+        assert_eq!(some, Some(Wrapping(u16::MAX)));
+    }
+
+    #[test]
+    fn sub_saturating_type() {
+        use core::num::Saturating;
+
+        assert_eq!(
+            Some(Saturating(0u8)).opt_sub(Saturating(1u8)),
+            Some(Saturating(0))
+        );
+        assert_eq!(
+            Some(Saturating(1u8)).opt_sub(Saturating(1u8)),
+            Some(Saturating(0))
+        );
+        assert_eq!(
+            Saturating(i64::MIN).opt_sub(Some(Saturating(1))),
+            Some(Saturating(i64::MIN))
+        );
+        assert_eq!(
+            Saturating(5u8).opt_saturating_sub(Option::<Saturating<u8>>::None),
+            None
+        );
+        assert_eq!(
+            Some(Saturating(i8::MAX)).opt_saturating_sub(Saturating(-1)),
+            Some(Saturating(i8::MAX))
+        );
+    }
+
+    impl OptionCheckedSubAssign for MyInt {
+        fn opt_checked_sub_assign(&mut self, rhs: MyInt) -> Result<(), Error> {
+            self.0.opt_checked_sub_assign(rhs.0)
+        }
+    }
+
+    impl OptionCheckedSubAssign<u64> for MyInt {
+        fn opt_checked_sub_assign(&mut self, rhs: u64) -> Result<(), Error> {
+            self.0.opt_checked_sub_assign(rhs)
+        }
+    }
+
+    #[test]
+    fn checked_sub_assign() {
+        let mut my = MY_3;
+        assert_eq!(my.opt_checked_sub_assign(MY_1), Ok(()));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_3;
+        assert_eq!(my.opt_checked_sub_assign(SOME_1), Ok(()));
+        assert_eq!(my, MY_2);
+
+        let mut my = MY_3;
+        assert_eq!(my.opt_checked_sub_assign(&SOME_3), Ok(()));
+        assert_eq!(my, MY_0);
+
+        let mut my = MY_0;
+        assert_eq!(my.opt_checked_sub_assign(MY_1), Err(Error::Underflow));
+        assert_eq!(my, MY_0);
+
+        let mut my = MY_1;
+        assert_eq!(my.opt_checked_sub_assign(NONE), Ok(()));
+        assert_eq!(my, MY_1);
+
+        let mut some = SOME_3;
+        assert_eq!(some.opt_checked_sub_assign(1), Ok(()));
+        assert_eq!(some, SOME_2);
+
+        let mut some = SOME_1;
+        assert_eq!(some.opt_checked_sub_assign(Some(2)), Err(Error::Underflow));
+        assert_eq!(some, SOME_1);
+
+        let mut some = SOME_0;
+        assert_eq!(
+            some.opt_checked_sub_assign(&SOME_MAX),
+            Err(Error::Underflow)
+        );
+        assert_eq!(some, SOME_0);
+
+        let mut some = SOME_2;
+        assert_eq!(some.opt_checked_sub_assign(NONE), Ok(()));
+        assert_eq!(some, SOME_2);
+
+        let mut none = NONE;
+        assert_eq!(none.opt_checked_sub_assign(SOME_MAX), Ok(()));
+        assert_eq!(none, NONE);
+    }
+
+    #[test]
+    fn checked_sub_assign_primitive() {
+        let mut some = Some(i64::MIN);
+        assert_eq!(some.opt_checked_sub_assign(1), Err(Error::Overflow));
+        assert_eq!(some, Some(i64::MIN));
+
+        let mut some = Some(0u32);
+        assert_eq!(some.opt_checked_sub_assign(Some(1)), Err(Error::Underflow));
+        assert_eq!(some, Some(0));
+
+        let mut some = Some(1.5f32);
+        assert_eq!(some.opt_checked_sub_assign(2.0), Ok(()));
+        assert_eq!(some, Some(-0.5));
+
+        let mut some = Some(f64::MIN);
+        assert_eq!(some.opt_checked_sub_assign(f64::MAX), Err(Error::NotFinite));
+        assert_eq!(some, Some(f64::MIN));
+    }
+
+    #[cfg(all(feature = "std", feature = "duration"))]
+    #[test]
+    fn sub_instants() {
+        use std::time::{Duration, Instant, SystemTime};
+
+        let ms_5 = Duration::from_millis(5);
+        let start = Instant::now();
+        let end = start + ms_5;
+
+        assert_eq!(Some(end).opt_sub(Some(start)), Some(ms_5));
+        assert_eq!(end.opt_sub(start), Some(ms_5));
+        assert_eq!(Some(end).opt_sub(Option::<Instant>::None), None);
+
+        assert_eq!(Some(end).opt_checked_sub(Some(start)), Ok(Some(ms_5)));
+        assert_eq!(end.opt_checked_sub(&Some(start)), Ok(Some(ms_5)));
+        assert_eq!(Some(start).opt_checked_sub(start), Ok(Some(Duration::ZERO)));
+        assert_eq!(
+            Some(start).opt_checked_sub(Some(end)),
+            Err(Error::Underflow)
+        );
+        assert_eq!(start.opt_checked_sub(end), Err(Error::Underflow));
+        assert_eq!(Option::<Instant>::None.opt_checked_sub(Some(end)), Ok(None));
+        assert_eq!(Some(end).opt_checked_sub(Option::<Instant>::None), Ok(None));
+
+        // `Instant - Duration` is unaffected
+        assert_eq!(Some(end).opt_checked_sub(ms_5), Ok(Some(start)));
+
+        let start = SystemTime::UNIX_EPOCH;
+        let end = start + ms_5;
+        assert_eq!(Some(end).opt_checked_sub(Some(start)), Ok(Some(ms_5)));
+        assert_eq!(Some(start).opt_checked_sub(end), Err(Error::Underflow));
+        assert_eq!(Option::<SystemTime>::None.opt_checked_sub(start), Ok(None));
+    }
 }
@@ -0,0 +1,188 @@
+//! Trait to chain optional [`Ordering`]s.
+
+use core::cmp::Ordering;
+
+// Required for doc
+#[allow(unused)]
+use crate::OptionOperations;
+
+/// Trait for [`Ordering`]s and `Option<Ordering>`s which can be chained.
+///
+/// This is the counterpart of [`Ordering::then`] and [`Ordering::then_with`]
+/// for the optional orderings returned by the comparison
+/// [`OptionOperations`], e.g. [`OptionOrd::opt_cmp`](crate::OptionOrd::opt_cmp).
+///
+/// This trait is implemented for `Ordering` and `Option<Ordering>`.
+pub trait OptionThen {
+    /// Chains two optional orderings.
+    ///
+    /// - Returns `other` if `self` is `Equal`.
+    /// - Returns `self` if `self` is `Less` or `Greater`.
+    /// - Returns `None` if `self` is `None`.
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// # use option_operations::OptionThen;
+    /// assert_eq!(Some(Ordering::Equal).opt_then(Ordering::Less), Some(Ordering::Less));
+    /// assert_eq!(Some(Ordering::Greater).opt_then(None), Some(Ordering::Greater));
+    /// assert_eq!(Option::<Ordering>::None.opt_then(Ordering::Less), None);
+    /// ```
+    #[must_use]
+    fn opt_then(self, other: impl Into<Option<Ordering>>) -> Option<Ordering>;
+
+    /// Chains two optional orderings, computing `other` only if needed.
+    ///
+    /// - Returns the result of `f` if `self` is `Equal`.
+    /// - Returns `self` if `self` is `Less` or `Greater`.
+    /// - Returns `None` if `self` is `None`.
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// # use option_operations::{OptionOrd, OptionThen};
+    /// let (a, b) = ((1, Some(2.0)), (1, Some(1.5)));
+    /// assert_eq!(
+    ///     a.0.opt_cmp(b.0).opt_then_with(|| a.1.opt_cmp(b.1)),
+    ///     Some(Ordering::Greater),
+    /// );
+    /// ```
+    #[must_use]
+    fn opt_then_with<O, F>(self, f: F) -> Option<Ordering>
+    where
+        O: Into<Option<Ordering>>,
+        F: FnOnce() -> O;
+}
+
+impl OptionThen for Ordering {
+    #[inline]
+    fn opt_then(self, other: impl Into<Option<Ordering>>) -> Option<Ordering> {
+        match self {
+            Ordering::Equal => other.into(),
+            _ => Some(self),
+        }
+    }
+
+    #[inline]
+    fn opt_then_with<O, F>(self, f: F) -> Option<Ordering>
+    where
+        O: Into<Option<Ordering>>,
+        F: FnOnce() -> O,
+    {
+        match self {
+            Ordering::Equal => f().into(),
+            _ => Some(self),
+        }
+    }
+}
+
+impl OptionThen for Option<Ordering> {
+    #[inline]
+    fn opt_then(self, other: impl Into<Option<Ordering>>) -> Option<Ordering> {
+        self.and_then(|ordering| ordering.opt_then(other))
+    }
+
+    #[inline]
+    fn opt_then_with<O, F>(self, f: F) -> Option<Ordering>
+    where
+        O: Into<Option<Ordering>>,
+        F: FnOnce() -> O,
+    {
+        self.and_then(|ordering| ordering.opt_then_with(f))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::OptionThen;
+    use crate::{OptionEq, OptionMinMax, OptionOperations, OptionOrd, OptionPartialCmp};
+    use core::cmp::Ordering::{self, *};
+
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct MyInt(u64);
+
+    impl OptionOperations for MyInt {}
+
+    #[derive(Copy, Clone, Debug)]
+    struct Record {
+        key: Option<MyInt>,
+        tie_breaker: Option<f64>,
+    }
+
+    impl Record {
+        fn opt_cmp(&self, other: &Record) -> Option<Ordering> {
+            self.key
+                .opt_cmp(other.key)
+                .opt_then_with(|| self.tie_breaker.opt_cmp(other.tie_breaker))
+        }
+    }
+
+    fn record(key: Option<u64>, tie_breaker: Option<f64>) -> Record {
+        Record {
+            key: key.map(MyInt),
+            tie_breaker,
+        }
+    }
+
+    #[test]
+    fn then() {
+        assert_eq!(Equal.opt_then(Less), Some(Less));
+        assert_eq!(Equal.opt_then(Some(Greater)), Some(Greater));
+        assert_eq!(Equal.opt_then(None), None);
+        assert_eq!(Less.opt_then(Greater), Some(Less));
+        assert_eq!(Greater.opt_then(None), Some(Greater));
+
+        assert_eq!(Some(Equal).opt_then(Less), Some(Less));
+        assert_eq!(Some(Equal).opt_then(None), None);
+        assert_eq!(Some(Less).opt_then(None), Some(Less));
+        assert_eq!(Option::<Ordering>::None.opt_then(Less), None);
+    }
+
+    #[test]
+    fn then_with() {
+        assert_eq!(Some(Equal).opt_then_with(|| Greater), Some(Greater));
+        assert_eq!(Some(Equal).opt_then_with(|| Some(Less)), Some(Less));
+        assert_eq!(Equal.opt_then_with(|| Option::<Ordering>::None), None);
+        assert_eq!(
+            Some(Less).opt_then_with(|| -> Ordering { unreachable!() }),
+            Some(Less)
+        );
+        assert_eq!(
+            Option::<Ordering>::None.opt_then_with(|| -> Ordering { unreachable!() }),
+            None
+        );
+    }
+
+    #[test]
+    fn then_with_tie_breaking() {
+        let a = record(Some(1), Some(2.0));
+
+        // Equal first key, the second key breaks the tie.
+        assert_eq!(a.opt_cmp(&record(Some(1), Some(1.5))), Some(Greater));
+        assert_eq!(a.opt_cmp(&record(Some(1), Some(2.5))), Some(Less));
+        assert_eq!(a.opt_cmp(&record(Some(1), Some(2.0))), Some(Equal));
+        assert_eq!(a.opt_cmp(&record(Some(1), None)), None);
+        assert_eq!(a.opt_cmp(&record(Some(1), Some(f64::NAN))), None);
+
+        // Different first key, the second key is ignored.
+        assert_eq!(a.opt_cmp(&record(Some(0), None)), Some(Greater));
+        assert_eq!(a.opt_cmp(&record(Some(2), Some(0.0))), Some(Less));
+
+        // The first key can't be compared.
+        assert_eq!(a.opt_cmp(&record(None, Some(2.0))), None);
+    }
+
+    #[test]
+    fn then_partial_cmp() {
+        let res = Some(1.0)
+            .opt_partial_cmp(1.0)
+            .flatten()
+            .opt_then_with(|| Some(2).opt_partial_cmp(3).flatten());
+        assert_eq!(res, Some(Less));
+    }
+
+    #[test]
+    fn ordering_option_operations() {
+        assert_eq!(Some(Less).opt_lt(Greater), Some(true));
+        assert_eq!(Equal.opt_max(Some(Less)), Some(Equal));
+        assert_eq!(Option::<Ordering>::None.opt_eq(Equal), None);
+    }
+}
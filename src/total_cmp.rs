@@ -0,0 +1,246 @@
+//! Trait for the total order [`OptionOperations`].
+
+use core::cmp::Ordering;
+
+use crate::{NoneOrdering, OptionOperations};
+
+/// Trait for values and `Option`s that can be compared using a total order.
+///
+/// Contrary to [`OptionOrd`](crate::OptionOrd), which relies on
+/// `PartialOrd`, the inner values are compared using a total order.
+/// For floats, this is the order defined by `total_cmp`, which means that
+/// `-0.0 < 0.0` and that `NaN`s are sorted consistently.
+///
+/// Implementing this trait leads to the following auto-implementations:
+///
+/// - `OptionTotalCmp<Option<InnerRhs>> for T`.
+/// - `OptionTotalCmp<Rhs> for Option<T>`.
+/// - `OptionTotalCmp<Option<InnerRhs>> for Option<T>`.
+/// - ... and some variants with references.
+///
+/// Note that since the `std` library doesn't define any `TotalCmp` trait,
+/// users must provide the base implementation for the inner type.
+pub trait OptionTotalCmp<Rhs = Self, InnerRhs = Rhs> {
+    /// Returns the total ordering between `self` and `rhs`.
+    ///
+    /// Returns `None` if exactly one argument is `None`. Two `None`s
+    /// are considered equal.
+    #[must_use]
+    fn opt_total_cmp(&self, rhs: Rhs) -> Option<Ordering>;
+
+    /// Returns the total ordering between `self` and `rhs`,
+    /// placing `None` according to `none_ordering`.
+    ///
+    /// ```
+    /// # use core::cmp::Ordering;
+    /// # use option_operations::{NoneOrdering, OptionTotalCmp};
+    /// let mut values = vec![Some(1.0), None, Some(f64::NAN), Some(-0.0), Some(0.0)];
+    /// values.sort_by(|a, b| a.opt_total_cmp_with(b, NoneOrdering::First));
+    ///
+    /// assert_eq!(values[0], None);
+    /// assert_eq!(values[1].unwrap().to_bits(), (-0.0f64).to_bits());
+    /// assert_eq!(values[2].unwrap().to_bits(), 0.0f64.to_bits());
+    /// assert_eq!(values[3], Some(1.0));
+    /// assert!(values[4].unwrap().is_nan());
+    /// ```
+    #[must_use]
+    fn opt_total_cmp_with(&self, rhs: Rhs, none_ordering: NoneOrdering) -> Ordering;
+}
+
+impl_for_floats!(OptionTotalCmp, {
+    #[inline]
+    fn opt_total_cmp(&self, rhs: Self) -> Option<Ordering> {
+        Some(self.total_cmp(&rhs))
+    }
+
+    #[inline]
+    fn opt_total_cmp_with(&self, rhs: Self, _none_ordering: NoneOrdering) -> Ordering {
+        self.total_cmp(&rhs)
+    }
+});
+
+impl<T, InnerRhs> OptionTotalCmp<Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + OptionTotalCmp<InnerRhs>,
+{
+    #[inline]
+    fn opt_total_cmp(&self, rhs: Option<InnerRhs>) -> Option<Ordering> {
+        rhs.and_then(|inner_rhs| self.opt_total_cmp(inner_rhs))
+    }
+
+    #[inline]
+    fn opt_total_cmp_with(&self, rhs: Option<InnerRhs>, none_ordering: NoneOrdering) -> Ordering {
+        match rhs {
+            Some(inner_rhs) => self.opt_total_cmp_with(inner_rhs, none_ordering),
+            None => none_ordering.none_cmp_some().reverse(),
+        }
+    }
+}
+
+impl<T, InnerRhs> OptionTotalCmp<&Option<InnerRhs>, InnerRhs> for T
+where
+    T: OptionOperations + OptionTotalCmp<InnerRhs>,
+    InnerRhs: Copy,
+{
+    #[inline]
+    fn opt_total_cmp(&self, rhs: &Option<InnerRhs>) -> Option<Ordering> {
+        rhs.and_then(|inner_rhs| self.opt_total_cmp(inner_rhs))
+    }
+
+    #[inline]
+    fn opt_total_cmp_with(&self, rhs: &Option<InnerRhs>, none_ordering: NoneOrdering) -> Ordering {
+        match rhs {
+            Some(inner_rhs) => self.opt_total_cmp_with(*inner_rhs, none_ordering),
+            None => none_ordering.none_cmp_some().reverse(),
+        }
+    }
+}
+
+impl<T, Rhs> OptionTotalCmp<Rhs> for Option<T>
+where
+    T: OptionOperations + OptionTotalCmp<Rhs>,
+{
+    #[inline]
+    fn opt_total_cmp(&self, rhs: Rhs) -> Option<Ordering> {
+        self.as_ref()
+            .and_then(|inner_self| inner_self.opt_total_cmp(rhs))
+    }
+
+    #[inline]
+    fn opt_total_cmp_with(&self, rhs: Rhs, none_ordering: NoneOrdering) -> Ordering {
+        match self {
+            Some(inner_self) => inner_self.opt_total_cmp_with(rhs, none_ordering),
+            None => none_ordering.none_cmp_some(),
+        }
+    }
+}
+
+impl<T, InnerRhs> OptionTotalCmp<Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + OptionTotalCmp<InnerRhs>,
+{
+    #[inline]
+    fn opt_total_cmp(&self, rhs: Option<InnerRhs>) -> Option<Ordering> {
+        match (self, rhs) {
+            (Some(inner_self), Some(inner_rhs)) => inner_self.opt_total_cmp(inner_rhs),
+            (None, None) => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn opt_total_cmp_with(&self, rhs: Option<InnerRhs>, none_ordering: NoneOrdering) -> Ordering {
+        match (self, rhs) {
+            (Some(inner_self), Some(inner_rhs)) => {
+                inner_self.opt_total_cmp_with(inner_rhs, none_ordering)
+            }
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => none_ordering.none_cmp_some(),
+            (Some(_), None) => none_ordering.none_cmp_some().reverse(),
+        }
+    }
+}
+
+impl<T, InnerRhs> OptionTotalCmp<&Option<InnerRhs>, InnerRhs> for Option<T>
+where
+    T: OptionOperations + OptionTotalCmp<InnerRhs>,
+    InnerRhs: Copy,
+{
+    #[inline]
+    fn opt_total_cmp(&self, rhs: &Option<InnerRhs>) -> Option<Ordering> {
+        self.opt_total_cmp(*rhs)
+    }
+
+    #[inline]
+    fn opt_total_cmp_with(&self, rhs: &Option<InnerRhs>, none_ordering: NoneOrdering) -> Ordering {
+        self.opt_total_cmp_with(*rhs, none_ordering)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cmp::Ordering;
+
+    use super::OptionTotalCmp;
+    use crate::NoneOrdering;
+
+    const NEG_ZERO: f64 = -0.0;
+    const NONE: Option<f64> = None;
+
+    #[test]
+    fn total_cmp() {
+        assert_eq!(1.0f64.opt_total_cmp(2.0), Some(Ordering::Less));
+        assert_eq!(Some(2.0f64).opt_total_cmp(1.0), Some(Ordering::Greater));
+        assert_eq!(Some(1.0f32).opt_total_cmp(Some(1.0)), Some(Ordering::Equal));
+        assert_eq!(1.0f64.opt_total_cmp(&Some(1.0)), Some(Ordering::Equal));
+
+        assert_eq!(
+            Some(NEG_ZERO).opt_total_cmp(Some(0.0)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(Some(0.0).opt_total_cmp(NEG_ZERO), Some(Ordering::Greater));
+        assert_eq!(
+            Some(f64::NAN).opt_total_cmp(Some(f64::NAN)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(
+            Some(f64::NAN).opt_total_cmp(f64::INFINITY),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            Some(-f64::NAN).opt_total_cmp(f64::NEG_INFINITY),
+            Some(Ordering::Less)
+        );
+
+        assert_eq!(NONE.opt_total_cmp(NONE), Some(Ordering::Equal));
+        assert_eq!(NONE.opt_total_cmp(&Some(0.0)), None);
+        assert_eq!(Some(0.0).opt_total_cmp(NONE), None);
+        assert_eq!(0.0f64.opt_total_cmp(NONE), None);
+    }
+
+    #[test]
+    fn total_cmp_with() {
+        use NoneOrdering::{First, Last};
+
+        assert_eq!(NONE.opt_total_cmp_with(NONE, First), Ordering::Equal);
+        assert_eq!(
+            NONE.opt_total_cmp_with(Some(f64::NAN), First),
+            Ordering::Less
+        );
+        assert_eq!(
+            NONE.opt_total_cmp_with(&Some(f64::NAN), Last),
+            Ordering::Greater
+        );
+        assert_eq!(NONE.opt_total_cmp_with(0.0, Last), Ordering::Greater);
+        assert_eq!(Some(0.0).opt_total_cmp_with(NONE, First), Ordering::Greater);
+        assert_eq!(0.0f64.opt_total_cmp_with(&NONE, Last), Ordering::Less);
+        assert_eq!(
+            Some(NEG_ZERO).opt_total_cmp_with(Some(0.0), Last),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn sort_with_nan() {
+        let mut values = [
+            Some(1.0),
+            NONE,
+            Some(f64::NAN),
+            Some(0.0),
+            Some(NEG_ZERO),
+            NONE,
+        ];
+
+        values.sort_unstable_by(|a, b| a.opt_total_cmp_with(b, NoneOrdering::Last));
+        assert_eq!(values[0].map(f64::to_bits), Some(NEG_ZERO.to_bits()));
+        assert_eq!(values[1].map(f64::to_bits), Some(0.0f64.to_bits()));
+        assert_eq!(values[2], Some(1.0));
+        assert!(values[3].unwrap().is_nan());
+        assert_eq!(values[4..], [NONE, NONE]);
+
+        values.sort_unstable_by(|a, b| a.opt_total_cmp_with(b, NoneOrdering::First));
+        assert_eq!(values[..2], [NONE, NONE]);
+        assert_eq!(values[2].map(f64::to_bits), Some(NEG_ZERO.to_bits()));
+        assert!(values[5].unwrap().is_nan());
+    }
+}
@@ -0,0 +1,119 @@
+//! Element-wise [`OptionOperations`] on tuples of `Option`s.
+//!
+//! `None`s are propagated per element: a `None` in one element only
+//! results in a `None` for that element, the other elements are still
+//! computed. The returned `Option` is thus always `Some`.
+
+use crate::{OptionAdd, OptionMul, OptionOperations, OptionSub};
+
+impl<A, B> OptionOperations for (A, B)
+where
+    A: OptionOperations,
+    B: OptionOperations,
+{
+}
+
+impl<A, B, C> OptionOperations for (A, B, C)
+where
+    A: OptionOperations,
+    B: OptionOperations,
+    C: OptionOperations,
+{
+}
+
+macro_rules! element_wise_op {
+    ($lhs:expr, $rhs:expr, $op:ident) => {
+        $lhs.zip($rhs).and_then(|(lhs, rhs)| lhs.$op(rhs))
+    };
+}
+
+macro_rules! impl_element_wise_op {
+    ($op_trait:ident, $op:ident) => {
+        paste::paste! {
+            impl<A, B, RhsA, RhsB> [<Option $op_trait>]<(Option<RhsA>, Option<RhsB>)>
+                for (Option<A>, Option<B>)
+            where
+                A: OptionOperations + [<Option $op_trait>]<RhsA>,
+                B: OptionOperations + [<Option $op_trait>]<RhsB>,
+            {
+                type Output = (Option<A::Output>, Option<B::Output>);
+
+                #[inline]
+                fn [<opt_ $op>](self, rhs: (Option<RhsA>, Option<RhsB>)) -> Option<Self::Output> {
+                    Some((
+                        element_wise_op!(self.0, rhs.0, [<opt_ $op>]),
+                        element_wise_op!(self.1, rhs.1, [<opt_ $op>]),
+                    ))
+                }
+            }
+
+            impl<A, B, C, RhsA, RhsB, RhsC>
+                [<Option $op_trait>]<(Option<RhsA>, Option<RhsB>, Option<RhsC>)>
+                for (Option<A>, Option<B>, Option<C>)
+            where
+                A: OptionOperations + [<Option $op_trait>]<RhsA>,
+                B: OptionOperations + [<Option $op_trait>]<RhsB>,
+                C: OptionOperations + [<Option $op_trait>]<RhsC>,
+            {
+                type Output = (Option<A::Output>, Option<B::Output>, Option<C::Output>);
+
+                #[inline]
+                fn [<opt_ $op>](
+                    self,
+                    rhs: (Option<RhsA>, Option<RhsB>, Option<RhsC>),
+                ) -> Option<Self::Output> {
+                    Some((
+                        element_wise_op!(self.0, rhs.0, [<opt_ $op>]),
+                        element_wise_op!(self.1, rhs.1, [<opt_ $op>]),
+                        element_wise_op!(self.2, rhs.2, [<opt_ $op>]),
+                    ))
+                }
+            }
+        }
+    };
+}
+
+impl_element_wise_op!(Add, add);
+impl_element_wise_op!(Sub, sub);
+impl_element_wise_op!(Mul, mul);
+
+#[cfg(test)]
+mod test {
+    use crate::{OptionAdd, OptionMul, OptionSub};
+
+    type Vec2 = (Option<f64>, Option<f64>);
+    type Vec3 = (Option<i32>, Option<i32>, Option<i32>);
+
+    #[test]
+    fn add_vec2() {
+        let a: Vec2 = (Some(1.0), Some(2.5));
+        let b: Vec2 = (Some(0.5), Some(-1.0));
+        assert_eq!(a.opt_add(b), Some((Some(1.5), Some(1.5))));
+
+        let a: Vec2 = (None, Some(2.5));
+        assert_eq!(a.opt_add(b), Some((None, Some(1.5))));
+
+        let b: Vec2 = (Some(0.5), None);
+        assert_eq!(a.opt_add(b), Some((None, None)));
+    }
+
+    #[test]
+    fn sub_mul_vec2() {
+        let a: Vec2 = (Some(3.0), Some(2.0));
+        let b: Vec2 = (Some(1.0), None);
+        assert_eq!(a.opt_sub(b), Some((Some(2.0), None)));
+        assert_eq!(
+            a.opt_mul((Some(2.0), Some(0.5))),
+            Some((Some(6.0), Some(1.0)))
+        );
+    }
+
+    #[test]
+    fn ops_vec3() {
+        let a: Vec3 = (Some(1), None, Some(3));
+        let b: Vec3 = (Some(4), Some(5), Some(6));
+        assert_eq!(a.opt_add(b), Some((Some(5), None, Some(9))));
+        assert_eq!(b.opt_sub(a), Some((Some(3), None, Some(3))));
+        assert_eq!(a.opt_mul(b), Some((Some(4), None, Some(18))));
+    }
+}
@@ -0,0 +1,79 @@
+//! Utilities to interoperate with [`OptionOperations`].
+
+// Required for doc
+#[allow(unused)]
+use crate::OptionOperations;
+
+/// Converts an `Option<Result<T, E>>` into a `Result<Option<T>, E>`.
+///
+/// This is the shape returned by the checked [`OptionOperations`], e.g.
+/// [`OptionCheckedAdd`](crate::OptionCheckedAdd). Same as
+/// [`Option::transpose`], as a function:
+///
+/// - `None` is mapped to `Ok(None)`.
+/// - `Some(Ok(value))` is mapped to `Ok(Some(value))`.
+/// - `Some(Err(err))` is mapped to `Err(err)`.
+///
+/// ```
+/// # use option_operations::{opt_result_transpose, Error, OptionCheckedAdd};
+/// let parsed: Option<Result<u8, Error>> = Some(Ok(254));
+/// let res = opt_result_transpose(parsed).and_then(|value| value.opt_checked_add(1));
+/// assert_eq!(res, Ok(Some(255)));
+/// ```
+#[inline]
+pub fn opt_result_transpose<T, E>(opt: Option<Result<T, E>>) -> Result<Option<T>, E> {
+    opt.transpose()
+}
+
+/// Converts a `Result<Option<T>, E>` into an `Option<Result<T, E>>`.
+///
+/// This is the reverse of [`opt_result_transpose`]. Same as
+/// [`Result::transpose`], as a function:
+///
+/// - `Ok(None)` is mapped to `None`.
+/// - `Ok(Some(value))` is mapped to `Some(Ok(value))`.
+/// - `Err(err)` is mapped to `Some(Err(err))`.
+///
+/// ```
+/// # use option_operations::{result_opt_transpose, Error, OptionCheckedAdd};
+/// let res = result_opt_transpose(Some(255u8).opt_checked_add(1));
+/// assert_eq!(res, Some(Err(Error::Overflow)));
+/// ```
+#[inline]
+pub fn result_opt_transpose<T, E>(res: Result<Option<T>, E>) -> Option<Result<T, E>> {
+    res.transpose()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{opt_result_transpose, result_opt_transpose};
+    use crate::Error;
+
+    #[test]
+    fn opt_result() {
+        assert_eq!(opt_result_transpose(Some(Ok::<_, Error>(1))), Ok(Some(1)));
+        assert_eq!(
+            opt_result_transpose(Some(Err::<u8, _>(Error::Overflow))),
+            Err(Error::Overflow)
+        );
+        assert_eq!(opt_result_transpose(None::<Result<u8, Error>>), Ok(None));
+    }
+
+    #[test]
+    fn result_opt() {
+        assert_eq!(result_opt_transpose(Ok::<_, Error>(Some(1))), Some(Ok(1)));
+        assert_eq!(result_opt_transpose(Ok::<Option<u8>, Error>(None)), None);
+        assert_eq!(
+            result_opt_transpose(Err::<Option<u8>, _>(Error::DivisionByZero)),
+            Some(Err(Error::DivisionByZero))
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let values: [Result<Option<u8>, Error>; 3] = [Ok(Some(1)), Ok(None), Err(Error::Overflow)];
+        for value in values.iter().copied() {
+            assert_eq!(opt_result_transpose(result_opt_transpose(value)), value);
+        }
+    }
+}